@@ -0,0 +1,113 @@
+use crate::{
+    components::{
+        character_class::{CharacterClasses, PlayerClass},
+        despawn_all,
+        player::AllPlayerAssets,
+    },
+    schedule::GameState,
+    theme::{palette::POPUP_BACKGROUND, widget},
+};
+use bevy::{ecs::spawn::SpawnWith, prelude::*};
+
+pub fn class_select_plugin(app: &mut App) {
+    app.add_systems(OnEnter(GameState::ClassSelect), spawn_class_select)
+        .add_systems(OnExit(GameState::ClassSelect), despawn_all::<ClassSelect>);
+}
+
+#[derive(Component)]
+struct ClassSelect;
+
+fn class_select(classes: &CharacterClasses, assets: &AllPlayerAssets) -> impl Bundle {
+    let cards = classes
+        .iter()
+        .zip(assets.iter())
+        .enumerate()
+        .map(|(class, (definition, assets))| {
+            (
+                class,
+                definition.name.clone(),
+                definition.description.clone(),
+                assets.image_node(definition),
+            )
+        })
+        .collect::<Vec<_>>();
+    (
+        ClassSelect,
+        widget::ui_root("ClassSelect"),
+        GlobalZIndex(2),
+        children![
+            widget::header("Choose your class"),
+            (
+                Name::new("ClassCards"),
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(20.),
+                    ..Default::default()
+                },
+                Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                    for (class, name, description, image) in cards {
+                        parent.spawn(class_card(class, name, description, image));
+                    }
+                })),
+            ),
+            widget::menu_button("Back", on_back),
+        ],
+    )
+}
+
+fn class_card(class: usize, name: String, description: String, image: ImageNode) -> impl Bundle {
+    (
+        Name::new(format!("ClassCard({name})")),
+        Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(10.),
+            padding: UiRect::all(Val::Px(10.)),
+            border: UiRect::all(Val::Px(2.)),
+            ..Default::default()
+        },
+        BackgroundColor(POPUP_BACKGROUND),
+        BorderColor(Color::BLACK),
+        children![
+            widget::label(name),
+            (
+                image,
+                Node {
+                    width: Val::Px(64.),
+                    height: Val::Px(64.),
+                    ..Default::default()
+                },
+            ),
+            (
+                Text(description),
+                TextFont::from_font_size(14.),
+                Node {
+                    max_width: Val::Px(180.),
+                    ..Default::default()
+                },
+            ),
+            widget::button(
+                "Play",
+                move |_: Trigger<Pointer<Click>>,
+                      mut commands: Commands,
+                      mut next_game_state: ResMut<NextState<GameState>>| {
+                    // The class is kept for the whole run
+                    commands.insert_resource(PlayerClass(class));
+                    next_game_state.set(GameState::InGame);
+                },
+            ),
+        ],
+    )
+}
+
+fn spawn_class_select(
+    mut commands: Commands,
+    classes: Res<CharacterClasses>,
+    assets: Res<AllPlayerAssets>,
+) {
+    commands.spawn(class_select(&classes, &assets));
+}
+
+fn on_back(_trigger: Trigger<Pointer<Click>>, mut next_game_state: ResMut<NextState<GameState>>) {
+    next_game_state.set(GameState::Menu);
+}
@@ -64,6 +64,37 @@ impl From<u16> for LifeRegen {
     }
 }
 
+/// Percent of the hit damage dealt leeched as life
+#[derive(Component, Default, Clone, Copy, Deref, DerefMut, Debug, Reflect)]
+pub struct LifeLeech(pub f32);
+
+impl LifeLeech {
+    pub fn reset(&mut self) {
+        self.0 = 0.;
+    }
+
+    pub fn add(&mut self, leech: &LifeLeech) {
+        self.0 += leech.0;
+    }
+
+    /// Life leeched from the `damage` dealt
+    pub fn leech(&self, damage: Damage) -> f32 {
+        *damage * self.0 / 100.
+    }
+}
+
+impl std::fmt::Display for LifeLeech {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.0}% of hit damage leeched as life", self.0)
+    }
+}
+
+impl From<u16> for LifeLeech {
+    fn from(value: u16) -> Self {
+        LifeLeech(value as f32)
+    }
+}
+
 /// Armour
 #[derive(Component, Clone, Copy, Default, Deref, DerefMut, Debug, Reflect)]
 pub struct Armour(pub f32);
@@ -160,6 +191,181 @@ impl ArmourUpdateQueryItem<'_> {
     }
 }
 
+/// [Evasion] rating which gives a 50% chance to evade a hit
+const EVASION_HALF_CHANCE: f32 = 100.;
+
+/// Maximum chance (in percent) to evade a hit
+const MAX_EVADE_CHANCE: f32 = 75.;
+
+/// Evasion rating, which gives a chance to avoid hits entirely
+#[derive(Component, Clone, Copy, Default, Deref, DerefMut, Debug, Reflect)]
+pub struct Evasion(pub f32);
+
+impl std::fmt::Display for Evasion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.0} Evasion", self.0)
+    }
+}
+
+impl From<u16> for Evasion {
+    fn from(value: u16) -> Self {
+        Evasion(value as f32)
+    }
+}
+
+impl Evasion {
+    pub fn reset(&mut self) {
+        self.0 = 0.;
+    }
+
+    pub fn init(&mut self, base: &BaseEvasion) {
+        self.0 = base.0;
+    }
+
+    pub fn add(&mut self, evasion: &Evasion) {
+        self.0 += evasion.0;
+    }
+
+    pub fn more(&mut self, more: &MoreEvasion) {
+        self.0 += more.0;
+    }
+
+    /// Chance (in percent) to evade a hit
+    pub fn evade_chance(&self) -> f32 {
+        let chance = 100. * self.0 / (self.0 + EVASION_HALF_CHANCE);
+        chance.clamp(0., MAX_EVADE_CHANCE)
+    }
+
+    pub fn try_evade(&self, rng: &mut ThreadRng) -> bool {
+        rng.random_range(0. ..100.) < self.evade_chance()
+    }
+}
+
+/// Base equipment [Evasion]
+#[derive(Component, Default, Debug, Deref, Reflect)]
+#[require(Evasion)]
+pub struct BaseEvasion(pub f32);
+
+impl std::fmt::Display for BaseEvasion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.0} Base evasion", self.0)
+    }
+}
+
+/// Add evasion to base [BaseEvasion]
+#[derive(Component, Default, Clone, Copy, Deref, DerefMut, Debug, Reflect)]
+pub struct MoreEvasion(pub f32);
+
+impl From<u16> for MoreEvasion {
+    fn from(value: u16) -> Self {
+        MoreEvasion(value as f32)
+    }
+}
+
+impl std::fmt::Display for MoreEvasion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.0} more evasion", self.0)
+    }
+}
+
+/// [Evasion] = [BaseEvasion] + [MoreEvasion]
+#[derive(QueryData)]
+#[query_data(mutable, derive(Debug))]
+pub struct EvasionUpdateQuery {
+    base: &'static BaseEvasion,
+    value: &'static mut Evasion,
+    more: Option<&'static MoreEvasion>,
+}
+
+impl EvasionUpdateQueryItem<'_> {
+    pub fn update(&mut self) {
+        self.value.init(self.base);
+        if let Some(more) = self.more {
+            self.value.more(more);
+        }
+    }
+}
+
+/// Maximum energy shield, which absorbs damage before the
+/// [crate::components::character::Life]
+#[derive(Component, Clone, Copy, Default, Deref, DerefMut, Debug, Reflect)]
+pub struct EnergyShield(pub f32);
+
+impl std::fmt::Display for EnergyShield {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.0} Energy shield", self.0)
+    }
+}
+
+impl From<u16> for EnergyShield {
+    fn from(value: u16) -> Self {
+        EnergyShield(value as f32)
+    }
+}
+
+impl EnergyShield {
+    pub fn reset(&mut self) {
+        self.0 = 0.;
+    }
+
+    pub fn init(&mut self, base: &BaseEnergyShield) {
+        self.0 = base.0;
+    }
+
+    pub fn add(&mut self, energy_shield: &EnergyShield) {
+        self.0 += energy_shield.0;
+    }
+
+    pub fn more(&mut self, more: &MoreEnergyShield) {
+        self.0 += more.0;
+    }
+}
+
+/// Base equipment [EnergyShield]
+#[derive(Component, Default, Debug, Deref, Reflect)]
+#[require(EnergyShield)]
+pub struct BaseEnergyShield(pub f32);
+
+impl std::fmt::Display for BaseEnergyShield {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.0} Base energy shield", self.0)
+    }
+}
+
+/// Add energy shield to base [BaseEnergyShield]
+#[derive(Component, Default, Clone, Copy, Deref, DerefMut, Debug, Reflect)]
+pub struct MoreEnergyShield(pub f32);
+
+impl From<u16> for MoreEnergyShield {
+    fn from(value: u16) -> Self {
+        MoreEnergyShield(value as f32)
+    }
+}
+
+impl std::fmt::Display for MoreEnergyShield {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.0} to maximum energy shield", self.0)
+    }
+}
+
+/// [EnergyShield] = [BaseEnergyShield] + [MoreEnergyShield]
+#[derive(QueryData)]
+#[query_data(mutable, derive(Debug))]
+pub struct EnergyShieldUpdateQuery {
+    base: &'static BaseEnergyShield,
+    value: &'static mut EnergyShield,
+    more: Option<&'static MoreEnergyShield>,
+}
+
+impl EnergyShieldUpdateQueryItem<'_> {
+    pub fn update(&mut self) {
+        self.value.init(self.base);
+        if let Some(more) = self.more {
+            self.value.more(more);
+        }
+    }
+}
+
 /// Increase [crate::components::character::BaseMovementSpeed]
 #[derive(Component, Default, Clone, Copy, Deref, DerefMut, Debug, Reflect)]
 pub struct IncreaseMovementSpeed(pub f32);
@@ -211,6 +417,84 @@ impl From<u16> for IncreaseAttackSpeed {
     }
 }
 
+/// Increase the quantity of items dropped by the monsters killed
+#[derive(Component, Default, Clone, Copy, Deref, DerefMut, Debug, Reflect)]
+pub struct IncreaseItemQuantity(pub f32);
+
+impl IncreaseItemQuantity {
+    pub fn reset(&mut self) {
+        self.0 = 0.;
+    }
+
+    pub fn add(&mut self, increase: &IncreaseItemQuantity) {
+        self.0 += increase.0;
+    }
+}
+
+impl std::fmt::Display for IncreaseItemQuantity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "+{:.0}% item quantity", self.0)
+    }
+}
+
+impl From<u16> for IncreaseItemQuantity {
+    fn from(value: u16) -> Self {
+        IncreaseItemQuantity(value as f32)
+    }
+}
+
+/// Increase the rarity of items dropped by the monsters killed
+#[derive(Component, Default, Clone, Copy, Deref, DerefMut, Debug, Reflect)]
+pub struct IncreaseItemRarity(pub f32);
+
+impl IncreaseItemRarity {
+    pub fn reset(&mut self) {
+        self.0 = 0.;
+    }
+
+    pub fn add(&mut self, increase: &IncreaseItemRarity) {
+        self.0 += increase.0;
+    }
+}
+
+impl std::fmt::Display for IncreaseItemRarity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "+{:.0}% item rarity", self.0)
+    }
+}
+
+impl From<u16> for IncreaseItemRarity {
+    fn from(value: u16) -> Self {
+        IncreaseItemRarity(value as f32)
+    }
+}
+
+/// Increase the [super::player::LightRadius] of the player
+#[derive(Component, Default, Clone, Copy, Deref, DerefMut, Debug, Reflect)]
+pub struct IncreaseLightRadius(pub f32);
+
+impl IncreaseLightRadius {
+    pub fn reset(&mut self) {
+        self.0 = 0.;
+    }
+
+    pub fn add(&mut self, increase: &IncreaseLightRadius) {
+        self.0 += increase.0;
+    }
+}
+
+impl std::fmt::Display for IncreaseLightRadius {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "+{:.0}% light radius", self.0)
+    }
+}
+
+impl From<u16> for IncreaseLightRadius {
+    fn from(value: u16) -> Self {
+        IncreaseLightRadius(value as f32)
+    }
+}
+
 /// Pierce chance
 #[derive(Component, Default, Clone, Copy, Deref, DerefMut, Debug, Reflect)]
 pub struct PierceChance(pub f32);
@@ -327,3 +611,92 @@ impl From<u16> for IncreaseAreaOfEffect {
         IncreaseAreaOfEffect(value as f32)
     }
 }
+
+/// Strength attribute, each point gives [Strength::LIFE_PER_POINT] life
+#[derive(Component, Default, Clone, Copy, Deref, DerefMut, Debug, Reflect)]
+pub struct Strength(pub f32);
+
+impl Strength {
+    pub const LIFE_PER_POINT: f32 = 0.2;
+
+    pub fn add(&mut self, strength: &Strength) {
+        self.0 += strength.0;
+    }
+
+    /// [MoreLife] given by the strength
+    pub fn more_life(&self) -> MoreLife {
+        MoreLife(self.0 * Self::LIFE_PER_POINT)
+    }
+}
+
+impl std::fmt::Display for Strength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "+{:.0} to strength", self.0)
+    }
+}
+
+impl From<u16> for Strength {
+    fn from(value: u16) -> Self {
+        Strength(value as f32)
+    }
+}
+
+/// Dexterity attribute, each point gives [Dexterity::ATTACK_SPEED_PER_POINT]%
+/// increased attack speed
+#[derive(Component, Default, Clone, Copy, Deref, DerefMut, Debug, Reflect)]
+pub struct Dexterity(pub f32);
+
+impl Dexterity {
+    pub const ATTACK_SPEED_PER_POINT: f32 = 0.2;
+
+    pub fn add(&mut self, dexterity: &Dexterity) {
+        self.0 += dexterity.0;
+    }
+
+    /// [IncreaseAttackSpeed] given by the dexterity
+    pub fn increase_attack_speed(&self) -> IncreaseAttackSpeed {
+        IncreaseAttackSpeed(self.0 * Self::ATTACK_SPEED_PER_POINT)
+    }
+}
+
+impl std::fmt::Display for Dexterity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "+{:.0} to dexterity", self.0)
+    }
+}
+
+impl From<u16> for Dexterity {
+    fn from(value: u16) -> Self {
+        Dexterity(value as f32)
+    }
+}
+
+/// Intelligence attribute, each point gives [Intelligence::AREA_PER_POINT]%
+/// increased area of effect
+#[derive(Component, Default, Clone, Copy, Deref, DerefMut, Debug, Reflect)]
+pub struct Intelligence(pub f32);
+
+impl Intelligence {
+    pub const AREA_PER_POINT: f32 = 0.2;
+
+    pub fn add(&mut self, intelligence: &Intelligence) {
+        self.0 += intelligence.0;
+    }
+
+    /// [IncreaseAreaOfEffect] given by the intelligence
+    pub fn increase_area_of_effect(&self) -> IncreaseAreaOfEffect {
+        IncreaseAreaOfEffect(self.0 * Self::AREA_PER_POINT)
+    }
+}
+
+impl std::fmt::Display for Intelligence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "+{:.0} to intelligence", self.0)
+    }
+}
+
+impl From<u16> for Intelligence {
+    fn from(value: u16) -> Self {
+        Intelligence(value as f32)
+    }
+}
@@ -1,6 +1,49 @@
 use bevy::prelude::*;
+use serde::Deserialize;
 use std::ops::Range;
 
+///
+/// Sprite sheet of a character, defined in a data file
+///
+#[derive(Debug, Deserialize)]
+pub struct SpriteSheet {
+    pub path: String,
+    pub tile_size: (u32, u32),
+    pub columns: u32,
+    pub rows: u32,
+    /// Atlas indexes used when the character is walking
+    pub walk_frames: Vec<usize>,
+    /// Atlas index used when the character doesn't move, the first
+    /// walk frame if not set
+    #[serde(default)]
+    pub idle_frame: Option<usize>,
+}
+
+impl SpriteSheet {
+    pub fn atlas_layout(&self) -> TextureAtlasLayout {
+        TextureAtlasLayout::from_grid(
+            UVec2::new(self.tile_size.0, self.tile_size.1),
+            self.columns,
+            self.rows,
+            None,
+            None,
+        )
+    }
+
+    pub fn idle_frame(&self) -> usize {
+        self.idle_frame.unwrap_or(self.walk_frames[0])
+    }
+
+    /// The walk frame following the atlas `index`
+    pub fn next_walk_frame(&self, index: usize) -> usize {
+        self.walk_frames
+            .iter()
+            .position(|&i| i == index)
+            .map(|pos| self.walk_frames[(pos + 1) % self.walk_frames.len()])
+            .unwrap_or(self.walk_frames[0])
+    }
+}
+
 ///
 /// The animation [AnimationTimer] component indicates that the entity should
 /// be animated
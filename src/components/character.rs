@@ -1,12 +1,15 @@
 use super::{
     affix::{
-        Armour, IncreaseAreaOfEffect, IncreaseAttackSpeed, IncreaseDamage, IncreaseMaxLife,
-        IncreaseMovementSpeed, LifeRegen, MoreDamage, MoreLife, PierceChance,
+        Armour, Dexterity, EnergyShield, Evasion, IncreaseAreaOfEffect, IncreaseAttackSpeed,
+        IncreaseDamage, IncreaseMaxLife, IncreaseMovementSpeed, Intelligence, LifeLeech, LifeRegen,
+        MoreDamage, MoreLife, PierceChance, Strength,
     },
-    damage::Damage,
+    damage::{Damage, DamageKind, DamageSource},
 };
-use bevy::prelude::*;
+use bevy::{ecs::query::QueryData, prelude::*};
 use bevy_rapier2d::prelude::*;
+use serde::Deserialize;
+use std::time::Duration;
 
 /// Required components for all characters
 #[derive(Component, Default)]
@@ -16,12 +19,18 @@ use bevy_rapier2d::prelude::*;
     CharacterLevel,
     BaseLife,
     BaseMovementSpeed,
+    BaseAttributes,
     IncreaseAttackSpeed,
     PierceChance,
     MoreDamage,
     IncreaseDamage,
     IncreaseAreaOfEffect,
     Armour,
+    Evasion,
+    EnergyShield,
+    CurrentEnergyShield,
+    LifeLeech,
+    LifeLeechPool,
     Transform,
     RigidBody::Dynamic,
     Velocity,
@@ -62,6 +71,56 @@ impl MovementAction {
     }
 }
 
+/// Delay (in seconds) without taking damage before the energy shield recharges
+const ENERGY_SHIELD_RECHARGE_DELAY: f32 = 2.;
+
+/// Part of the maximum [EnergyShield] recharged per second
+const ENERGY_SHIELD_RECHARGE_RATE: f32 = 0.33;
+
+/// Represent the current energy shield of a character, which absorbs the damage
+/// before the [Life].
+///
+/// It starts to recharge when the character didn't take damage since
+/// [ENERGY_SHIELD_RECHARGE_DELAY] seconds.
+#[derive(Component, Debug, Reflect)]
+pub struct CurrentEnergyShield {
+    value: f32,
+    recharge_delay: Timer,
+}
+
+impl Default for CurrentEnergyShield {
+    fn default() -> Self {
+        CurrentEnergyShield {
+            value: 0.,
+            recharge_delay: Timer::from_seconds(ENERGY_SHIELD_RECHARGE_DELAY, TimerMode::Once),
+        }
+    }
+}
+
+impl CurrentEnergyShield {
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Absorb the `damage`, returning the damage which remains for the [Life]
+    ///
+    /// It restarts the recharge delay.
+    pub fn absorb(&mut self, damage: Damage) -> Damage {
+        self.recharge_delay.reset();
+        let absorbed = damage.0.min(self.value);
+        self.value -= absorbed;
+        Damage(damage.0 - absorbed)
+    }
+
+    pub fn recharge(&mut self, delta: Duration, max: &EnergyShield) {
+        self.recharge_delay.tick(delta);
+        if self.recharge_delay.finished() {
+            self.value += **max * ENERGY_SHIELD_RECHARGE_RATE * delta.as_secs_f32();
+        }
+        self.value = self.value.min(**max);
+    }
+}
+
 /// Represent the initial life of a character
 #[derive(Component, Default, Deref, Clone, Copy, Reflect)]
 #[require(Life, MaxLife, IncreaseMaxLife, LifeRegen)]
@@ -98,6 +157,29 @@ impl Life {
     }
 }
 
+/// Maximum part of the [MaxLife] recovered per second by leech
+const MAX_LIFE_LEECH_RATE: f32 = 0.2;
+
+/// Life leeched by a character, not yet recovered.
+///
+/// The leeched life is recovered over time, with a maximum rate of
+/// [MAX_LIFE_LEECH_RATE] of the [MaxLife] per second.
+#[derive(Component, Default, Deref, Clone, Copy, Debug, Reflect)]
+pub struct LifeLeechPool(f32);
+
+impl LifeLeechPool {
+    pub fn add(&mut self, life: f32) {
+        self.0 += life;
+    }
+
+    /// Recover the leeched life for `delta` seconds
+    pub fn recover(&mut self, life: &mut Life, max_life: MaxLife, delta: f32) {
+        let recovered = self.0.min(*max_life * MAX_LIFE_LEECH_RATE * delta);
+        self.0 -= recovered;
+        life.regenerate(recovered, max_life);
+    }
+}
+
 /// Represent the max life of a character
 ///
 /// It's calculated with the [BaseLife], [crate::components::affix::MoreLife]s
@@ -129,6 +211,7 @@ impl std::fmt::Display for MaxLife {
 #[derive(Event)]
 pub struct HitEvent {
     pub damager: Entity,
+    pub source: DamageSource,
     pub damage: Damage,
 }
 
@@ -155,13 +238,40 @@ impl MovementSpeed {
 #[derive(Component, Default, Deref, DerefMut, Reflect)]
 pub struct CharacterLevel(pub u16);
 
+/// Initial attributes of a character, before adding the [Strength],
+/// [Dexterity] and [Intelligence] affixes
+#[derive(Component, Default, Clone, Copy, Debug, Reflect, Deserialize)]
+#[require(Strength, Dexterity, Intelligence)]
+pub struct BaseAttributes {
+    pub strength: f32,
+    pub dexterity: f32,
+    pub intelligence: f32,
+}
+
+/// Level and attributes of a character, used to check the
+/// [crate::components::item::Requirements] of an item
+#[derive(QueryData)]
+pub struct CharacterAttributes {
+    pub level: &'static CharacterLevel,
+    pub strength: &'static Strength,
+    pub dexterity: &'static Dexterity,
+    pub intelligence: &'static Intelligence,
+}
+
 /// Event to notify a character loose life
-#[derive(Event, Deref)]
-pub struct LooseLifeEvent(pub Damage);
+#[derive(Event)]
+pub struct LooseLifeEvent {
+    pub damage: Damage,
+    pub kind: DamageKind,
+    pub source: DamageSource,
+}
 
 /// Event to notify a character is dying.
 #[derive(Event)]
-pub struct CharacterDyingEvent;
+pub struct CharacterDyingEvent {
+    /// Source of the damage which killed the character
+    pub killer: DamageSource,
+}
 
 /// Event to notify a character has died
 ///
@@ -0,0 +1,42 @@
+use super::{animation::SpriteSheet, character::BaseAttributes, skills::SkillKind};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+///
+/// Definition of a character class that the player can choose
+///
+#[derive(Debug, Deserialize)]
+pub struct CharacterClassDefinition {
+    pub name: String,
+    pub description: String,
+    pub sprite: SpriteSheet,
+    pub life: f32,
+    pub movement_speed: f32,
+    pub attributes: BaseAttributes,
+    /// Skill book given at the start of the run
+    pub starting_skill: SkillKind,
+    /// Start node in the passive tree, allocated for free
+    pub passive_start: usize,
+}
+
+///
+/// All character classes, loaded from `assets/classes.ron`.
+/// The index of a [CharacterClassDefinition] is the class.
+///
+#[derive(Resource, Debug, Default, Deref, Deserialize)]
+pub struct CharacterClasses(pub Vec<CharacterClassDefinition>);
+
+impl CharacterClasses {
+    pub fn get_class(&self, class: usize) -> &CharacterClassDefinition {
+        self.get(class).expect("Character class out of range !")
+    }
+}
+
+///
+/// Class chosen for the run, which is the index of its [CharacterClassDefinition].
+///
+/// It is inserted as a resource when the class is selected, and on the
+/// [crate::components::player::Player].
+///
+#[derive(Resource, Component, Default, Clone, Copy, Deref, Reflect)]
+pub struct PlayerClass(pub usize);
@@ -91,12 +91,23 @@ impl DamageOverTime {
     }
 }
 
+/// The [DamageSource] of the [DamageOverTime] a character is currently taking
+#[derive(Component, Clone, Copy, Deref, Reflect)]
+pub struct DamageOverTimeSource(pub DamageSource);
+
 ///
 /// Damage
 ///
 #[derive(Clone, Copy, Component, Default, Deref, Reflect)]
 pub struct Damage(pub f32);
 
+/// How the [Damage] was dealt
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum DamageKind {
+    Hit,
+    OverTime,
+}
+
 impl std::ops::AddAssign for Damage {
     fn add_assign(&mut self, rhs: Self) {
         self.0 += rhs.0
@@ -138,11 +149,36 @@ impl Damager {
     }
 }
 
+/// Source of the damage dealt by a [Damager]: the
+/// [crate::components::character::Character] which owns it, and the
+/// [crate::components::skills::Skill] which spawned it, if any
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub struct DamageSource {
+    pub owner: Entity,
+    pub skill: Option<Entity>,
+}
+
+impl DamageSource {
+    /// Damage of a `skill` of the `owner`
+    pub fn from_skill(owner: Entity, skill: Entity) -> Self {
+        DamageSource {
+            owner,
+            skill: Some(skill),
+        }
+    }
+
+    /// Damage of the `owner` itself, when touching a character
+    pub fn from_character(owner: Entity) -> Self {
+        DamageSource { owner, skill: None }
+    }
+}
+
 /// Helper to spawn required [Damager] dynamic components
 #[derive(Bundle)]
 pub struct DamagerParams {
     pub transform: Transform,
     pub collision_groups: CollisionGroups,
+    pub source: DamageSource,
 }
 
 /// A [Projectile] is an [Damager] which is sent, and can pierce
@@ -1,6 +1,9 @@
 use super::{common::AffixProvider, Equipment};
 use crate::components::{
-    affix::{BaseArmour, MoreArmour, MoreLife, PierceChance},
+    affix::{
+        BaseArmour, IncreaseItemQuantity, IncreaseItemRarity, Intelligence, LifeLeech, MoreArmour,
+        MoreLife, PierceChance,
+    },
     item::{AffixConfigGenerator, ItemDescriptor, ItemRarity, ItemSpawnBundle},
     orb::OrbAction,
     rng_provider::RngKindProvider,
@@ -14,7 +17,11 @@ use rand::{rngs::ThreadRng, Rng};
     Equipment::Amulet,
     MoreArmour,
     MoreLife,
-    PierceChance
+    PierceChance,
+    IncreaseItemQuantity,
+    IncreaseItemRarity,
+    Intelligence,
+    LifeLeech
 )]
 pub struct Amulet {
     affix_provider: AmuletAffixProvider,
@@ -23,6 +30,7 @@ pub struct Amulet {
 
 impl ItemSpawnBundle for Amulet {
     type Implicit = BaseArmour;
+    const ATTRIBUTES: (u16, u16, u16) = (0, 0, 0);
 
     fn new(ilevel: u16, rng: &mut ThreadRng) -> (Self, Self::Implicit) {
         let implicit = BaseArmour(rng.random_range(1..=4) as f32);
@@ -59,7 +67,15 @@ impl ItemDescriptor for Amulet {
 impl OrbAction for Amulet {
     fn reset_affixes(&mut self, ecommands: &mut EntityCommands) {
         self.affix_provider.reset();
-        ecommands.insert((MoreArmour(0.), MoreLife(0.), PierceChance(0.)));
+        ecommands.insert((
+            MoreArmour(0.),
+            MoreLife(0.),
+            PierceChance(0.),
+            IncreaseItemQuantity(0.),
+            IncreaseItemRarity(0.),
+            Intelligence(0.),
+            LifeLeech(0.),
+        ));
     }
 
     fn add_affixes(&mut self, ecommands: &mut EntityCommands, count: u16, rng: &mut ThreadRng) {
@@ -81,6 +97,26 @@ impl OrbAction for Amulet {
                     self.affix_provider
                         .set::<PierceChance, _>(ecommands, value_and_tier);
                 }
+                Some(AmuletAffixKind::IncreaseItemQuantity) => {
+                    let value_and_tier = INCR_ITEM_QUANTITY_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<IncreaseItemQuantity, _>(ecommands, value_and_tier);
+                }
+                Some(AmuletAffixKind::IncreaseItemRarity) => {
+                    let value_and_tier = INCR_ITEM_RARITY_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<IncreaseItemRarity, _>(ecommands, value_and_tier);
+                }
+                Some(AmuletAffixKind::Intelligence) => {
+                    let value_and_tier = INTELLIGENCE_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<Intelligence, _>(ecommands, value_and_tier);
+                }
+                Some(AmuletAffixKind::LifeLeech) => {
+                    let value_and_tier = LIFE_LEECH_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<LifeLeech, _>(ecommands, value_and_tier);
+                }
                 None => {}
             }
         }
@@ -93,6 +129,10 @@ enum AmuletAffixKind {
     MoreLife,
     MoreArmour,
     PierceChance,
+    IncreaseItemQuantity,
+    IncreaseItemRarity,
+    Intelligence,
+    LifeLeech,
 }
 
 const MORE_ARMOUR_RANGES: &[(u16, (u16, u16), usize); 3] =
@@ -104,6 +144,18 @@ const MORE_LIFE_RANGES: &[(u16, (u16, u16), usize); 3] =
 const PIERCE_CHANCE_RANGES: &[(u16, (u16, u16), usize); 3] =
     &[(4, (3, 9), 10), (10, (10, 24), 10), (17, (25, 29), 10)];
 
+const INCR_ITEM_QUANTITY_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(4, (2, 5), 10), (10, (6, 10), 10), (17, (11, 15), 10)];
+
+const INCR_ITEM_RARITY_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(4, (5, 10), 10), (10, (11, 20), 10), (17, (21, 30), 10)];
+
+const INTELLIGENCE_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(4, (3, 6), 20), (10, (7, 12), 20), (17, (13, 20), 20)];
+
+const LIFE_LEECH_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(5, (1, 2), 10), (12, (3, 4), 10), (20, (5, 6), 10)];
+
 #[derive(Deref, DerefMut)]
 struct AmuletAffixProvider(AffixProvider<AmuletAffixKind>);
 
@@ -121,6 +173,19 @@ impl AmuletAffixProvider {
             PIERCE_CHANCE_RANGES.weight(ilevel),
         );
 
+        provider.add(
+            AmuletAffixKind::IncreaseItemQuantity,
+            INCR_ITEM_QUANTITY_RANGES.weight(ilevel),
+        );
+        provider.add(
+            AmuletAffixKind::IncreaseItemRarity,
+            INCR_ITEM_RARITY_RANGES.weight(ilevel),
+        );
+        provider.add(
+            AmuletAffixKind::Intelligence,
+            INTELLIGENCE_RANGES.weight(ilevel),
+        );
+        provider.add(AmuletAffixKind::LifeLeech, LIFE_LEECH_RANGES.weight(ilevel));
         AmuletAffixProvider(AffixProvider::new::<Amulet>(ilevel, provider))
     }
 }
@@ -1,6 +1,6 @@
-use super::{common::AffixProvider, Equipment};
+use super::{common::AffixProvider, DefenceImplicit, DefenceKind, Equipment};
 use crate::components::{
-    affix::{BaseArmour, LifeRegen, MoreArmour, MoreLife},
+    affix::{LifeRegen, MoreArmour, MoreEnergyShield, MoreEvasion, MoreLife, Strength},
     item::{AffixConfigGenerator, ItemDescriptor, ItemRarity, ItemSpawnBundle},
     orb::OrbAction,
     rng_provider::RngKindProvider,
@@ -13,25 +13,36 @@ use rand::{rngs::ThreadRng, Rng};
     Name::new("BodyArmour"),
     Equipment::BodyArmour,
     MoreArmour,
+    MoreEvasion,
+    MoreEnergyShield,
     MoreLife,
-    LifeRegen
+    LifeRegen,
+    Strength
 )]
 pub struct BodyArmour {
+    defence: DefenceKind,
     affix_provider: BodyArmourAffixProvider,
     implicit_label: String,
 }
 
 impl ItemSpawnBundle for BodyArmour {
-    type Implicit = BaseArmour;
+    type Implicit = DefenceImplicit;
+    const ATTRIBUTES: (u16, u16, u16) = (2, 0, 0);
 
     fn new(ilevel: u16, rng: &mut ThreadRng) -> (Self, Self::Implicit) {
-        let implicit = BaseArmour(rng.random_range(1..=4) as f32);
+        let defence = DefenceKind::gen(rng);
+        let implicit = DefenceImplicit::new(defence, rng.random_range(1..=4) as f32);
         let item = BodyArmour {
+            defence,
             affix_provider: BodyArmourAffixProvider::new(ilevel),
             implicit_label: implicit.to_string(),
         };
         (item, implicit)
     }
+
+    fn attributes(&self) -> (u16, u16, u16) {
+        self.defence.attributes()
+    }
 }
 
 impl ItemDescriptor for BodyArmour {
@@ -48,10 +59,16 @@ impl ItemDescriptor for BodyArmour {
     }
 
     fn tile_index(&self, rarity: ItemRarity) -> usize {
-        match rarity {
-            ItemRarity::Normal => 0,
-            ItemRarity::Magic => 2,
-            ItemRarity::Rare => 3,
+        match (self.defence, rarity) {
+            (DefenceKind::Armour, ItemRarity::Normal) => 0,
+            (DefenceKind::Armour, ItemRarity::Magic) => 2,
+            (DefenceKind::Armour, ItemRarity::Rare) => 3,
+            (DefenceKind::Evasion, ItemRarity::Normal) => 1,
+            (DefenceKind::Evasion, ItemRarity::Magic) => 1,
+            (DefenceKind::Evasion, ItemRarity::Rare) => 4,
+            (DefenceKind::EnergyShield, ItemRarity::Normal) => 1,
+            (DefenceKind::EnergyShield, ItemRarity::Magic) => 2,
+            (DefenceKind::EnergyShield, ItemRarity::Rare) => 4,
         }
     }
 }
@@ -59,17 +76,33 @@ impl ItemDescriptor for BodyArmour {
 impl OrbAction for BodyArmour {
     fn reset_affixes(&mut self, ecommands: &mut EntityCommands) {
         self.affix_provider.reset();
-        ecommands.insert((MoreArmour(0.), MoreLife(0.), LifeRegen(0.)));
+        ecommands.insert((
+            MoreArmour(0.),
+            MoreEvasion(0.),
+            MoreEnergyShield(0.),
+            MoreLife(0.),
+            LifeRegen(0.),
+            Strength(0.),
+        ));
     }
 
     fn add_affixes(&mut self, ecommands: &mut EntityCommands, count: u16, rng: &mut ThreadRng) {
         let ilevel = self.affix_provider.ilevel();
         for _ in 0..count {
             match self.affix_provider.gen(rng) {
-                Some(BodyArmourAffixKind::MoreArmour) => {
-                    let value_and_tier = MORE_ARMOUR_RANGES.generate(ilevel, rng);
-                    self.affix_provider
-                        .set::<MoreArmour, _>(ecommands, value_and_tier);
+                Some(BodyArmourAffixKind::MoreDefence) => {
+                    let value_and_tier = MORE_DEFENCE_RANGES.generate(ilevel, rng);
+                    match self.defence {
+                        DefenceKind::Armour => self
+                            .affix_provider
+                            .set::<MoreArmour, _>(ecommands, value_and_tier),
+                        DefenceKind::Evasion => self
+                            .affix_provider
+                            .set::<MoreEvasion, _>(ecommands, value_and_tier),
+                        DefenceKind::EnergyShield => self
+                            .affix_provider
+                            .set::<MoreEnergyShield, _>(ecommands, value_and_tier),
+                    }
                 }
                 Some(BodyArmourAffixKind::AddLife) => {
                     let value_and_tier = MORE_LIFE_RANGES.generate(ilevel, rng);
@@ -81,6 +114,11 @@ impl OrbAction for BodyArmour {
                     self.affix_provider
                         .set::<LifeRegen, _>(ecommands, value_and_tier);
                 }
+                Some(BodyArmourAffixKind::Strength) => {
+                    let value_and_tier = STRENGTH_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<Strength, _>(ecommands, value_and_tier);
+                }
                 None => {}
             }
         }
@@ -90,11 +128,12 @@ impl OrbAction for BodyArmour {
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 enum BodyArmourAffixKind {
     AddLife,
-    MoreArmour,
+    MoreDefence,
     LifeRegen,
+    Strength,
 }
 
-const MORE_ARMOUR_RANGES: &[(u16, (u16, u16), usize); 3] =
+const MORE_DEFENCE_RANGES: &[(u16, (u16, u16), usize); 3] =
     &[(4, (3, 9), 20), (10, (10, 24), 20), (17, (25, 29), 20)];
 
 const MORE_LIFE_RANGES: &[(u16, (u16, u16), usize); 3] =
@@ -103,6 +142,9 @@ const MORE_LIFE_RANGES: &[(u16, (u16, u16), usize); 3] =
 const LIFE_REGEN_RANGES: &[(u16, (u16, u16), usize); 3] =
     &[(1, (1, 2), 20), (7, (2, 8), 20), (19, (8, 16), 20)];
 
+const STRENGTH_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(4, (3, 6), 20), (10, (7, 12), 20), (17, (13, 20), 20)];
+
 #[derive(Deref, DerefMut)]
 struct BodyArmourAffixProvider(AffixProvider<BodyArmourAffixKind>);
 
@@ -110,8 +152,8 @@ impl BodyArmourAffixProvider {
     pub fn new(ilevel: u16) -> Self {
         let mut provider = RngKindProvider::default();
         provider.add(
-            BodyArmourAffixKind::MoreArmour,
-            MORE_ARMOUR_RANGES.weight(ilevel),
+            BodyArmourAffixKind::MoreDefence,
+            MORE_DEFENCE_RANGES.weight(ilevel),
         );
         provider.add(
             BodyArmourAffixKind::AddLife,
@@ -121,6 +163,10 @@ impl BodyArmourAffixProvider {
             BodyArmourAffixKind::LifeRegen,
             LIFE_REGEN_RANGES.weight(ilevel),
         );
+        provider.add(
+            BodyArmourAffixKind::Strength,
+            STRENGTH_RANGES.weight(ilevel),
+        );
         BodyArmourAffixProvider(AffixProvider::new::<BodyArmour>(ilevel, provider))
     }
 }
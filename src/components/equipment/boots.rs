@@ -1,6 +1,9 @@
-use super::{common::AffixProvider, Equipment};
+use super::{common::AffixProvider, DefenceImplicit, DefenceKind, Equipment};
 use crate::components::{
-    affix::{BaseArmour, IncreaseMovementSpeed, MoreArmour, MoreLife},
+    affix::{
+        Dexterity, IncreaseItemQuantity, IncreaseItemRarity, IncreaseMovementSpeed, MoreArmour,
+        MoreEnergyShield, MoreEvasion, MoreLife,
+    },
     item::{AffixConfigGenerator, ItemDescriptor, ItemRarity, ItemSpawnBundle},
     orb::OrbAction,
     rng_provider::RngKindProvider,
@@ -13,25 +16,38 @@ use rand::{rngs::ThreadRng, Rng};
     Name::new("Boots"),
     Equipment::Boots,
     MoreArmour,
+    MoreEvasion,
+    MoreEnergyShield,
     MoreLife,
-    IncreaseMovementSpeed
+    IncreaseMovementSpeed,
+    IncreaseItemQuantity,
+    IncreaseItemRarity,
+    Dexterity
 )]
 pub struct Boots {
+    defence: DefenceKind,
     affix_provider: BootsAffixProvider,
     implicit_label: String,
 }
 
 impl ItemSpawnBundle for Boots {
-    type Implicit = BaseArmour;
+    type Implicit = DefenceImplicit;
+    const ATTRIBUTES: (u16, u16, u16) = (0, 2, 0);
 
     fn new(ilevel: u16, rng: &mut ThreadRng) -> (Self, Self::Implicit) {
-        let implicit = BaseArmour(rng.random_range(1..=4) as f32);
+        let defence = DefenceKind::gen(rng);
+        let implicit = DefenceImplicit::new(defence, rng.random_range(1..=4) as f32);
         let item = Boots {
+            defence,
             affix_provider: BootsAffixProvider::new(ilevel),
             implicit_label: implicit.to_string(),
         };
         (item, implicit)
     }
+
+    fn attributes(&self) -> (u16, u16, u16) {
+        self.defence.attributes()
+    }
 }
 
 impl ItemDescriptor for Boots {
@@ -48,10 +64,16 @@ impl ItemDescriptor for Boots {
     }
 
     fn tile_index(&self, rarity: ItemRarity) -> usize {
-        match rarity {
-            ItemRarity::Normal => 63,
-            ItemRarity::Magic => 65,
-            ItemRarity::Rare => 66,
+        match (self.defence, rarity) {
+            (DefenceKind::Armour, ItemRarity::Normal) => 63,
+            (DefenceKind::Armour, ItemRarity::Magic) => 65,
+            (DefenceKind::Armour, ItemRarity::Rare) => 66,
+            (DefenceKind::Evasion, ItemRarity::Normal) => 64,
+            (DefenceKind::Evasion, ItemRarity::Magic) => 64,
+            (DefenceKind::Evasion, ItemRarity::Rare) => 67,
+            (DefenceKind::EnergyShield, ItemRarity::Normal) => 64,
+            (DefenceKind::EnergyShield, ItemRarity::Magic) => 65,
+            (DefenceKind::EnergyShield, ItemRarity::Rare) => 67,
         }
     }
 }
@@ -59,17 +81,35 @@ impl ItemDescriptor for Boots {
 impl OrbAction for Boots {
     fn reset_affixes(&mut self, ecommands: &mut EntityCommands) {
         self.affix_provider.reset();
-        ecommands.insert((MoreArmour(0.), MoreLife(0.), IncreaseMovementSpeed(0.)));
+        ecommands.insert((
+            MoreArmour(0.),
+            MoreEvasion(0.),
+            MoreEnergyShield(0.),
+            MoreLife(0.),
+            IncreaseMovementSpeed(0.),
+            IncreaseItemQuantity(0.),
+            IncreaseItemRarity(0.),
+            Dexterity(0.),
+        ));
     }
 
     fn add_affixes(&mut self, ecommands: &mut EntityCommands, count: u16, rng: &mut ThreadRng) {
         let ilevel = self.affix_provider.ilevel();
         for _ in 0..count {
             match self.affix_provider.gen(rng) {
-                Some(BootsAffixKind::MoreArmour) => {
-                    let value_and_tier = MORE_ARMOUR_RANGES.generate(ilevel, rng);
-                    self.affix_provider
-                        .set::<MoreArmour, _>(ecommands, value_and_tier);
+                Some(BootsAffixKind::MoreDefence) => {
+                    let value_and_tier = MORE_DEFENCE_RANGES.generate(ilevel, rng);
+                    match self.defence {
+                        DefenceKind::Armour => self
+                            .affix_provider
+                            .set::<MoreArmour, _>(ecommands, value_and_tier),
+                        DefenceKind::Evasion => self
+                            .affix_provider
+                            .set::<MoreEvasion, _>(ecommands, value_and_tier),
+                        DefenceKind::EnergyShield => self
+                            .affix_provider
+                            .set::<MoreEnergyShield, _>(ecommands, value_and_tier),
+                    }
                 }
                 Some(BootsAffixKind::AddLife) => {
                     let value_and_tier = MORE_LIFE_RANGES.generate(ilevel, rng);
@@ -81,6 +121,21 @@ impl OrbAction for Boots {
                     self.affix_provider
                         .set::<IncreaseMovementSpeed, _>(ecommands, value_and_tier);
                 }
+                Some(BootsAffixKind::IncreaseItemQuantity) => {
+                    let value_and_tier = INCR_ITEM_QUANTITY_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<IncreaseItemQuantity, _>(ecommands, value_and_tier);
+                }
+                Some(BootsAffixKind::IncreaseItemRarity) => {
+                    let value_and_tier = INCR_ITEM_RARITY_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<IncreaseItemRarity, _>(ecommands, value_and_tier);
+                }
+                Some(BootsAffixKind::Dexterity) => {
+                    let value_and_tier = DEXTERITY_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<Dexterity, _>(ecommands, value_and_tier);
+                }
                 None => {}
             }
         }
@@ -90,11 +145,14 @@ impl OrbAction for Boots {
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 enum BootsAffixKind {
     AddLife,
-    MoreArmour,
+    MoreDefence,
     IncreaseMovementSpeed,
+    IncreaseItemQuantity,
+    IncreaseItemRarity,
+    Dexterity,
 }
 
-const MORE_ARMOUR_RANGES: &[(u16, (u16, u16), usize); 3] =
+const MORE_DEFENCE_RANGES: &[(u16, (u16, u16), usize); 3] =
     &[(4, (3, 9), 20), (10, (10, 24), 20), (17, (25, 29), 20)];
 
 const MORE_LIFE_RANGES: &[(u16, (u16, u16), usize); 3] =
@@ -103,6 +161,15 @@ const MORE_LIFE_RANGES: &[(u16, (u16, u16), usize); 3] =
 const INCR_MOVEMENT_SPEED_RANGES: &[(u16, (u16, u16), usize); 3] =
     &[(4, (3, 9), 20), (10, (10, 24), 20), (17, (25, 29), 20)];
 
+const INCR_ITEM_QUANTITY_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(4, (2, 5), 10), (10, (6, 10), 10), (17, (11, 15), 10)];
+
+const INCR_ITEM_RARITY_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(4, (5, 10), 10), (10, (11, 20), 10), (17, (21, 30), 10)];
+
+const DEXTERITY_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(4, (3, 6), 20), (10, (7, 12), 20), (17, (13, 20), 20)];
+
 #[derive(Deref, DerefMut)]
 struct BootsAffixProvider(AffixProvider<BootsAffixKind>);
 
@@ -110,14 +177,23 @@ impl BootsAffixProvider {
     pub fn new(ilevel: u16) -> Self {
         let mut provider = RngKindProvider::default();
         provider.add(
-            BootsAffixKind::MoreArmour,
-            MORE_ARMOUR_RANGES.weight(ilevel),
+            BootsAffixKind::MoreDefence,
+            MORE_DEFENCE_RANGES.weight(ilevel),
         );
         provider.add(BootsAffixKind::AddLife, MORE_LIFE_RANGES.weight(ilevel));
         provider.add(
             BootsAffixKind::IncreaseMovementSpeed,
             INCR_MOVEMENT_SPEED_RANGES.weight(ilevel),
         );
+        provider.add(
+            BootsAffixKind::IncreaseItemQuantity,
+            INCR_ITEM_QUANTITY_RANGES.weight(ilevel),
+        );
+        provider.add(
+            BootsAffixKind::IncreaseItemRarity,
+            INCR_ITEM_RARITY_RANGES.weight(ilevel),
+        );
+        provider.add(BootsAffixKind::Dexterity, DEXTERITY_RANGES.weight(ilevel));
         BootsAffixProvider(AffixProvider::new::<Boots>(ilevel, provider))
     }
 }
@@ -1,6 +1,9 @@
-use super::{common::AffixProvider, Equipment};
+use super::{common::AffixProvider, DefenceImplicit, DefenceKind, Equipment};
 use crate::components::{
-    affix::{BaseArmour, LifeRegen, MoreArmour, MoreLife},
+    affix::{
+        IncreaseItemQuantity, IncreaseItemRarity, IncreaseLightRadius, LifeRegen, MoreArmour,
+        MoreEnergyShield, MoreEvasion, MoreLife, Strength,
+    },
     item::{AffixConfigGenerator, ItemDescriptor, ItemRarity, ItemSpawnBundle},
     orb::OrbAction,
     rng_provider::RngKindProvider,
@@ -13,24 +16,38 @@ use rand::{rngs::ThreadRng, Rng};
     Name::new("Helmet"),
     Equipment::Helmet,
     MoreArmour,
+    MoreEvasion,
+    MoreEnergyShield,
     MoreLife,
-    LifeRegen
+    LifeRegen,
+    IncreaseItemQuantity,
+    IncreaseItemRarity,
+    IncreaseLightRadius,
+    Strength
 )]
 pub struct Helmet {
+    defence: DefenceKind,
     affix_provider: HelmetAffixProvider,
     implicit: String,
 }
 
 impl ItemSpawnBundle for Helmet {
-    type Implicit = BaseArmour;
+    type Implicit = DefenceImplicit;
+    const ATTRIBUTES: (u16, u16, u16) = (2, 0, 0);
     fn new(ilevel: u16, rng: &mut ThreadRng) -> (Self, Self::Implicit) {
-        let implicit = BaseArmour(rng.random_range(1..=4) as f32);
+        let defence = DefenceKind::gen(rng);
+        let implicit = DefenceImplicit::new(defence, rng.random_range(1..=4) as f32);
         let item = Helmet {
+            defence,
             affix_provider: HelmetAffixProvider::new(ilevel),
             implicit: implicit.to_string(),
         };
         (item, implicit)
     }
+
+    fn attributes(&self) -> (u16, u16, u16) {
+        self.defence.attributes()
+    }
 }
 
 impl ItemDescriptor for Helmet {
@@ -47,10 +64,16 @@ impl ItemDescriptor for Helmet {
     }
 
     fn tile_index(&self, rarity: ItemRarity) -> usize {
-        match rarity {
-            ItemRarity::Normal => 182,
-            ItemRarity::Magic => 184,
-            ItemRarity::Rare => 185,
+        match (self.defence, rarity) {
+            (DefenceKind::Armour, ItemRarity::Normal) => 182,
+            (DefenceKind::Armour, ItemRarity::Magic) => 184,
+            (DefenceKind::Armour, ItemRarity::Rare) => 185,
+            (DefenceKind::Evasion, ItemRarity::Normal) => 187,
+            (DefenceKind::Evasion, ItemRarity::Magic) => 189,
+            (DefenceKind::Evasion, ItemRarity::Rare) => 190,
+            (DefenceKind::EnergyShield, ItemRarity::Normal) => 176,
+            (DefenceKind::EnergyShield, ItemRarity::Magic) => 178,
+            (DefenceKind::EnergyShield, ItemRarity::Rare) => 179,
         }
     }
 }
@@ -58,17 +81,36 @@ impl ItemDescriptor for Helmet {
 impl OrbAction for Helmet {
     fn reset_affixes(&mut self, ecommands: &mut EntityCommands) {
         self.affix_provider.reset();
-        ecommands.insert((MoreArmour(0.), MoreLife(0.), LifeRegen(0.)));
+        ecommands.insert((
+            MoreArmour(0.),
+            MoreEvasion(0.),
+            MoreEnergyShield(0.),
+            MoreLife(0.),
+            LifeRegen(0.),
+            IncreaseItemQuantity(0.),
+            IncreaseItemRarity(0.),
+            IncreaseLightRadius(0.),
+            Strength(0.),
+        ));
     }
 
     fn add_affixes(&mut self, ecommands: &mut EntityCommands, count: u16, rng: &mut ThreadRng) {
         let ilevel = self.affix_provider.ilevel();
         for _ in 0..count {
             match self.affix_provider.gen(rng) {
-                Some(HelmetAffixKind::MoreArmour) => {
-                    let value_and_tier = MORE_ARMOUR_RANGES.generate(ilevel, rng);
-                    self.affix_provider
-                        .set::<MoreArmour, _>(ecommands, value_and_tier);
+                Some(HelmetAffixKind::MoreDefence) => {
+                    let value_and_tier = MORE_DEFENCE_RANGES.generate(ilevel, rng);
+                    match self.defence {
+                        DefenceKind::Armour => self
+                            .affix_provider
+                            .set::<MoreArmour, _>(ecommands, value_and_tier),
+                        DefenceKind::Evasion => self
+                            .affix_provider
+                            .set::<MoreEvasion, _>(ecommands, value_and_tier),
+                        DefenceKind::EnergyShield => self
+                            .affix_provider
+                            .set::<MoreEnergyShield, _>(ecommands, value_and_tier),
+                    }
                 }
                 Some(HelmetAffixKind::MoreLife) => {
                     let value_and_tier = MORE_LIFE_RANGES.generate(ilevel, rng);
@@ -80,6 +122,26 @@ impl OrbAction for Helmet {
                     self.affix_provider
                         .set::<LifeRegen, _>(ecommands, value_and_tier);
                 }
+                Some(HelmetAffixKind::IncreaseItemQuantity) => {
+                    let value_and_tier = INCR_ITEM_QUANTITY_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<IncreaseItemQuantity, _>(ecommands, value_and_tier);
+                }
+                Some(HelmetAffixKind::IncreaseItemRarity) => {
+                    let value_and_tier = INCR_ITEM_RARITY_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<IncreaseItemRarity, _>(ecommands, value_and_tier);
+                }
+                Some(HelmetAffixKind::IncreaseLightRadius) => {
+                    let value_and_tier = INCR_LIGHT_RADIUS_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<IncreaseLightRadius, _>(ecommands, value_and_tier);
+                }
+                Some(HelmetAffixKind::Strength) => {
+                    let value_and_tier = STRENGTH_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<Strength, _>(ecommands, value_and_tier);
+                }
                 None => {}
             }
         }
@@ -89,11 +151,15 @@ impl OrbAction for Helmet {
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 enum HelmetAffixKind {
     MoreLife,
-    MoreArmour,
+    MoreDefence,
     LifeRegen,
+    IncreaseItemQuantity,
+    IncreaseItemRarity,
+    IncreaseLightRadius,
+    Strength,
 }
 
-const MORE_ARMOUR_RANGES: &[(u16, (u16, u16), usize); 3] =
+const MORE_DEFENCE_RANGES: &[(u16, (u16, u16), usize); 3] =
     &[(4, (3, 9), 20), (10, (10, 24), 20), (17, (25, 29), 20)];
 
 const MORE_LIFE_RANGES: &[(u16, (u16, u16), usize); 3] =
@@ -102,6 +168,18 @@ const MORE_LIFE_RANGES: &[(u16, (u16, u16), usize); 3] =
 const LIFE_REGEN_RANGES: &[(u16, (u16, u16), usize); 3] =
     &[(1, (1, 2), 20), (7, (2, 8), 20), (19, (8, 16), 20)];
 
+const INCR_ITEM_QUANTITY_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(4, (2, 5), 10), (10, (6, 10), 10), (17, (11, 15), 10)];
+
+const INCR_ITEM_RARITY_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(4, (5, 10), 10), (10, (11, 20), 10), (17, (21, 30), 10)];
+
+const INCR_LIGHT_RADIUS_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(2, (5, 10), 10), (8, (11, 20), 10), (15, (21, 30), 10)];
+
+const STRENGTH_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(4, (3, 6), 20), (10, (7, 12), 20), (17, (13, 20), 20)];
+
 #[derive(Deref, DerefMut)]
 struct HelmetAffixProvider(AffixProvider<HelmetAffixKind>);
 
@@ -109,11 +187,24 @@ impl HelmetAffixProvider {
     pub fn new(ilevel: u16) -> Self {
         let mut provider = RngKindProvider::default();
         provider.add(
-            HelmetAffixKind::MoreArmour,
-            MORE_ARMOUR_RANGES.weight(ilevel),
+            HelmetAffixKind::MoreDefence,
+            MORE_DEFENCE_RANGES.weight(ilevel),
         );
         provider.add(HelmetAffixKind::MoreLife, MORE_LIFE_RANGES.weight(ilevel));
         provider.add(HelmetAffixKind::LifeRegen, LIFE_REGEN_RANGES.weight(ilevel));
+        provider.add(
+            HelmetAffixKind::IncreaseItemQuantity,
+            INCR_ITEM_QUANTITY_RANGES.weight(ilevel),
+        );
+        provider.add(
+            HelmetAffixKind::IncreaseItemRarity,
+            INCR_ITEM_RARITY_RANGES.weight(ilevel),
+        );
+        provider.add(
+            HelmetAffixKind::IncreaseLightRadius,
+            INCR_LIGHT_RADIUS_RANGES.weight(ilevel),
+        );
+        provider.add(HelmetAffixKind::Strength, STRENGTH_RANGES.weight(ilevel));
         HelmetAffixProvider(AffixProvider::new::<Helmet>(ilevel, provider))
     }
 }
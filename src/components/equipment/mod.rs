@@ -8,7 +8,7 @@ pub mod weapon;
 pub use amulet::Amulet;
 pub use body_armour::BodyArmour;
 pub use boots::Boots;
-pub use common::{Equipment, EquipmentProvider};
+pub use common::{DefenceImplicit, DefenceKind, Equipment, EquipmentProvider};
 pub use helmet::Helmet;
 pub use wand::Wand;
 pub use weapon::Weapon;
@@ -16,16 +16,20 @@ pub use weapon::Weapon;
 mod common {
     use super::*;
     use crate::components::{
+        affix::{BaseArmour, BaseEnergyShield, BaseEvasion},
         common::EntityInserter,
-        item::{Item, ItemDescriptor, ItemLevel, ItemRarity, ItemSpawner, ValueAndTier},
+        item::{
+            AffixTiers, Item, ItemDescriptor, ItemLevel, ItemRarity, ItemSpawner, ValueAndTier,
+        },
         rng_provider::RngKindProvider,
     };
     use bevy::prelude::*;
-    use rand::rngs::ThreadRng;
+    use rand::{rngs::ThreadRng, Rng};
+    use serde::Deserialize;
     use std::fmt;
 
     /// Equiment type
-    #[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+    #[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect, Deserialize)]
     #[require(Item, ItemLevel, ItemRarity)]
     pub enum Equipment {
         Helmet,
@@ -45,8 +49,12 @@ mod common {
     }
 
     impl EquipmentKind {
-        fn spawn(&self, commands: &mut Commands, ilevel: u16, rng: &mut ThreadRng) -> Entity {
-            let spawner = ItemSpawner::new(ilevel, rng);
+        fn spawn(
+            &self,
+            commands: &mut Commands,
+            spawner: &ItemSpawner,
+            rng: &mut ThreadRng,
+        ) -> Entity {
             match self {
                 EquipmentKind::Amulet => spawner.spawn::<Amulet>(commands, rng),
                 EquipmentKind::BodyArmour => spawner.spawn::<BodyArmour>(commands, rng),
@@ -57,8 +65,76 @@ mod common {
         }
     }
 
+    /// Defence of the implicit of an armour piece ([Helmet], [BodyArmour] and [Boots])
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum DefenceKind {
+        Armour,
+        Evasion,
+        EnergyShield,
+    }
+
+    impl DefenceKind {
+        pub fn gen(rng: &mut ThreadRng) -> Self {
+            match rng.random_range(0..3) {
+                0 => DefenceKind::Armour,
+                1 => DefenceKind::Evasion,
+                _ => DefenceKind::EnergyShield,
+            }
+        }
+
+        /// `(strength, dexterity, intelligence)` weights of the requirements
+        pub fn attributes(&self) -> (u16, u16, u16) {
+            match self {
+                DefenceKind::Armour => (2, 0, 0),
+                DefenceKind::Evasion => (0, 2, 0),
+                DefenceKind::EnergyShield => (0, 0, 2),
+            }
+        }
+    }
+
+    /// Implicit of an armour piece.
+    ///
+    /// All the bases are inserted, so the local defences are always up to date,
+    /// but only the one of the [DefenceKind] is not null.
+    #[derive(Bundle)]
+    pub struct DefenceImplicit {
+        armour: BaseArmour,
+        evasion: BaseEvasion,
+        energy_shield: BaseEnergyShield,
+    }
+
+    impl DefenceImplicit {
+        pub fn new(kind: DefenceKind, value: f32) -> Self {
+            let mut implicit = DefenceImplicit {
+                armour: BaseArmour(0.),
+                evasion: BaseEvasion(0.),
+                energy_shield: BaseEnergyShield(0.),
+            };
+            match kind {
+                DefenceKind::Armour => implicit.armour.0 = value,
+                DefenceKind::Evasion => implicit.evasion.0 = value,
+                DefenceKind::EnergyShield => implicit.energy_shield.0 = value,
+            }
+            implicit
+        }
+    }
+
+    impl fmt::Display for DefenceImplicit {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if self.evasion.0 > 0. {
+                write!(f, "{}", self.evasion)
+            } else if self.energy_shield.0 > 0. {
+                write!(f, "{}", self.energy_shield)
+            } else {
+                write!(f, "{}", self.armour)
+            }
+        }
+    }
+
     pub struct EquipmentProvider {
         ilevel: u16,
+        rarity_bonus: f32,
+        rarity: Option<ItemRarity>,
         provider: RngKindProvider<EquipmentKind>,
     }
 
@@ -70,11 +146,36 @@ mod common {
             provider.add(EquipmentKind::Boots, 40);
             provider.add(EquipmentKind::Helmet, 40);
             provider.add(EquipmentKind::Wand, 40);
-            EquipmentProvider { ilevel, provider }
+            EquipmentProvider {
+                ilevel,
+                rarity_bonus: 0.,
+                rarity: None,
+                provider,
+            }
+        }
+
+        /// Set the rarity bonus (in percent) of the spawned equipments
+        pub fn with_rarity_bonus(mut self, rarity_bonus: f32) -> Self {
+            self.rarity_bonus = rarity_bonus;
+            self
+        }
+
+        /// Set the rarity of the spawned equipments, instead of a random one
+        pub fn with_rarity(mut self, rarity: ItemRarity) -> Self {
+            self.rarity = Some(rarity);
+            self
         }
 
         pub fn spawn(&mut self, commands: &mut Commands, rng: &mut ThreadRng) -> Option<Entity> {
-            Some(self.provider.gen(rng)?.spawn(commands, self.ilevel, rng))
+            let kind = self.provider.gen(rng)?;
+            let spawner = match self.rarity {
+                Some(rarity) => ItemSpawner {
+                    ilevel: self.ilevel,
+                    rarity,
+                },
+                None => ItemSpawner::with_rarity_bonus(self.ilevel, self.rarity_bonus, rng),
+            };
+            Some(kind.spawn(commands, &spawner, rng))
         }
     }
 
@@ -82,6 +183,7 @@ mod common {
         ilevel: u16,
         provider: RngKindProvider<K>,
         labels: Vec<String>,
+        tiers: Vec<u8>,
     }
 
     impl<K> AffixProvider<K>
@@ -96,6 +198,7 @@ mod common {
                 ilevel,
                 provider,
                 labels: vec![],
+                tiers: vec![],
             }
         }
 
@@ -106,6 +209,7 @@ mod common {
         pub fn reset(&mut self) {
             self.provider.reset();
             self.labels.clear();
+            self.tiers.clear();
         }
 
         pub fn gen(&mut self, rng: &mut ThreadRng) -> Option<K> {
@@ -119,7 +223,8 @@ mod common {
         {
             let affix = A::from(value.0);
             self.labels.push(format!("{affix} (t{})", value.1));
-            entity.insert(affix);
+            self.tiers.push(value.1);
+            entity.insert((affix, AffixTiers(self.tiers.clone())));
         }
 
         pub fn item_description(&self) -> String {
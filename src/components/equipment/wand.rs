@@ -3,7 +3,9 @@ use super::{
     weapon::{BaseAttackSpeed, Weapon},
 };
 use crate::components::{
-    affix::{IncreaseAttackSpeed, IncreaseDamage, MoreDamage, PierceChance},
+    affix::{
+        IncreaseAttackSpeed, IncreaseDamage, Intelligence, LifeLeech, MoreDamage, PierceChance,
+    },
     damage::BaseHitDamageRange,
     item::{AffixConfigGenerator, ItemDescriptor, ItemRarity, ItemSpawnBundle},
     orb::OrbAction,
@@ -22,7 +24,9 @@ use rand::{rngs::ThreadRng, Rng};
     MoreDamage,
     IncreaseDamage,
     PierceChance,
-    IncreaseAttackSpeed
+    IncreaseAttackSpeed,
+    Intelligence,
+    LifeLeech
 )]
 pub struct Wand {
     affix_provider: WandAffixProvider,
@@ -31,6 +35,7 @@ pub struct Wand {
 
 impl ItemSpawnBundle for Wand {
     type Implicit = BaseAttackSpeed;
+    const ATTRIBUTES: (u16, u16, u16) = (0, 0, 2);
 
     fn new(ilevel: u16, rng: &mut ThreadRng) -> (Self, Self::Implicit) {
         let implicit = BaseAttackSpeed(rng.random_range(1.0..1.5));
@@ -72,6 +77,8 @@ impl OrbAction for Wand {
             IncreaseDamage(0.),
             PierceChance(0.),
             IncreaseAttackSpeed(0.),
+            Intelligence(0.),
+            LifeLeech(0.),
         ));
     }
 
@@ -99,6 +106,16 @@ impl OrbAction for Wand {
                     self.affix_provider
                         .set::<IncreaseAttackSpeed, _>(ecommands, value_and_tier);
                 }
+                Some(WandAffixKind::Intelligence) => {
+                    let value_and_tier = INTELLIGENCE_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<Intelligence, _>(ecommands, value_and_tier);
+                }
+                Some(WandAffixKind::LifeLeech) => {
+                    let value_and_tier = LIFE_LEECH_RANGES.generate(ilevel, rng);
+                    self.affix_provider
+                        .set::<LifeLeech, _>(ecommands, value_and_tier);
+                }
                 None => {}
             }
         }
@@ -112,6 +129,8 @@ enum WandAffixKind {
     IncreaseDamage,
     PierceChance,
     IncreaseAttackSpeed,
+    Intelligence,
+    LifeLeech,
 }
 
 const MORE_DAMAGE_RANGES: &[(u16, (u16, u16), usize); 3] =
@@ -126,6 +145,12 @@ const PIERCE_CHANCE_RANGES: &[(u16, (u16, u16), usize); 3] =
 const INCR_ATTACK_SPEED_RANGES: &[(u16, (u16, u16), usize); 3] =
     &[(4, (3, 9), 10), (10, (10, 24), 10), (17, (25, 29), 10)];
 
+const INTELLIGENCE_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(4, (3, 6), 20), (10, (7, 12), 20), (17, (13, 20), 20)];
+
+const LIFE_LEECH_RANGES: &[(u16, (u16, u16), usize); 3] =
+    &[(5, (1, 2), 10), (12, (3, 4), 10), (20, (5, 6), 10)];
+
 #[derive(Deref, DerefMut)]
 struct WandAffixProvider(AffixProvider<WandAffixKind>);
 
@@ -145,6 +170,11 @@ impl WandAffixProvider {
             WandAffixKind::IncreaseAttackSpeed,
             INCR_ATTACK_SPEED_RANGES.weight(ilevel),
         );
+        provider.add(
+            WandAffixKind::Intelligence,
+            INTELLIGENCE_RANGES.weight(ilevel),
+        );
+        provider.add(WandAffixKind::LifeLeech, LIFE_LEECH_RANGES.weight(ilevel));
         WandAffixProvider(AffixProvider::new::<Wand>(ilevel, provider))
     }
 }
@@ -0,0 +1,342 @@
+use super::{
+    affix::{IncreaseMovementSpeed, LifeRegen, MoreArmour},
+    item::{Item, ItemDescriptor, ItemRarity, ItemRarityProvider, UpdateItemInfo},
+    rng_provider::RngKindProvider,
+};
+use bevy::prelude::*;
+use rand::{rngs::ThreadRng, Rng};
+use std::time::Duration;
+
+/// Number of flask slots on the player belt
+pub const N_FLASK_SLOTS: usize = 5;
+
+/// Duration of the effect of the instant flasks, for the [FlaskAffix]
+const INSTANT_FLASK_EFFECT_DURATION: f32 = 4.;
+
+///
+/// Life flask item
+///
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[require(Item, Name::new("Flask"))]
+pub struct Flask {
+    pub kind: FlaskKind,
+    pub affix: Option<FlaskAffix>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum FlaskKind {
+    /// Instant recovery
+    Small,
+    /// Recovery over time
+    Medium,
+    /// Bigger recovery over a longer time
+    Large,
+}
+
+impl FlaskKind {
+    /// Life recovered when using the flask
+    pub fn life(&self) -> f32 {
+        match self {
+            FlaskKind::Small => 6.,
+            FlaskKind::Medium => 15.,
+            FlaskKind::Large => 30.,
+        }
+    }
+
+    /// Duration of the life recovery, or `None` for an instant recovery
+    pub fn recovery_duration(&self) -> Option<f32> {
+        match self {
+            FlaskKind::Small => None,
+            FlaskKind::Medium => Some(3.),
+            FlaskKind::Large => Some(5.),
+        }
+    }
+
+    pub fn max_charges(&self) -> u16 {
+        match self {
+            FlaskKind::Small => 20,
+            FlaskKind::Medium => 30,
+            FlaskKind::Large => 45,
+        }
+    }
+
+    pub fn charges_per_use(&self) -> u16 {
+        match self {
+            FlaskKind::Small => 10,
+            FlaskKind::Medium => 15,
+            FlaskKind::Large => 15,
+        }
+    }
+
+    /// Duration of the flask effect
+    pub fn effect_duration(&self) -> f32 {
+        self.recovery_duration()
+            .unwrap_or(INSTANT_FLASK_EFFECT_DURATION)
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            FlaskKind::Small => "Small life flask",
+            FlaskKind::Medium => "Medium life flask",
+            FlaskKind::Large => "Large life flask",
+        }
+    }
+
+    pub fn tile_index(&self) -> usize {
+        match self {
+            FlaskKind::Small => 229,
+            FlaskKind::Medium => 236,
+            FlaskKind::Large => 245,
+        }
+    }
+}
+
+///
+/// Affix of a magic [Flask], active during the flask effect
+///
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum FlaskAffix {
+    /// % increased movement speed
+    MovementSpeed(f32),
+    /// Additional armour
+    Armour(f32),
+    /// Additional life regeneration
+    LifeRegen(f32),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum FlaskAffixKind {
+    MovementSpeed,
+    Armour,
+    LifeRegen,
+}
+
+impl FlaskAffix {
+    fn gen(ilevel: u16, rng: &mut ThreadRng) -> Option<Self> {
+        let mut provider = RngKindProvider::default();
+        provider.add(FlaskAffixKind::MovementSpeed, 40);
+        provider.add(FlaskAffixKind::Armour, 30);
+        provider.add(FlaskAffixKind::LifeRegen, 30);
+        let bonus = f32::from(ilevel / 4);
+        let affix = match provider.gen(rng)? {
+            FlaskAffixKind::MovementSpeed => {
+                FlaskAffix::MovementSpeed(rng.random_range(10..=20) as f32 + 2. * bonus)
+            }
+            FlaskAffixKind::Armour => FlaskAffix::Armour(rng.random_range(2..=5) as f32 + bonus),
+            FlaskAffixKind::LifeRegen => {
+                FlaskAffix::LifeRegen(rng.random_range(2..=4) as f32 + bonus)
+            }
+        };
+        Some(affix)
+    }
+
+    /// Insert the affix component on the entity of the [FlaskEffect]
+    pub fn insert(&self, ecommands: &mut EntityCommands) {
+        match *self {
+            FlaskAffix::MovementSpeed(value) => ecommands.insert(IncreaseMovementSpeed(value)),
+            FlaskAffix::Armour(value) => ecommands.insert(MoreArmour(value)),
+            FlaskAffix::LifeRegen(value) => ecommands.insert(LifeRegen(value)),
+        };
+    }
+}
+
+impl std::fmt::Display for FlaskAffix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            FlaskAffix::MovementSpeed(value) => write!(f, "{}", IncreaseMovementSpeed(value)),
+            FlaskAffix::Armour(value) => write!(f, "{}", MoreArmour(value)),
+            FlaskAffix::LifeRegen(value) => write!(f, "{}", LifeRegen(value)),
+        }?;
+        write!(f, " during effect")
+    }
+}
+
+impl ItemDescriptor for Flask {
+    fn title(&self) -> String {
+        self.kind.title().into()
+    }
+
+    fn description(&self) -> String {
+        let recovery = match self.kind.recovery_duration() {
+            Some(duration) => format!(
+                "Recovers {:.0} life over {duration:.0} seconds",
+                self.kind.life()
+            ),
+            None => format!("Recovers {:.0} life instantly", self.kind.life()),
+        };
+        let charges = format!(
+            "Consumes {} of {} charges on use",
+            self.kind.charges_per_use(),
+            self.kind.max_charges()
+        );
+        match self.affix {
+            Some(affix) => format!("{recovery}\n{charges}\n{affix}"),
+            None => format!("{recovery}\n{charges}"),
+        }
+    }
+
+    fn tile_index(&self, _rarity: ItemRarity) -> usize {
+        self.kind.tile_index()
+    }
+}
+
+///
+/// Charges of a [Flask], gained when killing monsters
+///
+#[derive(Component, Clone, Copy, Default, Debug, Reflect)]
+pub struct FlaskCharges {
+    pub current: u16,
+    pub max: u16,
+}
+
+impl FlaskCharges {
+    pub fn full(max: u16) -> Self {
+        FlaskCharges { current: max, max }
+    }
+
+    pub fn add(&mut self, charges: u16) {
+        self.current = (self.current + charges).min(self.max);
+    }
+
+    /// Consume `charges`, if there is enough charges
+    pub fn consume(&mut self, charges: u16) -> bool {
+        if self.current >= charges {
+            self.current -= charges;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl std::fmt::Display for FlaskCharges {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.current, self.max)
+    }
+}
+
+/// Provide a random [Flask], based on the level provided
+pub struct FlaskProvider {
+    ilevel: u16,
+    rarity_bonus: f32,
+}
+
+impl FlaskProvider {
+    pub fn new(ilevel: u16) -> Self {
+        FlaskProvider {
+            ilevel,
+            rarity_bonus: 0.,
+        }
+    }
+
+    /// The flask is more likely to be magic, depending on `rarity_bonus` (in percent)
+    pub fn with_rarity_bonus(mut self, rarity_bonus: f32) -> Self {
+        self.rarity_bonus = rarity_bonus;
+        self
+    }
+
+    pub fn spawn(&self, commands: &mut Commands, rng: &mut ThreadRng) -> Entity {
+        let mut provider = RngKindProvider::default();
+        provider.add(FlaskKind::Small, 40);
+        if self.ilevel > 2 {
+            provider.add(FlaskKind::Medium, 30);
+        }
+        if self.ilevel > 6 {
+            provider.add(FlaskKind::Large, 20);
+        }
+        let kind = provider.gen(rng).expect("At least 1 flask");
+
+        // Flasks can't be rare
+        let rarity = match ItemRarityProvider::gen_with_bonus(self.rarity_bonus, rng) {
+            ItemRarity::Normal => ItemRarity::Normal,
+            ItemRarity::Magic | ItemRarity::Rare => ItemRarity::Magic,
+        };
+        let affix = match rarity {
+            ItemRarity::Normal => None,
+            _ => FlaskAffix::gen(self.ilevel, rng),
+        };
+
+        let flask = Flask { kind, affix };
+        let entity = commands
+            .spawn((flask, rarity, FlaskCharges::full(kind.max_charges())))
+            .id();
+        commands.queue(UpdateItemInfo::<Flask>::new(entity));
+        entity
+    }
+}
+
+///
+/// Effect of a [Flask] on the character, which is the parent of the entity.
+///
+/// The [FlaskAffix] of the flask is inserted on the same entity, so it applies
+/// to the character until the end of the effect.
+///
+#[derive(Component)]
+#[require(Name::new("FlaskEffect"))]
+pub struct FlaskEffect {
+    pub timer: Timer,
+    /// Life recovered per second, for the flasks which recover life over time
+    pub life_per_second: f32,
+}
+
+impl FlaskEffect {
+    pub fn new(kind: FlaskKind) -> Self {
+        let duration = kind.effect_duration();
+        let life_per_second = match kind.recovery_duration() {
+            Some(duration) => kind.life() / duration,
+            None => 0.,
+        };
+        FlaskEffect {
+            timer: Timer::new(Duration::from_secs_f32(duration), TimerMode::Once),
+            life_per_second,
+        }
+    }
+}
+
+///
+/// The [Flask]s of the player belt
+///
+#[derive(Component, Default, Reflect)]
+pub struct PlayerFlasks([Option<Entity>; N_FLASK_SLOTS]);
+
+impl PlayerFlasks {
+    pub fn get(&self, slot: usize) -> Option<Entity> {
+        *self.0.get(slot)?
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.0.iter().flatten().copied()
+    }
+
+    /// Set the flask in the slot, returning the previous one
+    pub fn set(&mut self, slot: usize, flask: Entity) -> Option<Entity> {
+        self.0[slot].replace(flask)
+    }
+
+    pub fn remove(&mut self, flask: Entity) -> bool {
+        let Some(slot) = self.0.iter().position(|&o| o == Some(flask)) else {
+            return false;
+        };
+        self.0[slot] = None;
+        true
+    }
+}
+
+/// Event to put a [Flask] in a slot of the player belt
+#[derive(Event)]
+pub struct EquipFlaskEvent {
+    pub flask: Entity,
+    pub slot: usize,
+}
+
+/// Event to remove a [Flask] from the player belt
+#[derive(Event)]
+pub struct RemoveFlaskEvent(pub Entity);
+
+/// Event to use the [Flask] of a slot of the player belt
+#[derive(Event)]
+pub struct UseFlaskEvent(pub usize);
+
+/// Location of a [Flask] slot in the UI
+#[derive(Component, Clone, Copy, Deref)]
+pub struct FlaskSlot(pub usize);
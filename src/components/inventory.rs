@@ -56,6 +56,14 @@ impl Inventory {
         *self.0.get(index)?
     }
 
+    pub fn items(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.0.iter().flatten().copied()
+    }
+
+    pub fn free_slots(&self) -> usize {
+        self.0.iter().filter(|o| o.is_none()).count()
+    }
+
     pub fn pos(index: usize) -> InventoryPos {
         assert!(index < Inventory::len());
         InventoryPos {
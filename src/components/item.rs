@@ -1,6 +1,10 @@
 use super::{
+    character::CharacterAttributesItem,
     equipment::EquipmentProvider,
+    flask::FlaskProvider,
     inventory::{Inventory, InventoryChanged, PlayerEquipmentChanged},
+    loot::LootKind,
+    map_item::MapProvider,
     orb::{OrbAction, OrbProvider},
     player::Player,
     rng_provider::RngKindProvider,
@@ -8,6 +12,7 @@ use super::{
 };
 use bevy::prelude::*;
 use rand::{rngs::ThreadRng, Rng};
+use serde::Deserialize;
 use std::marker::PhantomData;
 
 pub const ITEM_SIZE: UVec2 = UVec2::new(48, 48);
@@ -81,6 +86,59 @@ pub struct DroppedItem(pub Entity);
 #[require(Item)]
 pub struct ItemLevel(pub u16);
 
+/// Level and attributes required to equip an [Item]
+#[derive(Component, Clone, Copy, Default, Debug, Reflect)]
+pub struct Requirements {
+    /// Character level, starting at 0
+    pub level: u16,
+    pub strength: u16,
+    pub dexterity: u16,
+    pub intelligence: u16,
+}
+
+impl Requirements {
+    /// Requirements of an item of level `ilevel`.
+    ///
+    /// `attributes` are the `(strength, dexterity, intelligence)` weights of the
+    /// item base: each attribute requirement grows with the item level.
+    pub fn new(ilevel: u16, attributes: (u16, u16, u16)) -> Self {
+        let (strength, dexterity, intelligence) = attributes;
+        let factor = ilevel + 2;
+        Requirements {
+            level: ilevel * 4 / 5,
+            strength: strength * factor,
+            dexterity: dexterity * factor,
+            intelligence: intelligence * factor,
+        }
+    }
+
+    /// Labels of the requirements, and whether each one is met by the character
+    pub fn check(&self, character: &CharacterAttributesItem) -> Vec<(String, bool)> {
+        let mut checks = vec![];
+        if self.level > 0 {
+            checks.push((
+                format!("Level {}", self.level + 1),
+                **character.level >= self.level,
+            ));
+        }
+        let attributes = [
+            ("Str", self.strength, **character.strength),
+            ("Dex", self.dexterity, **character.dexterity),
+            ("Int", self.intelligence, **character.intelligence),
+        ];
+        for (label, required, value) in attributes {
+            if required > 0 {
+                checks.push((format!("{required} {label}"), value >= f32::from(required)));
+            }
+        }
+        checks
+    }
+
+    pub fn are_met(&self, character: &CharacterAttributesItem) -> bool {
+        self.check(character).iter().all(|(_, met)| *met)
+    }
+}
+
 /// Component to add to UI to indicate which entity (if any) correspond to the node
 #[derive(Component, Default, Reflect)]
 pub struct ItemEntity(pub Option<Entity>);
@@ -118,6 +176,10 @@ impl<T> ItemLocationAccept<T> {
 #[derive(Component)]
 pub struct ItemLocationAcceptAll;
 
+/// The [Item] of the [ItemLocation] can't be dragged
+#[derive(Component, Default)]
+pub struct ItemLocationLocked;
+
 #[derive(Component)]
 #[require(
     ImageNode,
@@ -132,19 +194,53 @@ impl ItemProvider {
     pub fn spawn(&self, commands: &mut Commands, rng: &mut ThreadRng) -> Option<Entity> {
         let entity = match rng.random_range(0..100) {
             0..30 => EquipmentProvider::new(self.0).spawn(commands, rng)?,
-            30..60 => OrbProvider::spawn(commands, rng),
-            60..90 => SkillProvider::new(self.0).spawn(commands, rng)?,
+            30..55 => OrbProvider::spawn(commands, rng),
+            55..80 => SkillProvider::new(self.0).spawn(commands, rng)?,
+            80..90 => FlaskProvider::new(self.0).spawn(commands, rng),
+            90..93 => MapProvider::new(self.0).spawn(commands, rng),
             _ => return None,
         };
         Some(entity)
     }
+
+    /// Spawn an [Item] of the given [LootKind], with a rarity bonus (in percent)
+    pub fn spawn_loot(
+        &self,
+        kind: LootKind,
+        rarity_bonus: f32,
+        commands: &mut Commands,
+        rng: &mut ThreadRng,
+    ) -> Option<Entity> {
+        let entity = match kind {
+            LootKind::Equipment => EquipmentProvider::new(self.0)
+                .with_rarity_bonus(rarity_bonus)
+                .spawn(commands, rng)?,
+            LootKind::Orb => OrbProvider::spawn(commands, rng),
+            LootKind::Skill => SkillProvider::new(self.0).spawn(commands, rng)?,
+            LootKind::Flask => FlaskProvider::new(self.0)
+                .with_rarity_bonus(rarity_bonus)
+                .spawn(commands, rng),
+            LootKind::Map => MapProvider::new(self.0)
+                .with_rarity_bonus(rarity_bonus)
+                .spawn(commands, rng),
+        };
+        Some(entity)
+    }
 }
 
 pub trait ItemSpawnBundle {
-    type Implicit: Component + std::fmt::Display;
+    type Implicit: Bundle + std::fmt::Display;
+    /// `(strength, dexterity, intelligence)` weights of the [Requirements]
+    const ATTRIBUTES: (u16, u16, u16);
     fn new(ilevel: u16, rng: &mut ThreadRng) -> (Self, Self::Implicit)
     where
         Self: Sized;
+
+    /// `(strength, dexterity, intelligence)` weights of the [Requirements]
+    /// of this item, which can depend on its base
+    fn attributes(&self) -> (u16, u16, u16) {
+        Self::ATTRIBUTES
+    }
 }
 
 /// Util to spawn a random [Item] of a given type.
@@ -157,9 +253,15 @@ pub struct ItemSpawner {
 
 impl ItemSpawner {
     pub fn new(ilevel: u16, rng: &mut ThreadRng) -> Self {
+        Self::with_rarity_bonus(ilevel, 0., rng)
+    }
+
+    /// Create a spawner which rarity is more likely to be magic or rare,
+    /// depending on `rarity_bonus` (in percent)
+    pub fn with_rarity_bonus(ilevel: u16, rarity_bonus: f32, rng: &mut ThreadRng) -> Self {
         Self {
             ilevel,
-            rarity: ItemRarityProvider::gen(rng),
+            rarity: ItemRarityProvider::gen_with_bonus(rarity_bonus, rng),
         }
     }
 
@@ -169,10 +271,17 @@ impl ItemSpawner {
         T: Component + ItemSpawnBundle + ItemDescriptor + OrbAction,
     {
         let (mut item, implicit) = T::new(self.ilevel, rng);
+        let requirements = Requirements::new(self.ilevel, item.attributes());
         let mut item_cmds = commands.spawn_empty();
         let item_entity = item_cmds.id();
         item.add_affixes(&mut item_cmds, self.rarity.n_affix(), rng);
-        item_cmds.insert((item, implicit, self.rarity));
+        item_cmds.insert((
+            item,
+            implicit,
+            self.rarity,
+            ItemLevel(self.ilevel),
+            requirements,
+        ));
         commands.queue(UpdateItemInfo::<T>::new(item_entity));
         item_entity
     }
@@ -248,7 +357,7 @@ where
 pub struct ItemChanged(pub Entity);
 
 /// Equipment Rarity
-#[derive(Component, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[derive(Component, Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect, Deserialize)]
 pub enum ItemRarity {
     #[default]
     Normal,
@@ -270,21 +379,29 @@ impl ItemRarity {
 pub struct ItemRarityProvider(RngKindProvider<ItemRarity>);
 
 impl ItemRarityProvider {
-    fn new() -> Self {
+    fn new(rarity_bonus: f32) -> Self {
+        let multiplier = (1. + rarity_bonus / 100.).max(0.);
         let mut provider = RngKindProvider::default();
-        provider.add(ItemRarity::Normal, 10);
-        provider.add(ItemRarity::Magic, 8);
-        provider.add(ItemRarity::Rare, 5);
+        provider.add(ItemRarity::Normal, 100);
+        provider.add(ItemRarity::Magic, (80. * multiplier) as usize);
+        provider.add(ItemRarity::Rare, (50. * multiplier) as usize);
         ItemRarityProvider(provider)
     }
 
-    pub fn gen(rng: &mut ThreadRng) -> ItemRarity {
-        Self::new().0.gen(rng).expect("At least one rarity")
+    pub fn gen_with_bonus(rarity_bonus: f32, rng: &mut ThreadRng) -> ItemRarity {
+        Self::new(rarity_bonus)
+            .0
+            .gen(rng)
+            .expect("At least one rarity")
     }
 }
 
 pub struct ValueAndTier(pub u16, pub u8);
 
+/// Tiers of the affixes of an [Item], the tier 1 being the best one
+#[derive(Component, Clone, Default, Debug, Deref, Reflect)]
+pub struct AffixTiers(pub Vec<u8>);
+
 /// Utility to manage adding affix according to ilevel.
 pub trait AffixConfigGenerator {
     fn max_affix_index(&self, ilevel: u16) -> usize;
@@ -0,0 +1,211 @@
+//!
+//! Minimal model of an [LDtk](https://ldtk.io) project, to load the
+//! handcrafted levels of `assets/kill-them-all.ldtk`.
+//!
+//! Only the parts used by the game are deserialized.
+//!
+use super::world_map::MapLevelConfig;
+use crate::config::asset_path;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Path of the LDtk project, in the `assets` folder
+pub const LDTK_PROJECT: &str = "kill-them-all.ldtk";
+
+/// Identifier of the IntGrid layer used for the colliders
+const FLOOR_LAYER: &str = "Floor";
+/// Identifier of the auto layer with the tiles of the level
+const TILES_LAYER: &str = "AutoFloor";
+/// Identifier of the layer with the entity markers
+const ENTITIES_LAYER: &str = "Entities";
+
+/// IntGrid values which block the characters: `Collider` and `Water`
+const BLOCKING_VALUES: [i32; 2] = [3, 4];
+
+#[derive(Resource, Deserialize)]
+pub struct LdtkProject {
+    pub levels: Vec<LdtkLevel>,
+}
+
+impl LdtkProject {
+    /// Read and deserialize the LDtk project located in the `assets` folder
+    pub fn load(filename: &str) -> Result<Self> {
+        let content = std::fs::read_to_string(asset_path(filename))?;
+        Ok(serde_json::from_str(&content)?)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LdtkLevel {
+    pub identifier: String,
+    pub px_wid: i32,
+    pub px_hei: i32,
+    #[serde(default)]
+    pub layer_instances: Vec<LdtkLayer>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LdtkLayer {
+    #[serde(rename = "__identifier")]
+    pub identifier: String,
+    #[serde(rename = "__cWid")]
+    pub c_wid: i32,
+    #[serde(rename = "__cHei")]
+    pub c_hei: i32,
+    #[serde(rename = "__gridSize")]
+    pub grid_size: i32,
+    #[serde(default)]
+    pub int_grid_csv: Vec<i32>,
+    #[serde(default)]
+    pub auto_layer_tiles: Vec<LdtkTile>,
+    #[serde(default)]
+    pub entity_instances: Vec<LdtkEntity>,
+}
+
+/// A tile of an auto layer
+#[derive(Deserialize)]
+pub struct LdtkTile {
+    /// Position of the top left corner, in pixels
+    pub px: [i32; 2],
+    /// Index of the tile in the tileset
+    pub t: u32,
+    /// Flip bits: 1 for X, 2 for Y
+    pub f: u8,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LdtkEntity {
+    #[serde(rename = "__identifier")]
+    pub identifier: String,
+    /// Position of the pivot, in pixels
+    pub px: [i32; 2],
+    #[serde(rename = "__pivot")]
+    pub pivot: [f32; 2],
+    pub width: i32,
+    pub height: i32,
+    #[serde(default)]
+    pub field_instances: Vec<LdtkField>,
+}
+
+#[derive(Deserialize)]
+pub struct LdtkField {
+    #[serde(rename = "__identifier")]
+    pub identifier: String,
+    #[serde(rename = "__value")]
+    pub value: serde_json::Value,
+}
+
+impl LdtkEntity {
+    fn field(&self, identifier: &str) -> Option<&serde_json::Value> {
+        self.field_instances
+            .iter()
+            .find(|field| field.identifier == identifier)
+            .map(|field| &field.value)
+    }
+
+    pub fn int_field(&self, identifier: &str) -> Option<i64> {
+        self.field(identifier)?.as_i64()
+    }
+
+    pub fn string_field(&self, identifier: &str) -> Option<&str> {
+        self.field(identifier)?.as_str()
+    }
+
+    /// Position of the center of the entity, in pixels
+    pub fn center(&self) -> Vec2 {
+        Vec2::new(
+            self.px[0] as f32 + (0.5 - self.pivot[0]) * self.width as f32,
+            self.px[1] as f32 + (0.5 - self.pivot[1]) * self.height as f32,
+        )
+    }
+}
+
+impl LdtkLevel {
+    fn layer(&self, identifier: &str) -> Option<&LdtkLayer> {
+        self.layer_instances
+            .iter()
+            .find(|layer| layer.identifier == identifier)
+    }
+
+    /// The layer with the tiles of the level
+    pub fn tiles_layer(&self) -> Option<&LdtkLayer> {
+        self.layer(TILES_LAYER)
+    }
+
+    /// All the entities of the level with the `identifier`
+    pub fn entities<'a>(&'a self, identifier: &'a str) -> impl Iterator<Item = &'a LdtkEntity> {
+        self.layer(ENTITIES_LAYER)
+            .into_iter()
+            .flat_map(|layer| layer.entity_instances.iter())
+            .filter(move |entity| entity.identifier == identifier)
+    }
+
+    /// Size of the cells of the level, in pixels
+    pub fn grid_size(&self) -> i32 {
+        self.layer(FLOOR_LAYER).map_or(16, |layer| layer.grid_size)
+    }
+
+    /// Configuration of the level, from the `LevelConfig` entity
+    pub fn config(&self) -> Option<MapLevelConfig> {
+        let entity = self.entities("LevelConfig").next()?;
+        Some(MapLevelConfig {
+            name: entity.string_field("name").unwrap_or_default().to_string(),
+            monster_level: entity.int_field("monster_level").unwrap_or_default() as u16,
+        })
+    }
+
+    /// Convert a position in pixels, from the top left corner of the level,
+    /// to a position relative to the level tilemap.
+    ///
+    /// LDtk `y` axis goes down, and the tile `(0, 0)` of the tilemap
+    /// is centered on its origin.
+    pub fn to_local(&self, px: Vec2) -> Vec2 {
+        let half_grid = self.grid_size() as f32 / 2.;
+        Vec2::new(px.x - half_grid, self.px_hei as f32 - px.y - half_grid)
+    }
+
+    /// Position of the center of an entity, relative to the level tilemap
+    pub fn entity_pos(&self, entity: &LdtkEntity) -> Vec2 {
+        self.to_local(entity.center())
+    }
+
+    /// The rectangles which block the characters, relative to the level tilemap.
+    ///
+    /// The blocking cells of a row are merged, to limit the number of colliders.
+    pub fn colliders(&self) -> Vec<Rect> {
+        let Some(layer) = self.layer(FLOOR_LAYER) else {
+            return Vec::new();
+        };
+        let grid = layer.grid_size as f32;
+        let is_blocking = |cx: i32, cy: i32| {
+            layer
+                .int_grid_csv
+                .get((cy * layer.c_wid + cx) as usize)
+                .is_some_and(|value| BLOCKING_VALUES.contains(value))
+        };
+        let mut rects = Vec::new();
+        for cy in 0..layer.c_hei {
+            let mut cx = 0;
+            while cx < layer.c_wid {
+                if !is_blocking(cx, cy) {
+                    cx += 1;
+                    continue;
+                }
+                let start = cx;
+                while cx < layer.c_wid && is_blocking(cx, cy) {
+                    cx += 1;
+                }
+                let top_left = Vec2::new(start as f32, cy as f32) * grid;
+                let bottom_right = Vec2::new(cx as f32, (cy + 1) as f32) * grid;
+                rects.push(Rect::from_corners(
+                    self.to_local(top_left),
+                    self.to_local(bottom_right),
+                ));
+            }
+        }
+        rects
+    }
+}
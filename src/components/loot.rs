@@ -0,0 +1,138 @@
+use super::{
+    affix::{IncreaseItemQuantity, IncreaseItemRarity},
+    monster::MonsterRarity,
+    rng_provider::RngKindProvider,
+};
+use bevy::prelude::*;
+use rand::{rngs::ThreadRng, Rng};
+use serde::Deserialize;
+
+/// Kind of loot a monster can drop
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum LootKind {
+    Equipment,
+    Orb,
+    Skill,
+    Flask,
+    Map,
+}
+
+/// Bonuses that modify the loot of a monster, in percent
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+pub struct LootBonus {
+    #[serde(default)]
+    pub item_quantity: f32,
+    #[serde(default)]
+    pub item_rarity: f32,
+}
+
+impl LootBonus {
+    /// Bonus of a character that kills a monster
+    pub fn new(item_quantity: &IncreaseItemQuantity, item_rarity: &IncreaseItemRarity) -> Self {
+        LootBonus {
+            item_quantity: **item_quantity,
+            item_rarity: **item_rarity,
+        }
+    }
+}
+
+impl std::ops::Add for LootBonus {
+    type Output = LootBonus;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        LootBonus {
+            item_quantity: self.item_quantity + rhs.item_quantity,
+            item_rarity: self.item_rarity + rhs.item_rarity,
+        }
+    }
+}
+
+/// Weight of each [LootKind], and the weight to drop nothing
+#[derive(Clone, Debug, Deserialize)]
+pub struct LootWeights {
+    pub equipment: usize,
+    pub orb: usize,
+    pub skill: usize,
+    #[serde(default)]
+    pub flask: usize,
+    #[serde(default)]
+    pub map: usize,
+    pub nothing: usize,
+}
+
+impl LootWeights {
+    pub fn gen(&self, rng: &mut ThreadRng) -> Option<LootKind> {
+        let mut provider = RngKindProvider::default();
+        provider
+            .add(Some(LootKind::Equipment), self.equipment)
+            .add(Some(LootKind::Orb), self.orb)
+            .add(Some(LootKind::Skill), self.skill)
+            .add(Some(LootKind::Flask), self.flask)
+            .add(Some(LootKind::Map), self.map)
+            .add(None, self.nothing);
+        provider.gen(rng).flatten()
+    }
+}
+
+///
+/// Loot dropped by a monster of a given rarity, from a given level
+///
+#[derive(Debug, Deserialize)]
+pub struct LootTable {
+    /// Name of the monster kind, or `None` for all kinds
+    #[serde(default)]
+    pub monster: Option<String>,
+    pub rarity: MonsterRarity,
+    pub min_level: u16,
+    /// Number of drops per kill, before the item quantity bonus
+    pub drops: u16,
+    pub weights: LootWeights,
+    #[serde(default)]
+    pub bonus: LootBonus,
+    /// Always drop at least one orb
+    #[serde(default)]
+    pub guaranteed_currency: bool,
+}
+
+impl LootTable {
+    /// Total bonus of the table and of the killer
+    pub fn total_bonus(&self, killer_bonus: LootBonus) -> LootBonus {
+        self.bonus + killer_bonus
+    }
+
+    /// Roll the loot of a kill
+    pub fn roll(&self, killer_bonus: LootBonus, rng: &mut ThreadRng) -> Vec<LootKind> {
+        // The fractional part of the drops is a chance of an additional drop
+        let bonus = self.total_bonus(killer_bonus);
+        let drops = f32::from(self.drops) * (1. + bonus.item_quantity / 100.).max(0.);
+        let mut n_drops = drops.trunc() as u16;
+        if rng.random::<f32>() < drops.fract() {
+            n_drops += 1;
+        }
+
+        let mut loot: Vec<LootKind> = (0..n_drops).filter_map(|_| self.weights.gen(rng)).collect();
+        if self.guaranteed_currency && !loot.contains(&LootKind::Orb) {
+            loot.push(LootKind::Orb);
+        }
+        loot
+    }
+}
+
+///
+/// All loot tables, loaded from `assets/loot_tables.ron`
+///
+#[derive(Resource, Debug, Default, Deref, Deserialize)]
+pub struct LootTables(pub Vec<LootTable>);
+
+impl LootTables {
+    /// Find the table of a monster.
+    ///
+    /// A table for the monster kind is used before the generic ones,
+    /// and the table with the highest level lower or equal to `level` is used.
+    pub fn find(&self, monster: &str, rarity: MonsterRarity, level: u16) -> Option<&LootTable> {
+        self.iter()
+            .filter(|table| table.rarity == rarity && table.min_level <= level)
+            .filter(|table| table.monster.as_ref().is_none_or(|name| name == monster))
+            .max_by_key(|table| (table.monster.is_some(), table.min_level))
+    }
+}
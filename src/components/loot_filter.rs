@@ -0,0 +1,178 @@
+use super::{
+    affix::{
+        IncreaseAttackSpeed, IncreaseDamage, IncreaseItemQuantity, IncreaseItemRarity,
+        IncreaseLightRadius, IncreaseMaxLife, IncreaseMovementSpeed, LifeRegen, MoreArmour,
+        MoreDamage, MoreLife, PierceChance,
+    },
+    equipment::Equipment,
+    flask::Flask,
+    item::{ItemLevel, ItemRarity},
+    map_item::MapItem,
+    orb::Orb,
+    skills::SkillBook,
+};
+use bevy::{ecs::world::EntityRef, prelude::*};
+use serde::Deserialize;
+
+/// Class of an item
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum ItemClass {
+    /// Any [Equipment], or a given one
+    Equipment(Option<Equipment>),
+    /// Any [Orb], or a given one
+    Orb(Option<Orb>),
+    SkillBook,
+    Flask,
+    Map,
+}
+
+impl ItemClass {
+    fn matches(&self, item: &EntityRef) -> bool {
+        match self {
+            ItemClass::Equipment(equipment) => item
+                .get::<Equipment>()
+                .is_some_and(|e| equipment.is_none_or(|equipment| equipment == *e)),
+            ItemClass::Orb(orb) => item
+                .get::<Orb>()
+                .is_some_and(|o| orb.is_none_or(|orb| orb == *o)),
+            ItemClass::SkillBook => item.contains::<SkillBook>(),
+            ItemClass::Flask => item.contains::<Flask>(),
+            ItemClass::Map => item.contains::<MapItem>(),
+        }
+    }
+}
+
+/// Affixes that can be checked by a [LootFilterRule]
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum AffixKind {
+    MoreLife,
+    IncreaseMaxLife,
+    LifeRegen,
+    MoreArmour,
+    MoreDamage,
+    IncreaseDamage,
+    IncreaseAttackSpeed,
+    IncreaseMovementSpeed,
+    PierceChance,
+    IncreaseItemQuantity,
+    IncreaseItemRarity,
+    IncreaseLightRadius,
+}
+
+impl AffixKind {
+    /// The item has the affix with a non zero value
+    fn is_on(&self, item: &EntityRef) -> bool {
+        let value = match self {
+            AffixKind::MoreLife => item.get::<MoreLife>().map(|a| **a),
+            AffixKind::IncreaseMaxLife => item.get::<IncreaseMaxLife>().map(|a| **a),
+            AffixKind::LifeRegen => item.get::<LifeRegen>().map(|a| **a),
+            AffixKind::MoreArmour => item.get::<MoreArmour>().map(|a| **a),
+            AffixKind::MoreDamage => item.get::<MoreDamage>().map(|a| **a),
+            AffixKind::IncreaseDamage => item.get::<IncreaseDamage>().map(|a| **a),
+            AffixKind::IncreaseAttackSpeed => item.get::<IncreaseAttackSpeed>().map(|a| **a),
+            AffixKind::IncreaseMovementSpeed => item.get::<IncreaseMovementSpeed>().map(|a| **a),
+            AffixKind::PierceChance => item.get::<PierceChance>().map(|a| **a),
+            AffixKind::IncreaseItemQuantity => item.get::<IncreaseItemQuantity>().map(|a| **a),
+            AffixKind::IncreaseItemRarity => item.get::<IncreaseItemRarity>().map(|a| **a),
+            AffixKind::IncreaseLightRadius => item.get::<IncreaseLightRadius>().map(|a| **a),
+        };
+        value.is_some_and(|v| v != 0.)
+    }
+}
+
+/// How to show a [super::item::DroppedItem]
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LootHighlight {
+    /// Color of the border, as (red, green, blue)
+    #[serde(default)]
+    pub border: Option<(f32, f32, f32)>,
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Show a beam of light, with the border color
+    #[serde(default)]
+    pub beam: bool,
+    /// Sound played when the item drops
+    #[serde(default)]
+    pub sound: Option<String>,
+}
+
+impl LootHighlight {
+    pub fn color(&self) -> Color {
+        self.border
+            .map(|(r, g, b)| Color::srgb(r, g, b))
+            .unwrap_or(Color::WHITE)
+    }
+}
+
+/// Action of a [LootFilterRule]
+#[derive(Debug, Clone, Deserialize)]
+pub enum LootFilterAction {
+    Hide,
+    Highlight(LootHighlight),
+}
+
+///
+/// A rule of the loot filter. The item matches if all conditions match.
+///
+#[derive(Debug, Deserialize)]
+pub struct LootFilterRule {
+    #[serde(default)]
+    pub class: Option<ItemClass>,
+    #[serde(default)]
+    pub rarity: Option<Vec<ItemRarity>>,
+    #[serde(default)]
+    pub min_level: Option<u16>,
+    #[serde(default)]
+    pub max_level: Option<u16>,
+    /// Affixes that should all be on the item
+    #[serde(default)]
+    pub affixes: Vec<AffixKind>,
+    pub action: LootFilterAction,
+}
+
+impl LootFilterRule {
+    pub fn matches(&self, item: &EntityRef) -> bool {
+        let level = item.get::<ItemLevel>().map(|l| **l);
+        self.class.is_none_or(|class| class.matches(item))
+            && self.rarity.as_ref().is_none_or(|rarities| {
+                item.get::<ItemRarity>()
+                    .is_some_and(|rarity| rarities.contains(rarity))
+            })
+            && self
+                .min_level
+                .is_none_or(|min| level.is_some_and(|level| level >= min))
+            && self
+                .max_level
+                .is_none_or(|max| level.is_some_and(|level| level <= max))
+            && self.affixes.iter().all(|affix| affix.is_on(item))
+    }
+}
+
+///
+/// Loot filter, loaded from `assets/loot_filter.ron`.
+///
+/// The first matching rule gives the action of an item.
+/// Items that don't match any rule are shown without highlight.
+///
+#[derive(Resource, Debug, Default, Deref, Deserialize)]
+pub struct LootFilter(pub Vec<LootFilterRule>);
+
+impl LootFilter {
+    pub fn action(&self, item: &EntityRef) -> Option<&LootFilterAction> {
+        self.iter()
+            .find(|rule| rule.matches(item))
+            .map(|rule| &rule.action)
+    }
+}
+
+/// Tag for [super::item::DroppedItem]s hidden by the loot filter
+#[derive(Component)]
+pub struct LootFilterHidden;
+
+/// Tag for the entities added to a [super::item::DroppedItem] by the loot filter
+#[derive(Component)]
+pub struct LootFilterDecoration;
+
+/// Event triggered when the loot filter file has been reloaded
+#[derive(Event)]
+pub struct LootFilterChanged;
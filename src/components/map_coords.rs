@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+
+///
+/// Conversions between the coordinates of the [super::world_map::ProceduralWorldMap]:
+/// - "world": a translation in the world, in pixels
+/// - "tile": the position of a tile on the whole map
+/// - "chunk": the position of a chunk, which has `chunk_size` x `chunk_size` tiles
+///
+/// A tile is centered on its position times the `tile_size`, as the tiles of
+/// the tilemap of a chunk. So the tile (0, 0) covers the world from
+/// `-tile_size / 2` to `tile_size / 2`.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MapCoords {
+    tile_size: u32,
+    chunk_size: u32,
+}
+
+impl MapCoords {
+    pub fn new(tile_size: u32, chunk_size: u32) -> Self {
+        MapCoords {
+            tile_size: tile_size.max(1),
+            chunk_size: chunk_size.max(1),
+        }
+    }
+
+    pub fn tile_size(&self) -> f32 {
+        self.tile_size as f32
+    }
+
+    pub fn chunk_size(&self) -> i32 {
+        self.chunk_size as i32
+    }
+
+    /// Number of tiles in a chunk
+    pub fn chunk_len(&self) -> usize {
+        (self.chunk_size * self.chunk_size) as usize
+    }
+
+    /// The tile which covers the `translation`
+    pub fn world_to_tile(&self, translation: Vec2) -> IVec2 {
+        (translation / self.tile_size() + Vec2::splat(0.5))
+            .floor()
+            .as_ivec2()
+    }
+
+    /// The center of the `tile` in the world
+    pub fn tile_to_world(&self, tile: IVec2) -> Vec2 {
+        tile.as_vec2() * self.tile_size()
+    }
+
+    /// The chunk which contains the `tile`
+    pub fn tile_to_chunk(&self, tile: IVec2) -> IVec2 {
+        tile.div_euclid(IVec2::splat(self.chunk_size()))
+    }
+
+    /// The position of the `tile` in its chunk, from (0, 0) to (chunk_size - 1, chunk_size - 1)
+    pub fn tile_in_chunk(&self, tile: IVec2) -> IVec2 {
+        tile.rem_euclid(IVec2::splat(self.chunk_size()))
+    }
+
+    /// The index of the `tile` in its chunk, row by row from the bottom
+    pub fn tile_index(&self, tile: IVec2) -> usize {
+        let local = self.tile_in_chunk(tile);
+        (local.y * self.chunk_size() + local.x) as usize
+    }
+
+    /// The tile at the `index` of the chunk, row by row from the bottom
+    pub fn index_to_tile(&self, chunk: IVec2, index: usize) -> IVec2 {
+        let index = index as i32;
+        let local = IVec2::new(index % self.chunk_size(), index / self.chunk_size());
+        self.chunk_to_tile(chunk) + local
+    }
+
+    /// The first tile of the `chunk`, at its bottom left
+    pub fn chunk_to_tile(&self, chunk: IVec2) -> IVec2 {
+        chunk * self.chunk_size()
+    }
+
+    /// The chunk which contains the `translation`
+    pub fn world_to_chunk(&self, translation: Vec2) -> IVec2 {
+        self.tile_to_chunk(self.world_to_tile(translation))
+    }
+
+    /// The translation of the tilemap of the `chunk`, which is the center of its first tile
+    pub fn chunk_to_world(&self, chunk: IVec2) -> Vec2 {
+        self.tile_to_world(self.chunk_to_tile(chunk))
+    }
+
+    /// The center of the `chunk` in the world
+    pub fn chunk_center(&self, chunk: IVec2) -> Vec2 {
+        let half_chunk = (self.chunk_size() - 1) as f32 / 2.;
+        self.chunk_to_world(chunk) + Vec2::splat(half_chunk * self.tile_size())
+    }
+}
@@ -0,0 +1,197 @@
+use super::item::{
+    Item, ItemDescriptor, ItemLevel, ItemRarity, ItemRarityProvider, UpdateItemInfo,
+};
+use bevy::prelude::*;
+use rand::{rngs::ThreadRng, seq::SliceRandom, Rng};
+
+/// Rolled modifier of a [MapItem], which changes the rules of the area
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum MapModifier {
+    /// Monsters have +x% life
+    MonsterLife(u16),
+    /// Area contains x extra rare packs
+    ExtraRarePacks(u16),
+    /// Players have x% less life regeneration
+    LessPlayerRegen(u16),
+    /// +x% item quantity
+    ItemQuantity(u16),
+}
+
+impl MapModifier {
+    /// One modifier of each kind, in a random order
+    fn gen_all(tier: u16, rng: &mut ThreadRng) -> [MapModifier; 4] {
+        let mut modifiers = [
+            MapModifier::MonsterLife(rng.random_range(15..=30) + 5 * tier),
+            MapModifier::ExtraRarePacks(rng.random_range(1..=2) + tier / 3),
+            MapModifier::LessPlayerRegen(rng.random_range(20..=40)),
+            MapModifier::ItemQuantity(rng.random_range(10..=20) + 2 * tier),
+        ];
+        modifiers.shuffle(rng);
+        modifiers
+    }
+}
+
+impl std::fmt::Display for MapModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MapModifier::MonsterLife(value) => write!(f, "Monsters have +{value}% life"),
+            MapModifier::ExtraRarePacks(value) => {
+                write!(f, "Area contains {value} extra rare packs")
+            }
+            MapModifier::LessPlayerRegen(value) => {
+                write!(f, "Players have {value}% less life regeneration")
+            }
+            MapModifier::ItemQuantity(value) => write!(f, "+{value}% item quantity"),
+        }
+    }
+}
+
+///
+/// Map item, which starts a new area with the rules of its [MapModifier]s
+///
+#[derive(Component, Clone, Debug, Reflect)]
+#[require(Item, Name::new("MapItem"))]
+pub struct MapItem {
+    /// Tier of the map, starting at 1
+    pub tier: u16,
+    pub modifiers: Vec<MapModifier>,
+}
+
+impl MapItem {
+    /// Number of monsters to kill to complete the map
+    pub fn goal(&self) -> u16 {
+        30 + 10 * self.tier
+    }
+
+    pub fn rules(&self) -> MapRules {
+        let mut rules = MapRules::default();
+        for modifier in &self.modifiers {
+            match *modifier {
+                MapModifier::MonsterLife(value) => rules.monster_life += f32::from(value),
+                MapModifier::ExtraRarePacks(value) => rules.extra_rare_packs += value,
+                MapModifier::LessPlayerRegen(value) => rules.less_player_regen += f32::from(value),
+                MapModifier::ItemQuantity(value) => rules.item_quantity += f32::from(value),
+            }
+        }
+        rules
+    }
+}
+
+impl ItemDescriptor for MapItem {
+    fn title(&self) -> String {
+        format!("Map (tier {})", self.tier)
+    }
+
+    fn description(&self) -> String {
+        let goal = format!("Kill {} monsters to complete", self.goal());
+        self.modifiers.iter().fold(goal, |description, modifier| {
+            format!("{description}\n{modifier}")
+        })
+    }
+
+    fn tile_index(&self, rarity: ItemRarity) -> usize {
+        match rarity {
+            ItemRarity::Normal => 272,
+            ItemRarity::Magic => 275,
+            ItemRarity::Rare => 274,
+        }
+    }
+}
+
+/// Rules of the area of the [ActiveMap], summed from its [MapModifier]s
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MapRules {
+    /// Increased life of the monsters, in percent
+    pub monster_life: f32,
+    pub extra_rare_packs: u16,
+    /// Less life regeneration of the player, in percent
+    pub less_player_regen: f32,
+    /// Increased item quantity, in percent
+    pub item_quantity: f32,
+}
+
+impl MapRules {
+    /// Multiplier of the player life regeneration
+    pub fn player_regen_multiplier(&self) -> f32 {
+        (1. - self.less_player_regen / 100.).max(0.)
+    }
+}
+
+///
+/// The map which is running, if any.
+///
+/// The map level doesn't change while it runs.
+///
+#[derive(Resource, Debug)]
+pub struct ActiveMap {
+    pub tier: u16,
+    pub level: u16,
+    pub rules: MapRules,
+    /// Monsters killed since the map started
+    pub kills: u16,
+    /// Number of monsters to kill to complete the map
+    pub goal: u16,
+}
+
+impl ActiveMap {
+    pub fn new(map: &MapItem, level: u16) -> Self {
+        ActiveMap {
+            tier: map.tier,
+            level,
+            rules: map.rules(),
+            kills: 0,
+            goal: map.goal(),
+        }
+    }
+
+    /// Count killed monsters, and return `true` if the map is completed
+    pub fn add_kills(&mut self, count: u16) -> bool {
+        self.kills = self.kills.saturating_add(count);
+        self.kills >= self.goal
+    }
+}
+
+/// Provide a random [MapItem], based on the level provided
+pub struct MapProvider {
+    ilevel: u16,
+    rarity_bonus: f32,
+}
+
+impl MapProvider {
+    pub fn new(ilevel: u16) -> Self {
+        MapProvider {
+            ilevel,
+            rarity_bonus: 0.,
+        }
+    }
+
+    /// The map is more likely to be magic or rare, depending on `rarity_bonus` (in percent)
+    pub fn with_rarity_bonus(mut self, rarity_bonus: f32) -> Self {
+        self.rarity_bonus = rarity_bonus;
+        self
+    }
+
+    pub fn spawn(&self, commands: &mut Commands, rng: &mut ThreadRng) -> Entity {
+        let tier = 1 + self.ilevel / 5;
+        let rarity = ItemRarityProvider::gen_with_bonus(self.rarity_bonus, rng);
+        let n_modifiers = match rarity {
+            ItemRarity::Normal => 0,
+            ItemRarity::Magic => rng.random_range(1..=2),
+            ItemRarity::Rare => rng.random_range(3..=4),
+        };
+        let modifiers = MapModifier::gen_all(tier, rng)[..n_modifiers].to_vec();
+        let entity = commands
+            .spawn((MapItem { tier, modifiers }, rarity, ItemLevel(self.ilevel)))
+            .id();
+        commands.queue(UpdateItemInfo::<MapItem>::new(entity));
+        entity
+    }
+}
+
+/// Event to start the map of a [MapItem] of the inventory
+#[derive(Event)]
+pub struct ActivateMapEvent(pub Entity);
+
+/// Event triggered when the kills goal of the [ActiveMap] is reached
+#[derive(Event)]
+pub struct MapCompletedEvent;
@@ -1,20 +1,33 @@
 pub mod affix;
 pub mod animation;
 pub mod character;
+pub mod character_class;
 pub mod damage;
 pub mod equipment;
+pub mod flask;
 pub mod inventory;
 pub mod item;
+pub mod ldtk;
+pub mod loot;
+pub mod loot_filter;
+pub mod map_coords;
+pub mod map_item;
 pub mod monster;
 pub mod orb;
+pub mod passive_tree;
 pub mod player;
+pub mod prop;
 pub mod rng_provider;
 pub mod skills;
+pub mod status_effect;
+pub mod town;
 pub mod upgrade;
+pub mod vendor;
 pub mod world_map;
 
 pub use common::{
     despawn_all, LifeTime, GROUP_ALL, GROUP_DAMAGER, GROUP_ENEMY, GROUP_ITEM, GROUP_PLAYER,
+    GROUP_PROP,
 };
 
 mod common {
@@ -25,6 +38,7 @@ mod common {
     pub const GROUP_ENEMY: Group = Group::GROUP_2;
     pub const GROUP_ITEM: Group = Group::GROUP_3;
     pub const GROUP_DAMAGER: Group = Group::GROUP_4;
+    pub const GROUP_PROP: Group = Group::GROUP_5;
     pub const GROUP_ALL: Group = Group::ALL;
 
     /// Generic system that takes a component as a parameter, and will despawn all entities with that component
@@ -2,15 +2,82 @@ use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 use rand::{rngs::ThreadRng, Rng};
 
+use serde::Deserialize;
+
 use super::{
-    affix::IncreaseAttackSpeed,
-    animation::AnimationTimer,
+    affix::{IncreaseAttackSpeed, IncreaseMovementSpeed},
+    animation::{AnimationTimer, SpriteSheet},
     character::{BaseLife, BaseMovementSpeed, Character, Target},
-    damage::HitDamageRange,
-    world_map::LAYER_MONSTER,
+    damage::{DamageSource, HitDamageRange},
+    rng_provider::RngKindProvider,
+    skills::SkillKind,
+    world_map::{Biome, LAYER_MONSTER},
     GROUP_ALL, GROUP_ENEMY, GROUP_ITEM,
 };
 
+///
+/// Definition of a monster archetype
+///
+#[derive(Debug, Deserialize)]
+pub struct MonsterDefinition {
+    pub name: String,
+    pub sprite: SpriteSheet,
+    /// Weight used to randomly choose the kind of monster to spawn
+    pub weight: usize,
+    /// Weights replacing [MonsterDefinition::weight] in some [Biome]s
+    #[serde(default)]
+    pub biome_weights: Vec<(Biome, usize)>,
+    pub life: f32,
+    pub life_per_level: f32,
+    pub movement_speed: f32,
+    pub hit_damage: (f32, f32),
+    /// Experience given at level 0 by a normal monster
+    pub xp: u32,
+    /// Percent of chance to be rare, as a list of `(min level, percent)`
+    /// sorted by level
+    pub rare_chances: Vec<(u16, u16)>,
+    /// Skills of all monsters of this kind
+    pub skills: Vec<SkillKind>,
+    /// Skills only given to rare monsters of this kind
+    pub rare_skills: Vec<SkillKind>,
+}
+
+impl MonsterDefinition {
+    /// Weight to spawn this kind of monster in the [Biome]
+    pub fn weight(&self, biome: Biome) -> usize {
+        self.biome_weights
+            .iter()
+            .find(|(b, _)| *b == biome)
+            .map(|(_, weight)| *weight)
+            .unwrap_or(self.weight)
+    }
+
+    pub fn rare_chance(&self, level: u16) -> u16 {
+        self.rare_chances
+            .iter()
+            .rfind(|(min_level, _)| *min_level <= level)
+            .map(|(_, percent)| *percent)
+            .unwrap_or(0)
+    }
+
+    pub fn life(&self, level: u16) -> f32 {
+        self.life + self.life_per_level * f32::from(level)
+    }
+}
+
+///
+/// All monster archetypes, loaded from `assets/monsters.ron`.
+/// The index of a [MonsterDefinition] is the monster kind.
+///
+#[derive(Resource, Debug, Default, Deref, Deserialize)]
+pub struct MonsterDefinitions(pub Vec<MonsterDefinition>);
+
+impl MonsterDefinitions {
+    pub fn get_kind(&self, kind: usize) -> &MonsterDefinition {
+        self.get(kind).expect("Monster kind out of range !")
+    }
+}
+
 ///
 ///  Assets of a single monster
 ///
@@ -30,42 +97,28 @@ impl MonsterAssets {
 }
 
 ///
-///  Assets of all monsters
+///  Assets of all monsters, indexed by monster kind
 ///
-#[derive(Resource, Deref, DerefMut)]
+#[derive(Resource, Default, Deref, DerefMut)]
 pub struct AllMonsterAssets(pub Vec<MonsterAssets>);
 
-impl FromWorld for AllMonsterAssets {
-    fn from_world(world: &mut World) -> Self {
-        let atlas_layout = world.add_asset(TextureAtlasLayout::from_grid(
-            UVec2::new(16, 16),
-            4,
-            4,
-            None,
-            None,
-        ));
-
-        AllMonsterAssets(vec![
-            // Monster kind 1
-            MonsterAssets {
-                texture: world.load_asset("characters/Cyclope/SpriteSheet.png"),
-                atlas_layout: atlas_layout.clone(),
-            },
-            // Monster kind 2
-            MonsterAssets {
-                texture: world.load_asset("characters/Skull/SpriteSheet.png"),
-                atlas_layout: atlas_layout.clone(),
-            },
-            // Monster kind 3
-            MonsterAssets {
-                texture: world.load_asset("characters/DragonYellow/SpriteSheet.png"),
-                atlas_layout: atlas_layout.clone(),
-            },
-        ])
+impl AllMonsterAssets {
+    pub fn load(
+        definitions: &MonsterDefinitions,
+        asset_server: &AssetServer,
+        layouts: &mut Assets<TextureAtlasLayout>,
+    ) -> Self {
+        AllMonsterAssets(
+            definitions
+                .iter()
+                .map(|def| MonsterAssets {
+                    texture: asset_server.load(&def.sprite.path),
+                    atlas_layout: layouts.add(def.sprite.atlas_layout()),
+                })
+                .collect(),
+        )
     }
-}
 
-impl AllMonsterAssets {
     pub fn sprite(&self, kind: usize) -> Sprite {
         self.get(kind)
             .expect("Monster type out of range !")
@@ -105,67 +158,55 @@ pub struct MonsterLevel(pub u16);
 )]
 pub struct Monster;
 
-#[derive(Component)]
-#[require(Name::new("Monster#1"), Monster)]
-pub struct MonsterType1;
+/// Kind of monster, which is the index of its [MonsterDefinition]
+#[derive(Component, Default, Clone, Copy, Deref, Reflect)]
+pub struct MonsterKind(pub usize);
 
-impl MonsterType1 {
-    pub fn bundle(builder: MonsterBuilder, pos: Vec2, assets: &AllMonsterAssets) -> impl Bundle {
-        (
-            MonsterType1,
-            BaseLife::bundle(2.),
-            BaseMovementSpeed(50.),
-            builder.bundle(pos, assets),
-        )
-    }
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Eq, Reflect, Deserialize)]
+pub enum MonsterRarity {
+    #[default]
+    Normal,
+    Magic,
+    Rare,
 }
 
-#[derive(Component)]
-#[require(Name::new("Monster#2"), Monster)]
-pub struct MonsterType2;
-
-impl MonsterType2 {
-    pub fn bundle(builder: MonsterBuilder, pos: Vec2, assets: &AllMonsterAssets) -> impl Bundle {
-        (
-            MonsterType2,
-            BaseLife::bundle(3.),
-            BaseMovementSpeed(40.),
-            builder.bundle(pos, assets),
-        )
+impl MonsterRarity {
+    /// [IncreaseMovementSpeed] granted to the whole pack when the monster is a pack leader
+    pub fn pack_aura(&self) -> IncreaseMovementSpeed {
+        match self {
+            MonsterRarity::Normal => IncreaseMovementSpeed(0.),
+            MonsterRarity::Magic => IncreaseMovementSpeed(15.),
+            MonsterRarity::Rare => IncreaseMovementSpeed(30.),
+        }
     }
 }
 
+/// Leader of a pack of monsters
 #[derive(Component)]
-#[require(Name::new("Monster#3"))]
-pub struct MonsterType3;
+pub struct PackLeader;
 
-impl MonsterType3 {
-    pub fn bundle(builder: MonsterBuilder, pos: Vec2, assets: &AllMonsterAssets) -> impl Bundle {
-        (
-            MonsterType3,
-            Monster,
-            BaseLife::bundle(4.),
-            BaseMovementSpeed(30.),
-            builder.bundle(pos, assets),
-        )
-    }
-}
+/// Monster following the leader of its pack
+#[derive(Component, Deref)]
+pub struct PackFollower(pub Entity);
 
-// TODO: use enum
-const MONSTER_KIND_COUNT: usize = 3;
+/// Tag for affixes granted by the aura of a pack leader
+#[derive(Component)]
+pub struct PackAura;
 
-#[derive(Component, Default, Clone, Copy, Reflect)]
-pub enum MonsterRarity {
-    #[default]
-    Normal,
-    Rare,
-}
+/// Monster fleeing after the death of its pack leader
+#[derive(Component, Deref, DerefMut)]
+pub struct Scattered(pub Timer);
 
 /// Event sent (use [EventReader] to read) when the monsters can be spawn
+///
+/// Each `(position, count)` is a pack of monsters. When `count` is more than 1,
+/// the pack has a magic or rare leader followed by normal monsters of the same kind.
 #[derive(Event, Default)]
 pub struct SpawnMonstersEvent {
     pub mlevel: u16,
     pub monsters: Vec<(Vec2, u16)>,
+    /// Rarity of the pack leaders, instead of a random one
+    pub leader_rarity: Option<MonsterRarity>,
 }
 
 ///
@@ -179,27 +220,27 @@ pub struct MonsterBuilder {
 }
 
 impl MonsterBuilder {
-    pub fn generate(level: u16, rng: &mut ThreadRng) -> Self {
+    pub fn generate(
+        level: u16,
+        biome: Biome,
+        definitions: &MonsterDefinitions,
+        rng: &mut ThreadRng,
+    ) -> Self {
+        // Kind, depending on the biome
+        let mut kind_provider = RngKindProvider::default();
+        for (kind, def) in definitions.iter().enumerate() {
+            kind_provider.add(kind, def.weight(biome));
+        }
+        let kind = kind_provider.gen(rng).unwrap_or_default();
+
         // Rarity
-        let percent = match level {
-            0..1 => 0,
-            1..4 => 10,
-            4..8 => 20,
-            8..15 => 30,
-            15..25 => 40,
-            25..45 => 50,
-            45..65 => 60,
-            _ => 70,
-        };
+        let percent = definitions.get_kind(kind).rare_chance(level);
         let rarity = if rng.random_range(0..100) >= percent {
             MonsterRarity::Normal
         } else {
             MonsterRarity::Rare
         };
 
-        // Kind
-        let kind = rng.random_range(0..MONSTER_KIND_COUNT);
-
         // Create the params
         MonsterBuilder {
             kind,
@@ -208,14 +249,25 @@ impl MonsterBuilder {
         }
     }
 
-    pub fn bundle(&self, pos: Vec2, assets: &AllMonsterAssets) -> impl Bundle {
+    pub fn bundle(
+        &self,
+        pos: Vec2,
+        definitions: &MonsterDefinitions,
+        assets: &AllMonsterAssets,
+    ) -> impl Bundle {
+        let definition = definitions.get_kind(self.kind);
         let translation = pos.extend(LAYER_MONSTER);
         let scale = self.scale();
-        let xp = self.xp_on_death();
-        let damage_range = self.hit_damage_range();
+        let xp = self.xp_on_death(definition);
+        let damage_range = self.hit_damage_range(definition);
         (
+            Monster,
+            Name::new(definition.name.clone()),
+            MonsterKind(self.kind),
             MonsterLevel(self.level),
             self.rarity,
+            BaseLife::bundle(definition.life(self.level)),
+            BaseMovementSpeed(definition.movement_speed),
             assets.sprite(self.kind),
             Transform::from_translation(translation).with_scale(scale),
             XpOnDeath(xp),
@@ -224,27 +276,41 @@ impl MonsterBuilder {
         )
     }
 
+    /// Skills given to the monster, depending on its rarity
+    pub fn skills(&self, definitions: &MonsterDefinitions) -> Vec<SkillKind> {
+        let definition = definitions.get_kind(self.kind);
+        let mut skills = definition.skills.clone();
+        if let MonsterRarity::Rare = self.rarity {
+            skills.extend(definition.rare_skills.iter());
+        }
+        skills
+    }
+
     fn scale(&self) -> Vec3 {
         let scale = match self.rarity {
             MonsterRarity::Normal => 1.,
+            MonsterRarity::Magic => 1.5,
             MonsterRarity::Rare => 2.,
         };
         Vec3::new(scale, scale, 1.)
     }
 
-    fn xp_on_death(&self) -> u32 {
+    fn xp_on_death(&self, definition: &MonsterDefinition) -> u32 {
         let xp = match self.rarity {
-            MonsterRarity::Normal => 1,
-            MonsterRarity::Rare => 4,
+            MonsterRarity::Normal => definition.xp,
+            MonsterRarity::Magic => 2 * definition.xp,
+            MonsterRarity::Rare => 4 * definition.xp,
         };
         let multiplier = u32::from(self.level) + 1;
         xp * multiplier
     }
 
-    pub fn hit_damage_range(&self) -> (f32, f32) {
+    pub fn hit_damage_range(&self, definition: &MonsterDefinition) -> (f32, f32) {
+        let (min, max) = definition.hit_damage;
         let (min, max) = match self.rarity {
-            MonsterRarity::Normal => (1., 2.),
-            MonsterRarity::Rare => (2., 4.),
+            MonsterRarity::Normal => (min, max),
+            MonsterRarity::Magic => (1.5 * min, 1.5 * max),
+            MonsterRarity::Rare => (2. * min, 2. * max),
         };
         let multiplier = (self.level + 1) as f32;
         let min = min * multiplier;
@@ -267,4 +333,8 @@ pub struct MonsterDeathEvent {
     pub pos: Vec3,
     pub xp: u32,
     pub mlevel: u16,
+    pub kind: usize,
+    pub rarity: MonsterRarity,
+    /// Source of the damage which killed the monster
+    pub killer: DamageSource,
 }
@@ -4,9 +4,10 @@ use super::{
 };
 use bevy::prelude::*;
 use rand::rngs::ThreadRng;
+use serde::Deserialize;
 
 /// Orb item
-#[derive(Component, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Component, Clone, Copy, Eq, PartialEq, Hash, Debug, Deserialize)]
 #[require(Item)]
 pub enum Orb {
     /// Transform a normal item to a magic one
@@ -19,6 +20,18 @@ pub enum Orb {
     Chaos,
 }
 
+impl Orb {
+    /// Value of the orb when trading, in orbs of transmutation
+    pub fn value(&self) -> u16 {
+        match self {
+            Orb::Transmutation => 1,
+            Orb::Alteration => 3,
+            Orb::Regal => 6,
+            Orb::Chaos => 9,
+        }
+    }
+}
+
 impl ItemDescriptor for Orb {
     fn title(&self) -> String {
         match self {
@@ -0,0 +1,230 @@
+use super::{
+    affix::{
+        IncreaseAreaOfEffect, IncreaseAttackSpeed, IncreaseDamage, IncreaseItemQuantity,
+        IncreaseItemRarity, IncreaseMaxLife, IncreaseMovementSpeed, LifeRegen, MoreArmour,
+        MoreDamage, MoreLife, PierceChance,
+    },
+    orb::Orb,
+};
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::{HashSet, VecDeque};
+
+/// Cost to refund a single allocated passive node
+pub const RESPEC_COST: (Orb, u16) = (Orb::Alteration, 1);
+
+/// Cost to refund all allocated passive nodes
+pub const RESET_COST: (Orb, u16) = (Orb::Chaos, 1);
+
+///
+/// Affix granted by a [PassiveNodeDefinition]
+///
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum PassiveAffix {
+    MoreLife(f32),
+    IncreaseMaxLife(f32),
+    LifeRegen(f32),
+    MoreArmour(f32),
+    IncreaseAttackSpeed(f32),
+    IncreaseMovementSpeed(f32),
+    PierceChance(f32),
+    MoreDamage(f32),
+    IncreaseDamage(f32),
+    IncreaseAreaOfEffect(f32),
+    IncreaseItemQuantity(f32),
+    IncreaseItemRarity(f32),
+}
+
+impl PassiveAffix {
+    /// Spawn the affix as a child of the `player`, tagged with the [PassiveNode]
+    pub fn spawn(&self, commands: &mut Commands, player: Entity, node: usize) -> Entity {
+        let mut entity_commands = commands.spawn((
+            PassiveNode(node),
+            Name::new(format!("PassiveNode({node})")),
+            ChildOf(player),
+        ));
+        match *self {
+            PassiveAffix::MoreLife(value) => entity_commands.insert(MoreLife(value)),
+            PassiveAffix::IncreaseMaxLife(value) => entity_commands.insert(IncreaseMaxLife(value)),
+            PassiveAffix::LifeRegen(value) => entity_commands.insert(LifeRegen(value)),
+            PassiveAffix::MoreArmour(value) => entity_commands.insert(MoreArmour(value)),
+            PassiveAffix::IncreaseAttackSpeed(value) => {
+                entity_commands.insert(IncreaseAttackSpeed(value))
+            }
+            PassiveAffix::IncreaseMovementSpeed(value) => {
+                entity_commands.insert(IncreaseMovementSpeed(value))
+            }
+            PassiveAffix::PierceChance(value) => entity_commands.insert(PierceChance(value)),
+            PassiveAffix::MoreDamage(value) => entity_commands.insert(MoreDamage(value)),
+            PassiveAffix::IncreaseDamage(value) => entity_commands.insert(IncreaseDamage(value)),
+            PassiveAffix::IncreaseAreaOfEffect(value) => {
+                entity_commands.insert(IncreaseAreaOfEffect(value))
+            }
+            PassiveAffix::IncreaseItemQuantity(value) => {
+                entity_commands.insert(IncreaseItemQuantity(value))
+            }
+            PassiveAffix::IncreaseItemRarity(value) => {
+                entity_commands.insert(IncreaseItemRarity(value))
+            }
+        };
+        entity_commands.id()
+    }
+}
+
+impl std::fmt::Display for PassiveAffix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            PassiveAffix::MoreLife(value) => write!(f, "{}", MoreLife(value)),
+            PassiveAffix::IncreaseMaxLife(value) => write!(f, "{}", IncreaseMaxLife(value)),
+            PassiveAffix::LifeRegen(value) => write!(f, "{}", LifeRegen(value)),
+            PassiveAffix::MoreArmour(value) => write!(f, "{}", MoreArmour(value)),
+            PassiveAffix::IncreaseAttackSpeed(value) => write!(f, "{}", IncreaseAttackSpeed(value)),
+            PassiveAffix::IncreaseMovementSpeed(value) => {
+                write!(f, "{}", IncreaseMovementSpeed(value))
+            }
+            PassiveAffix::PierceChance(value) => write!(f, "{}", PierceChance(value)),
+            PassiveAffix::MoreDamage(value) => write!(f, "{}", MoreDamage(value)),
+            PassiveAffix::IncreaseDamage(value) => write!(f, "{}", IncreaseDamage(value)),
+            PassiveAffix::IncreaseAreaOfEffect(value) => {
+                write!(f, "{}", IncreaseAreaOfEffect(value))
+            }
+            PassiveAffix::IncreaseItemQuantity(value) => {
+                write!(f, "{}", IncreaseItemQuantity(value))
+            }
+            PassiveAffix::IncreaseItemRarity(value) => write!(f, "{}", IncreaseItemRarity(value)),
+        }
+    }
+}
+
+///
+/// A node of the [PassiveTree]
+///
+#[derive(Debug, Deserialize)]
+pub struct PassiveNodeDefinition {
+    pub name: String,
+    /// Position of the node in the tree window, in pixels
+    pub pos: (f32, f32),
+    /// Indexes of the linked nodes. Links are bidirectional, so they only
+    /// need to be declared on one of the two nodes.
+    #[serde(default)]
+    pub links: Vec<usize>,
+    #[serde(default)]
+    pub affixes: Vec<PassiveAffix>,
+}
+
+///
+/// The passive skill tree, loaded from `assets/passive_tree.ron`.
+/// The index of a [PassiveNodeDefinition] is the node id.
+///
+/// The start node is given by the
+/// [super::character_class::CharacterClassDefinition::passive_start].
+///
+#[derive(Resource, Debug, Default, Deserialize)]
+pub struct PassiveTree {
+    pub nodes: Vec<PassiveNodeDefinition>,
+}
+
+impl PassiveTree {
+    pub fn node(&self, id: usize) -> Option<&PassiveNodeDefinition> {
+        self.nodes.get(id)
+    }
+
+    /// All links `(from, to)` of the tree
+    pub fn links(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .flat_map(|(id, node)| node.links.iter().map(move |&other| (id, other)))
+    }
+
+    /// Nodes linked to the node `id`
+    pub fn neighbors(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.links().filter_map(move |(from, to)| {
+            if from == id {
+                Some(to)
+            } else if to == id {
+                Some(from)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// A node can be allocated if it is not allocated yet and is linked
+    /// to an allocated node
+    pub fn can_allocate(&self, allocated: &HashSet<usize>, id: usize) -> bool {
+        id < self.nodes.len()
+            && !allocated.contains(&id)
+            && self.neighbors(id).any(|n| allocated.contains(&n))
+    }
+
+    /// A node can be refunded if all other allocated nodes stay connected
+    /// to the `start` node without it
+    pub fn can_refund(&self, allocated: &HashSet<usize>, start: usize, id: usize) -> bool {
+        if id == start || !allocated.contains(&id) {
+            return false;
+        }
+        let mut remaining = allocated.clone();
+        remaining.remove(&id);
+
+        let mut reached = HashSet::from([start]);
+        let mut to_visit = VecDeque::from([start]);
+        while let Some(current) = to_visit.pop_front() {
+            for neighbor in self.neighbors(current) {
+                if remaining.contains(&neighbor) && reached.insert(neighbor) {
+                    to_visit.push_back(neighbor);
+                }
+            }
+        }
+        remaining.is_subset(&reached)
+    }
+}
+
+///
+/// Passive points and allocated nodes of the [crate::components::player::Player]
+///
+#[derive(Component, Default, Debug, Reflect)]
+pub struct PassiveSkills {
+    /// The start node, allocated for free and never refunded
+    pub start: usize,
+    /// Points available to allocate nodes
+    pub points: u16,
+    /// Allocated nodes, excluding the start node
+    pub allocated: HashSet<usize>,
+}
+
+impl PassiveSkills {
+    pub fn new(start: usize) -> Self {
+        PassiveSkills {
+            start,
+            ..Default::default()
+        }
+    }
+
+    /// Allocated nodes, including the start node
+    pub fn allocated_with_start(&self) -> HashSet<usize> {
+        let mut allocated = self.allocated.clone();
+        allocated.insert(self.start);
+        allocated
+    }
+}
+
+/// Tag for affixes granted by an allocated passive node
+#[derive(Component, Clone, Copy, Deref, Reflect)]
+pub struct PassiveNode(pub usize);
+
+/// Event to allocate a passive node
+#[derive(Event)]
+pub struct AllocatePassiveEvent(pub usize);
+
+/// Event to refund a passive node, paying [RESPEC_COST]
+#[derive(Event)]
+pub struct RespecPassiveEvent(pub usize);
+
+/// Event to refund all passive nodes, paying [RESET_COST]
+#[derive(Event)]
+pub struct ResetPassivesEvent;
+
+/// Event to indicate the [PassiveSkills] changed
+#[derive(Event)]
+pub struct PassiveSkillsChanged;
@@ -1,6 +1,10 @@
 use super::{
+    affix::{IncreaseItemQuantity, IncreaseItemRarity, IncreaseLightRadius},
     animation::AnimationTimer,
     character::{BaseLife, BaseMovementSpeed, Character, Target},
+    character_class::{CharacterClassDefinition, CharacterClasses, PlayerClass},
+    flask::PlayerFlasks,
+    passive_tree::PassiveSkills,
     world_map::LAYER_PLAYER,
     GROUP_ALL, GROUP_PLAYER,
 };
@@ -14,8 +18,14 @@ use std::time::Duration;
     Name::new("Player"),
     Character,
     PlayerBooks,
+    PlayerFlasks,
     Target::Monster,
     Experience,
+    PassiveSkills,
+    IncreaseItemQuantity,
+    IncreaseItemRarity,
+    IncreaseLightRadius,
+    LightRadius,
     Sprite,
     Transform::from_xyz(10., 10., LAYER_PLAYER),
     AnimationTimer,
@@ -26,11 +36,20 @@ use std::time::Duration;
 pub struct Player;
 
 impl Player {
-    pub fn bundle(assets: &PlayerAssets) -> impl Bundle {
+    pub fn bundle(
+        class: PlayerClass,
+        classes: &CharacterClasses,
+        assets: &AllPlayerAssets,
+    ) -> impl Bundle {
+        let definition = classes.get_class(*class);
+        let assets = assets.get(*class).expect("Player class out of range !");
         (
             Player,
-            BaseLife::bundle(10.),
-            BaseMovementSpeed(100.),
+            class,
+            BaseLife::bundle(definition.life),
+            BaseMovementSpeed(definition.movement_speed),
+            definition.attributes,
+            PassiveSkills::new(definition.passive_start),
             Sprite {
                 image: assets.texture.clone(),
                 texture_atlas: Some(assets.atlas_layout.clone().into()),
@@ -41,6 +60,25 @@ impl Player {
     }
 }
 
+/// [LightRadius] without [IncreaseLightRadius]
+pub const BASE_LIGHT_RADIUS: f32 = 160.;
+
+/// Distance around the player where the monsters are visible and the map is revealed
+#[derive(Component, Clone, Copy, Deref, Debug, Reflect)]
+pub struct LightRadius(pub f32);
+
+impl Default for LightRadius {
+    fn default() -> Self {
+        LightRadius(BASE_LIGHT_RADIUS)
+    }
+}
+
+impl LightRadius {
+    pub fn update(&mut self, increase: &IncreaseLightRadius) {
+        self.0 = BASE_LIGHT_RADIUS * (1. + **increase / 100.);
+    }
+}
+
 #[derive(Component, Clone, Copy, Debug)]
 pub enum PlayerAction {
     Skill1,
@@ -122,28 +160,46 @@ pub struct RemoveSkillBookEvent {
 
 pub const PLAYER_SIZE: Vec2 = Vec2::new(16.0, 16.0);
 
-/// All [Player] assets
-#[derive(Resource)]
+/// [Player] assets of a character class
 pub struct PlayerAssets {
     pub texture: Handle<Image>,
     pub atlas_layout: Handle<TextureAtlasLayout>,
 }
 
-impl FromWorld for PlayerAssets {
-    fn from_world(world: &mut World) -> Self {
-        let texture = world.load_asset("characters/RedNinja/SpriteSheet.png");
-        let atlas_layout = world.add_asset(TextureAtlasLayout::from_grid(
-            UVec2::new(16, 16),
-            4,
-            7,
-            None,
-            None,
-        ));
-
-        PlayerAssets {
-            texture,
-            atlas_layout,
-        }
+impl PlayerAssets {
+    /// Image of the idle character, to be displayed in the UI
+    pub fn image_node(&self, definition: &CharacterClassDefinition) -> ImageNode {
+        ImageNode::from_atlas_image(
+            self.texture.clone(),
+            TextureAtlas {
+                layout: self.atlas_layout.clone(),
+                index: definition.sprite.idle_frame(),
+            },
+        )
+    }
+}
+
+///
+///  [Player] assets of all character classes, indexed by class
+///
+#[derive(Resource, Default, Deref)]
+pub struct AllPlayerAssets(pub Vec<PlayerAssets>);
+
+impl AllPlayerAssets {
+    pub fn load(
+        classes: &CharacterClasses,
+        asset_server: &AssetServer,
+        layouts: &mut Assets<TextureAtlasLayout>,
+    ) -> Self {
+        AllPlayerAssets(
+            classes
+                .iter()
+                .map(|def| PlayerAssets {
+                    texture: asset_server.load(&def.sprite.path),
+                    atlas_layout: layouts.add(def.sprite.atlas_layout()),
+                })
+                .collect(),
+        )
     }
 }
 
@@ -162,11 +218,21 @@ pub struct LevelUpEvent;
 pub struct Experience(u32);
 
 impl Experience {
-    const LEVELS: [u32; 6] = [4, 10, 30, 80, 170, 300];
+    /// Maximum level, starting at 0
+    pub const MAX_LEVEL: u16 = 99;
+
+    /// Total experience required to reach the `level` (starting at 0)
+    pub fn level_min_exp(level: u16) -> u32 {
+        let level = u32::from(level.min(Self::MAX_LEVEL));
+        level * level * (level + 4)
+    }
 
-    /// Add xp
+    /// Add xp, up to the [Experience::MAX_LEVEL]
     pub fn add(&mut self, xp: u32) {
-        self.0 += xp;
+        self.0 = self
+            .0
+            .saturating_add(xp)
+            .min(Self::level_min_exp(Self::MAX_LEVEL));
     }
 
     pub fn current(&self) -> u32 {
@@ -175,28 +241,44 @@ impl Experience {
 
     /// Level starting at 0
     pub fn level(&self) -> u16 {
-        let mut level = 0;
-        for xp in Experience::LEVELS.iter() {
-            if self.0 >= *xp {
-                level += 1;
-            } else {
-                break;
-            }
-        }
-        level
+        (1..=Self::MAX_LEVEL)
+            .take_while(|&level| self.0 >= Self::level_min_exp(level))
+            .last()
+            .unwrap_or(0)
     }
 
+    /// Experience range of the current level.
+    ///
+    /// At [Experience::MAX_LEVEL], this is the range of the previous level, so
+    /// the experience stays at the maximum of the range.
     pub fn get_current_level_min_max_exp(&self) -> (u32, u32) {
-        let level = self.level();
-        let min = match level {
-            0 => 0,
-            _ => Self::LEVELS.get(level as usize - 1).cloned().unwrap_or(0),
-        };
-        let max = Self::LEVELS
-            .get(level as usize)
-            .cloned()
-            .unwrap_or(*Self::LEVELS.last().unwrap());
-        (min, max)
+        let level = self.level().min(Self::MAX_LEVEL - 1);
+        (Self::level_min_exp(level), Self::level_min_exp(level + 1))
+    }
+
+    /// Multiplier applied to the experience given by a monster, depending on
+    /// the level gap between the monster and the player.
+    ///
+    /// There is no penalty in a safe zone around the player level, which grows
+    /// with the level. Beyond it, higher monsters give a bonus (up to 50%), and
+    /// lower monsters a penalty (down to 5%).
+    pub fn level_gap_multiplier(player_level: u16, monster_level: u16) -> f32 {
+        let safe_zone = 3 + i32::from(player_level) / 16;
+        let gap = i32::from(monster_level) - i32::from(player_level);
+        if gap > safe_zone {
+            1. + (0.05 * (gap - safe_zone) as f32).min(0.5)
+        } else if gap < -safe_zone {
+            (1. - 0.1 * (-gap - safe_zone) as f32).max(0.05)
+        } else {
+            1.
+        }
+    }
+
+    /// Experience given to the player by a monster, taking into account
+    /// the level gap
+    pub fn monster_xp(&self, xp: u32, monster_level: u16) -> u32 {
+        let multiplier = Self::level_gap_multiplier(self.level(), monster_level);
+        (xp as f32 * multiplier).round() as u32
     }
 }
 
@@ -0,0 +1,160 @@
+use super::{
+    world_map::{Biome, LAYER_PROP},
+    GROUP_DAMAGER, GROUP_ENEMY, GROUP_PLAYER, GROUP_PROP,
+};
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_rapier2d::prelude::*;
+use rand::Rng;
+
+///
+/// Kind of [Prop] of the world map
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum PropKind {
+    Tree,
+    Rock,
+    Ruin,
+    /// Destructible
+    Crate,
+    /// Destructible
+    Urn,
+}
+
+impl PropKind {
+    const ALL: [PropKind; 5] = [
+        PropKind::Tree,
+        PropKind::Rock,
+        PropKind::Ruin,
+        PropKind::Crate,
+        PropKind::Urn,
+    ];
+
+    /// Weights of `[Tree, Rock, Ruin, Crate, Urn]` in the [Biome]
+    fn weights(biome: Biome) -> [u32; 5] {
+        match biome {
+            Biome::Forest => [50, 15, 5, 15, 15],
+            Biome::Desert => [5, 40, 20, 15, 20],
+            Biome::Snow => [30, 40, 5, 15, 10],
+            Biome::Swamp => [35, 10, 20, 15, 20],
+        }
+    }
+
+    /// Randomly choose a kind of prop, depending on the [Biome]
+    ///
+    /// It doesn't depend on any hash order, so that the props of a chunk
+    /// are the same with the same random generator.
+    pub fn gen(biome: Biome, rng: &mut impl Rng) -> Self {
+        let weights = Self::weights(biome);
+        let mut sel = rng.random_range(0..weights.iter().sum::<u32>());
+        for (kind, weight) in Self::ALL.into_iter().zip(weights) {
+            if sel < weight {
+                return kind;
+            }
+            sel -= weight;
+        }
+        unreachable!("The selection is lower than the sum of weights")
+    }
+
+    pub fn is_destructible(&self) -> bool {
+        matches!(self, PropKind::Crate | PropKind::Urn)
+    }
+
+    fn collider(&self) -> Collider {
+        match self {
+            PropKind::Tree => Collider::ball(6.),
+            PropKind::Rock => Collider::ball(5.),
+            PropKind::Ruin => Collider::cuboid(12., 4.),
+            PropKind::Crate => Collider::cuboid(5., 5.),
+            PropKind::Urn => Collider::ball(4.),
+        }
+    }
+
+    fn mesh(&self) -> Mesh {
+        match self {
+            PropKind::Tree => Circle::new(8.).into(),
+            PropKind::Rock => Circle::new(5.).into(),
+            PropKind::Ruin => Rectangle::new(24., 8.).into(),
+            PropKind::Crate => Rectangle::new(10., 10.).into(),
+            PropKind::Urn => Circle::new(4.).into(),
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            PropKind::Tree => Srgba::rgb_u8(34, 100, 34).into(),
+            PropKind::Rock => Srgba::rgb_u8(120, 120, 120).into(),
+            PropKind::Ruin => Srgba::rgb_u8(150, 140, 120).into(),
+            PropKind::Crate => Srgba::rgb_u8(140, 90, 40).into(),
+            PropKind::Urn => Srgba::rgb_u8(200, 120, 60).into(),
+        }
+    }
+}
+
+///
+/// A decoration of the world map, which blocks the characters.
+///
+/// The props of a chunk are generated from the map seed, so `chunk` and
+/// `index` identify the prop when the chunk is spawned again.
+///
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[require(Name::new("Prop"), RigidBody::Fixed)]
+pub struct Prop {
+    pub kind: PropKind,
+    pub chunk: IVec2,
+    pub index: usize,
+}
+
+/// A [Prop] which is destroyed when hit by a [super::damage::Damager]
+#[derive(Component)]
+pub struct DestructibleProp;
+
+impl Prop {
+    /// Bundle of the prop, at the `pos` relative to its chunk
+    pub fn bundle(&self, pos: Vec2, assets: &PropAssets) -> impl Bundle {
+        let filters = if self.kind.is_destructible() {
+            GROUP_PLAYER | GROUP_ENEMY | GROUP_DAMAGER
+        } else {
+            GROUP_PLAYER | GROUP_ENEMY
+        };
+        let (mesh, material) = assets.get(self.kind);
+        (
+            *self,
+            Transform::from_translation(pos.extend(LAYER_PROP)),
+            self.kind.collider(),
+            CollisionGroups::new(GROUP_PROP, filters),
+            Mesh2d(mesh),
+            MeshMaterial2d(material),
+        )
+    }
+}
+
+/// Event sent when a [DestructibleProp] is destroyed
+#[derive(Event)]
+pub struct PropDestroyedEvent {
+    pub pos: Vec2,
+    pub kind: PropKind,
+}
+
+#[derive(Resource)]
+pub struct PropAssets(HashMap<PropKind, (Handle<Mesh>, Handle<ColorMaterial>)>);
+
+impl FromWorld for PropAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mut assets = HashMap::new();
+        for kind in PropKind::ALL {
+            let mesh = world.add_asset(kind.mesh());
+            let material = world.add_asset(ColorMaterial::from(kind.color()));
+            assets.insert(kind, (mesh, material));
+        }
+        PropAssets(assets)
+    }
+}
+
+impl PropAssets {
+    fn get(&self, kind: PropKind) -> (Handle<Mesh>, Handle<ColorMaterial>) {
+        self.0
+            .get(&kind)
+            .cloned()
+            .expect("All prop kinds should have assets")
+    }
+}
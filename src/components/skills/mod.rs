@@ -8,11 +8,12 @@ use super::{
     rng_provider::RngKindProvider,
 };
 use bevy::prelude::*;
-use death_aura::DeathAuraBook;
-use fireball::FireBallLauncherBook;
-use mine::MineDropperBook;
+use death_aura::{DeathAura, DeathAuraBook};
+use fireball::{FireBallLauncher, FireBallLauncherBook};
+use mine::{MineDropper, MineDropperBook};
 use rand::rngs::ThreadRng;
-use shuriken::ShurikenLauncherBook;
+use serde::Deserialize;
+use shuriken::{ShurikenLauncher, ShurikenLauncherBook};
 
 #[derive(Component, Copy, Clone, Default)]
 pub struct Skill;
@@ -37,7 +38,7 @@ pub struct SkillBookLocation;
 #[derive(Component, Default)]
 pub struct AffectedByAreaOfEffect;
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Deserialize)]
 pub enum SkillKind {
     DeathAura,
     Fireball,
@@ -46,7 +47,8 @@ pub enum SkillKind {
 }
 
 impl SkillKind {
-    fn spawn(&self, commands: &mut Commands) -> Entity {
+    /// Spawn the [SkillBook] of the skill
+    pub fn spawn(&self, commands: &mut Commands) -> Entity {
         match self {
             SkillKind::DeathAura => commands.spawn(DeathAuraBook).id(),
             SkillKind::Fireball => commands.spawn(FireBallLauncherBook).id(),
@@ -54,6 +56,16 @@ impl SkillKind {
             SkillKind::Shuriken => commands.spawn(ShurikenLauncherBook).id(),
         }
     }
+
+    /// Spawn the skill itself, without its book (used by monsters)
+    pub fn spawn_skill(&self, commands: &mut Commands) -> Entity {
+        match self {
+            SkillKind::DeathAura => commands.spawn(DeathAura).id(),
+            SkillKind::Fireball => commands.spawn(FireBallLauncher).id(),
+            SkillKind::MineDropper => commands.spawn(MineDropper).id(),
+            SkillKind::Shuriken => commands.spawn(ShurikenLauncher).id(),
+        }
+    }
 }
 
 pub struct SkillProvider {
@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+///
+/// A temporary state of a character, like [crate::utils::invulnerable::Invulnerable]
+/// or a flask effect.
+///
+/// The entity with the [StatusEffect] is a child of the character, and is
+/// despawned when the effect finishes. Use [AddStatusEffectEvent] to add an
+/// effect to a character, so that the same effect stacks instead of being
+/// duplicated.
+///
+#[derive(Component, Clone, Debug, Reflect)]
+#[require(Name::new("StatusEffect"))]
+pub struct StatusEffect {
+    /// Name of the effect, which identifies it when stacking
+    pub label: &'static str,
+    /// Index of the icon in the `kte-items.png` texture
+    pub tile_index: usize,
+    pub stacks: u16,
+    pub max_stacks: u16,
+    timer: Timer,
+}
+
+impl StatusEffect {
+    pub fn new(label: &'static str, tile_index: usize, duration: Duration) -> Self {
+        StatusEffect {
+            label,
+            tile_index,
+            stacks: 1,
+            max_stacks: 1,
+            timer: Timer::new(duration, TimerMode::Once),
+        }
+    }
+
+    pub fn with_max_stacks(mut self, max_stacks: u16) -> Self {
+        self.max_stacks = max_stacks.max(1);
+        self
+    }
+
+    /// Stack the effect again: add a stack, up to [StatusEffect::max_stacks],
+    /// and restart the duration
+    pub fn stack(&mut self, duration: Duration) {
+        self.stacks = (self.stacks + 1).min(self.max_stacks);
+        self.timer.set_duration(duration);
+        self.timer.reset();
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.timer.duration()
+    }
+
+    pub fn remaining_secs(&self) -> f32 {
+        self.timer.remaining_secs()
+    }
+
+    pub fn tick(&mut self, delta: Duration) {
+        self.timer.tick(delta);
+    }
+
+    pub fn finished(&self) -> bool {
+        self.timer.finished()
+    }
+}
+
+/// Event to add a [StatusEffect] to the targeted character
+#[derive(Event, Deref)]
+pub struct AddStatusEffectEvent(pub StatusEffect);
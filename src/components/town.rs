@@ -0,0 +1,191 @@
+use super::{
+    world_map::{WorldMap, LAYER_MAP, LAYER_PROP},
+    GROUP_ITEM, GROUP_PLAYER,
+};
+use bevy::{math::vec2, prelude::*};
+use bevy_rapier2d::prelude::*;
+
+/// Size of the ground of the [Town]
+const TOWN_SIZE: Vec2 = vec2(400., 300.);
+
+/// Size of the sprite of a [TownStation]
+const STATION_SIZE: Vec2 = vec2(20., 20.);
+
+///
+/// The safe hub between the runs, where no monster spawns.
+///
+/// It is a [WorldMap], so that the player can move and drop items in it.
+///
+#[derive(Component)]
+#[require(
+    Name::new("Town"),
+    WorldMap,
+    Sprite::from_color(Srgba::rgb_u8(90, 80, 60), TOWN_SIZE),
+    Transform::from_xyz(0., 0., LAYER_MAP)
+)]
+pub struct Town;
+
+///
+/// Station of the [Town], which opens its window when the player walks on it
+///
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum TownStation {
+    /// Store the items between the runs
+    Stash,
+    /// Apply the orbs on an item
+    CraftingBench,
+    /// Trade the items
+    Vendor,
+    /// Start the map of a [super::map_item::MapItem]
+    MapDevice,
+    /// Go back to the open field
+    Portal,
+}
+
+impl TownStation {
+    pub const ALL: [TownStation; 5] = [
+        TownStation::Stash,
+        TownStation::CraftingBench,
+        TownStation::Vendor,
+        TownStation::MapDevice,
+        TownStation::Portal,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            TownStation::Stash => "Stash",
+            TownStation::CraftingBench => "Crafting bench",
+            TownStation::Vendor => "Vendor",
+            TownStation::MapDevice => "Map device",
+            TownStation::Portal => "Portal",
+        }
+    }
+
+    /// Position of the station, relative to the [Town]
+    fn pos(&self) -> Vec2 {
+        match self {
+            TownStation::Stash => vec2(-120., 80.),
+            TownStation::CraftingBench => vec2(-40., 80.),
+            TownStation::Vendor => vec2(40., 80.),
+            TownStation::MapDevice => vec2(120., 80.),
+            TownStation::Portal => vec2(0., -100.),
+        }
+    }
+
+    fn color(&self) -> Srgba {
+        match self {
+            TownStation::Stash => Srgba::rgb_u8(140, 90, 40),
+            TownStation::CraftingBench => Srgba::rgb_u8(120, 120, 130),
+            TownStation::Vendor => Srgba::rgb_u8(200, 170, 60),
+            TownStation::MapDevice => Srgba::rgb_u8(60, 110, 180),
+            TownStation::Portal => Srgba::rgb_u8(80, 60, 160),
+        }
+    }
+
+    pub fn bundle(self) -> impl Bundle {
+        (
+            self,
+            Name::new(self.title()),
+            Sprite::from_color(self.color(), STATION_SIZE),
+            Transform::from_translation(self.pos().extend(LAYER_PROP)),
+            Collider::cuboid(STATION_SIZE.x / 2., STATION_SIZE.y / 2.),
+            Sensor,
+            CollisionGroups::new(GROUP_ITEM, GROUP_PLAYER),
+        )
+    }
+}
+
+///
+/// The [Stash] contains the items stored in the [Town], as children.
+///
+/// It is kept between the runs.
+///
+#[derive(Component, Reflect)]
+#[require(Name::new("Stash"))]
+pub struct Stash([Option<Entity>; Stash::len()]);
+
+impl Default for Stash {
+    fn default() -> Self {
+        Stash([None; Stash::len()])
+    }
+}
+
+impl Stash {
+    pub const N_COLS: u16 = 12;
+    pub const N_ROWS: u16 = 6;
+
+    pub const fn len() -> usize {
+        (Stash::N_COLS * Stash::N_ROWS) as usize
+    }
+
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.0.contains(&Some(entity))
+    }
+
+    pub fn add_at(&mut self, item: Entity, index: usize) -> bool {
+        assert!(index < Self::len());
+        if self.0[index].is_some() {
+            warn!("Can't add item to a non empty location");
+            return false;
+        }
+        info!("Stash added {item} at {index}");
+        self.0[index] = Some(item);
+        true
+    }
+
+    pub fn remove(&mut self, item: Entity) -> bool {
+        match self.0.iter().position(|o| *o == Some(item)) {
+            Some(index) => {
+                self.0[index] = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn at(&self, index: usize) -> Option<Entity> {
+        *self.0.get(index)?
+    }
+
+    pub fn grid_placement(index: usize) -> (GridPlacement, GridPlacement) {
+        assert!(index < Stash::len());
+        let col = index as u16 % Self::N_COLS;
+        let row = index as u16 / Self::N_COLS;
+        (
+            GridPlacement::start(col as i16 + 1),
+            GridPlacement::start(row as i16 + 1),
+        )
+    }
+}
+
+///
+/// The item on the crafting bench of the [Town], as a child.
+///
+/// It is kept between the runs.
+///
+#[derive(Component, Default, Deref, DerefMut, Reflect)]
+#[require(Name::new("CraftingBench"))]
+pub struct CraftingBench(pub Option<Entity>);
+
+/// Event triggered when the player walks on a [TownStation]
+#[derive(Event, Deref)]
+pub struct EnterTownStationEvent(pub TownStation);
+
+/// Event triggered when the player leaves a [TownStation]
+#[derive(Event, Deref)]
+pub struct LeaveTownStationEvent(pub TownStation);
+
+/// Try to add an item of the [super::inventory::Inventory] to the [Stash]
+#[derive(Event)]
+pub struct AddToStashEvent {
+    pub item: Entity,
+    pub pos: usize,
+}
+
+/// Event to indicate The [Stash] or the [CraftingBench] changed
+#[derive(Event)]
+pub struct TownStorageChanged;
+
+/// Try to put an item of the [super::inventory::Inventory] on the [CraftingBench]
+#[derive(Event)]
+pub struct PutOnCraftingBenchEvent(pub Entity);
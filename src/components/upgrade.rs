@@ -1,7 +1,8 @@
 use super::{
     affix::{
-        IncreaseAreaOfEffect, IncreaseAttackSpeed, IncreaseDamage, IncreaseMaxLife,
-        IncreaseMovementSpeed, LifeRegen, MoreDamage, MoreLife, PierceChance,
+        Dexterity, IncreaseAreaOfEffect, IncreaseAttackSpeed, IncreaseDamage, IncreaseItemQuantity,
+        IncreaseItemRarity, IncreaseMaxLife, IncreaseMovementSpeed, Intelligence, LifeLeech,
+        LifeRegen, MoreDamage, MoreLife, PierceChance, Strength,
     },
     rng_provider::RngKindProvider,
 };
@@ -22,6 +23,12 @@ pub enum UpgradeKind {
     MoreDamage,
     IncreaseDamage,
     IncreaseAreaOfEffect,
+    IncreaseItemQuantity,
+    IncreaseItemRarity,
+    Strength,
+    Dexterity,
+    Intelligence,
+    LifeLeech,
 }
 
 ///
@@ -93,6 +100,30 @@ impl UpgradeKind {
                 let upgrade = IncreaseAreaOfEffect(rng.random_range(10..20) as f32);
                 Self::spawn(commands, upgrade)
             }
+            UpgradeKind::IncreaseItemQuantity => {
+                let upgrade = IncreaseItemQuantity(rng.random_range(2..8) as f32);
+                Self::spawn(commands, upgrade)
+            }
+            UpgradeKind::IncreaseItemRarity => {
+                let upgrade = IncreaseItemRarity(rng.random_range(5..15) as f32);
+                Self::spawn(commands, upgrade)
+            }
+            UpgradeKind::Strength => {
+                let upgrade = Strength(rng.random_range(5..15) as f32);
+                Self::spawn(commands, upgrade)
+            }
+            UpgradeKind::Dexterity => {
+                let upgrade = Dexterity(rng.random_range(5..15) as f32);
+                Self::spawn(commands, upgrade)
+            }
+            UpgradeKind::Intelligence => {
+                let upgrade = Intelligence(rng.random_range(5..15) as f32);
+                Self::spawn(commands, upgrade)
+            }
+            UpgradeKind::LifeLeech => {
+                let upgrade = LifeLeech(rng.random_range(1..3) as f32);
+                Self::spawn(commands, upgrade)
+            }
         }
     }
 
@@ -122,6 +153,12 @@ impl UpgradeProvider {
         provider.add(UpgradeKind::MoreDamage, 20);
         provider.add(UpgradeKind::IncreaseDamage, 20);
         provider.add(UpgradeKind::IncreaseAreaOfEffect, 20);
+        provider.add(UpgradeKind::IncreaseItemQuantity, 10);
+        provider.add(UpgradeKind::IncreaseItemRarity, 10);
+        provider.add(UpgradeKind::Strength, 20);
+        provider.add(UpgradeKind::Dexterity, 20);
+        provider.add(UpgradeKind::Intelligence, 20);
+        provider.add(UpgradeKind::LifeLeech, 10);
         UpgradeProvider(provider)
     }
 }
@@ -0,0 +1,186 @@
+use super::{equipment::EquipmentProvider, item::ItemRarity, orb::Orb};
+use bevy::prelude::*;
+use rand::{rngs::ThreadRng, Rng};
+use std::fmt;
+
+/// Value of an item sold to the vendor, in orbs of transmutation.
+///
+/// It depends on the rarity, the item level, and the tier of each affix
+/// (from 4 for a tier 1 affix, to 0 from the tier 5).
+///
+/// It is always below the [VendorOffer::price] of the same item, so buying
+/// and selling items never creates orbs.
+pub fn item_value(rarity: ItemRarity, ilevel: u16, tiers: &[u8]) -> u16 {
+    let rarity_value = match rarity {
+        ItemRarity::Normal => 1,
+        ItemRarity::Magic => 2,
+        ItemRarity::Rare => 4,
+    };
+    let tiers_value = tiers
+        .iter()
+        .map(|&tier| 5_u16.saturating_sub(u16::from(tier.max(1))))
+        .sum::<u16>();
+    let value = rarity_value + ilevel / 5 + tiers_value;
+    let max_value = VendorOffer::price(rarity, ilevel).value().saturating_sub(1);
+    value.min(max_value)
+}
+
+/// The orbs given by the vendor for a `value`, in orbs of transmutation,
+/// starting with the most valuable ones
+pub fn orbs_for_value(value: u16) -> Vec<Orb> {
+    let mut orbs = vec![];
+    let mut remaining = value;
+    for orb in [Orb::Chaos, Orb::Regal, Orb::Alteration, Orb::Transmutation] {
+        while remaining >= orb.value() {
+            orbs.push(orb);
+            remaining -= orb.value();
+        }
+    }
+    orbs
+}
+
+/// Price of a [VendorOffer]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Price {
+    pub orb: Orb,
+    pub count: u16,
+}
+
+impl Price {
+    /// Value of the price, in orbs of transmutation
+    pub fn value(&self) -> u16 {
+        self.orb.value() * self.count
+    }
+}
+
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} x {}", self.count, orb_label(self.orb))
+    }
+}
+
+fn orb_label(orb: Orb) -> &'static str {
+    match orb {
+        Orb::Transmutation => "Transmutation",
+        Orb::Alteration => "Alteration",
+        Orb::Regal => "Regal",
+        Orb::Chaos => "Chaos",
+    }
+}
+
+/// Exchange of orbs offered by the vendor
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CurrencyRecipe {
+    pub input: Price,
+    pub output: Orb,
+}
+
+impl fmt::Display for CurrencyRecipe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> 1 {}", self.input, orb_label(self.output))
+    }
+}
+
+pub const CURRENCY_RECIPES: [CurrencyRecipe; 3] = [
+    CurrencyRecipe {
+        input: Price {
+            orb: Orb::Transmutation,
+            count: 3,
+        },
+        output: Orb::Alteration,
+    },
+    CurrencyRecipe {
+        input: Price {
+            orb: Orb::Alteration,
+            count: 2,
+        },
+        output: Orb::Regal,
+    },
+    CurrencyRecipe {
+        input: Price {
+            orb: Orb::Alteration,
+            count: 3,
+        },
+        output: Orb::Chaos,
+    },
+];
+
+/// An item sold by the vendor
+#[derive(Clone, Copy, Debug)]
+pub struct VendorOffer {
+    pub item: Entity,
+    pub price: Price,
+}
+
+impl VendorOffer {
+    /// Normal bases cost orbs of transmutation, and magic ones orbs of alteration
+    pub fn price(rarity: ItemRarity, ilevel: u16) -> Price {
+        let orb = match rarity {
+            ItemRarity::Normal => Orb::Transmutation,
+            ItemRarity::Magic | ItemRarity::Rare => Orb::Alteration,
+        };
+        Price {
+            orb,
+            count: 1 + ilevel / 10,
+        }
+    }
+}
+
+///
+/// Items sold by the vendor, as children.
+///
+/// The stock changes each time the player comes back to the town.
+///
+#[derive(Component, Default)]
+#[require(Name::new("VendorStock"))]
+pub struct VendorStock(pub Vec<Option<VendorOffer>>);
+
+impl VendorStock {
+    pub const LEN: usize = 6;
+
+    /// Spawn normal or magic equipments of level `ilevel`
+    pub fn spawn(commands: &mut Commands, ilevel: u16, rng: &mut ThreadRng) -> Entity {
+        let mut offers = Vec::with_capacity(Self::LEN);
+        for _ in 0..Self::LEN {
+            let rarity = if rng.random_bool(0.5) {
+                ItemRarity::Normal
+            } else {
+                ItemRarity::Magic
+            };
+            let mut provider = EquipmentProvider::new(ilevel).with_rarity(rarity);
+            if let Some(item) = provider.spawn(commands, rng) {
+                let price = VendorOffer::price(rarity, ilevel);
+                offers.push(Some(VendorOffer { item, price }));
+            }
+        }
+        let items = offers.iter().flatten().map(|o| o.item).collect::<Vec<_>>();
+        let stock = commands.spawn(VendorStock(offers)).id();
+        commands.entity(stock).add_children(&items);
+        stock
+    }
+
+    pub fn get(&self, index: usize) -> Option<VendorOffer> {
+        *self.0.get(index)?
+    }
+
+    /// Remove the offer of the bought item
+    pub fn take(&mut self, index: usize) -> Option<VendorOffer> {
+        self.0.get_mut(index)?.take()
+    }
+}
+
+/// Event to sell an item of the [super::inventory::Inventory] to the vendor
+#[derive(Event)]
+pub struct SellItemEvent(pub Entity);
+
+/// Event to buy the [VendorOffer] at an index of the [VendorStock]
+#[derive(Event)]
+pub struct BuyItemEvent(pub usize);
+
+/// Event to exchange orbs with the [CurrencyRecipe] at an index of [CURRENCY_RECIPES]
+#[derive(Event)]
+pub struct ExchangeCurrencyEvent(pub usize);
+
+/// Event to indicate the [VendorStock] changed
+#[derive(Event)]
+pub struct VendorStockChanged;
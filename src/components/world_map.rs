@@ -1,3 +1,7 @@
+use super::{
+    map_coords::MapCoords,
+    prop::{Prop, PropKind},
+};
 use bevy::{
     platform::collections::{HashMap, HashSet},
     prelude::*,
@@ -8,7 +12,12 @@ use bevy_ecs_tilemap::{
     TilemapBundle,
 };
 use noise::{NoiseFn, Perlin};
-use rand::{rngs::ThreadRng, Rng};
+use rand::{
+    rngs::{StdRng, ThreadRng},
+    Rng, SeedableRng,
+};
+use serde::Deserialize;
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 #[derive(Resource)]
 pub struct WorldMapAssets {
@@ -24,7 +33,7 @@ impl FromWorld for WorldMapAssets {
 }
 
 /// The world map
-#[derive(Component, Copy, Clone)]
+#[derive(Component, Default, Copy, Clone)]
 #[require(Name::new("WorldMap"), Transform, Visibility)]
 pub struct WorldMap;
 
@@ -59,6 +68,11 @@ impl CurrentMapLevel {
         self.0 += 1;
     }
 
+    /// Use the level of a [super::map_item::MapItem]
+    pub fn set(&mut self, level: u16) {
+        self.0 = level;
+    }
+
     pub fn monsters_to_spawn(&self, rng: &mut ThreadRng) -> MonsterToSpawn {
         let n_groups = match self.0 {
             0..1 => 1,
@@ -100,9 +114,12 @@ pub struct WorldMapLoadingFinished {
 }
 
 pub const LAYER_PLAYER: f32 = 10.;
+/// The fog of war is over the monsters, which are hidden out of the light
+pub const LAYER_FOG: f32 = 9.5;
 pub const LAYER_MONSTER: f32 = 9.;
 pub const LAYER_DAMAGER: f32 = 8.;
 pub const LAYER_ITEM: f32 = 7.;
+pub const LAYER_PROP: f32 = 5.;
 pub const LAYER_MAP: f32 = 0.;
 
 // ============================================================================
@@ -110,64 +127,189 @@ pub const LAYER_MAP: f32 = 0.;
 // PROCEDURAL WORLD MAP GENERATOR
 //
 // ============================================================================
+/// Maximum number of [Prop]s in a chunk of the world map
+const MAX_PROPS_PER_CHUNK: usize = 6;
+
 #[derive(Resource)]
 pub struct ProceduralWorldMap {
     config: WorldMapConfig,
+    /// Seed of the map, to generate the same chunk each time it's spawned
+    seed: u32,
     perlin: Perlin,
-    tiles_kind: HashMap<(i32, i32), TileKind>,
+    /// Low frequency noise for the [Biome]s
+    biome_perlin: Perlin,
+    chunks_cache: ChunksCache,
     spawned_chunks: HashSet<IVec2>,
+    /// The [ChunkFog] of the chunks already spawned once
+    fog: HashMap<IVec2, ChunkFog>,
+    /// Incremented each time a tile is revealed
+    fog_revision: u32,
+    /// The destructible [Prop]s already destroyed, by chunk
+    destroyed_props: HashSet<(IVec2, usize)>,
 }
 
 impl ProceduralWorldMap {
     pub fn new(config: WorldMapConfig, rng: &mut ThreadRng) -> Self {
+        Self::with_seed(config, rng.random())
+    }
+
+    pub fn with_seed(config: WorldMapConfig, seed: u32) -> Self {
         ProceduralWorldMap {
+            chunks_cache: ChunksCache::new(config.chunks_cache_size),
             config,
-            perlin: Perlin::new(rng.random()),
-            tiles_kind: HashMap::new(),
+            seed,
+            perlin: Perlin::new(seed),
+            biome_perlin: Perlin::new(seed.wrapping_add(1)),
             spawned_chunks: HashSet::new(),
+            fog: HashMap::new(),
+            fog_revision: 0,
+            destroyed_props: HashSet::new(),
         }
     }
 
-    pub fn chunk_pos(&self, translation: Vec2) -> IVec2 {
-        let pos = translation.as_ivec2();
-        let chunk_size: IVec2 = IVec2::splat(self.config.chunk_size as i32);
-        let tile_size: IVec2 = IVec2::splat(self.config.tile_size as i32);
-        let mut chunk_pos = pos / (chunk_size * tile_size);
-        // Fix the calculus for negative values
-        if translation.x < 0. {
-            chunk_pos.x -= 1;
-        }
-        if translation.y < 0. {
-            chunk_pos.y -= 1;
-        }
-        chunk_pos
+    /// Random generator of a chunk, which only depends on the map seed
+    fn chunk_rng(&self, chunk_pos: IVec2) -> StdRng {
+        let mut hasher = DefaultHasher::new();
+        (self.seed, chunk_pos.x, chunk_pos.y).hash(&mut hasher);
+        StdRng::seed_from_u64(hasher.finish())
+    }
+
+    /// Conversions between the world, tile and chunk coordinates of the map
+    pub fn coords(&self) -> MapCoords {
+        self.config.coords()
     }
 
     pub fn is_spawned(&self, pos: IVec2) -> bool {
         self.spawned_chunks.contains(&pos)
     }
 
-    fn tile_kind(&mut self, x: i32, y: i32) -> TileKind {
-        match self.tiles_kind.get(&(x, y)) {
-            Some(kind) => *kind,
-            None => {
-                let noise_val = self.perlin.get([
-                    x as f64 / self.config.noise_scale,
-                    y as f64 / self.config.noise_scale,
-                ]);
-                let kind = if noise_val < -0.4 {
-                    TileKind::Water
-                } else if noise_val < 0.5 {
-                    TileKind::Mud
-                } else {
-                    TileKind::Grass
-                };
-                self.tiles_kind.insert((x, y), kind);
-                kind
+    pub fn explore_chunk(&mut self, chunk_pos: IVec2) {
+        let chunk_len = self.coords().chunk_len();
+        self.fog
+            .entry(chunk_pos)
+            .or_insert_with(|| ChunkFog::new(chunk_len));
+    }
+
+    #[cfg(test)]
+    pub fn is_explored(&self, chunk_pos: IVec2) -> bool {
+        self.fog.contains_key(&chunk_pos)
+    }
+
+    pub fn chunk_fog(&self, chunk_pos: IVec2) -> Option<&ChunkFog> {
+        self.fog.get(&chunk_pos)
+    }
+
+    /// Changes each time new tiles are revealed
+    pub fn fog_revision(&self) -> u32 {
+        self.fog_revision
+    }
+
+    /// Reveal the tiles in the `radius` around the `center` tile, in "world map" coordinates
+    pub fn reveal(&mut self, center: IVec2, radius: i32) {
+        let coords = self.coords();
+        for y in -radius..=radius {
+            for x in -radius..=radius {
+                if x * x + y * y > radius * radius {
+                    continue;
+                }
+                let tile = center + IVec2::new(x, y);
+                let chunk_pos = coords.tile_to_chunk(tile);
+                let index = coords.tile_index(tile);
+                self.explore_chunk(chunk_pos);
+                if self
+                    .fog
+                    .get(&chunk_pos)
+                    .is_some_and(|f| f.is_revealed(index))
+                {
+                    continue;
+                }
+                let kind = self.tile_kind(tile.x, tile.y);
+                if let Some(fog) = self.fog.get_mut(&chunk_pos) {
+                    fog.revealed[index] = Some(kind);
+                    self.fog_revision = self.fog_revision.wrapping_add(1);
+                }
             }
         }
     }
 
+    /// [TileKind]s of the revealed tiles of a chunk, with their index
+    /// in the chunk (row by row from the bottom)
+    ///
+    /// They are read from the [ChunkFog], so the chunk is never generated again.
+    pub fn revealed_tiles_kind(
+        &self,
+        chunk_pos: IVec2,
+    ) -> impl Iterator<Item = (usize, TileKind)> + '_ {
+        self.fog.get(&chunk_pos).into_iter().flat_map(|fog| {
+            fog.revealed
+                .iter()
+                .enumerate()
+                .filter_map(|(index, kind)| kind.map(|kind| (index, kind)))
+        })
+    }
+
+    /// [Biome] of the tile at (x, y), in "world map" coordinates
+    fn tile_biome(&self, x: i32, y: i32) -> Biome {
+        let noise_val = self.biome_perlin.get([
+            x as f64 / self.config.biome_noise_scale,
+            y as f64 / self.config.biome_noise_scale,
+        ]);
+        Biome::from_noise(noise_val)
+    }
+
+    /// [Biome] at the `translation` in the world
+    pub fn biome(&self, translation: Vec2) -> Biome {
+        let tile = self.coords().world_to_tile(translation);
+        self.tile_biome(tile.x, tile.y)
+    }
+
+    /// Number of chunks which [TileKind]s are currently cached
+    #[cfg(test)]
+    pub fn cached_chunks(&self) -> usize {
+        self.chunks_cache.len()
+    }
+
+    fn generate_tile_kind(&self, x: i32, y: i32) -> TileKind {
+        let noise_val = self.perlin.get([
+            x as f64 / self.config.noise_scale,
+            y as f64 / self.config.noise_scale,
+        ]);
+        let (water_level, grass_level) = self.tile_biome(x, y).levels();
+        if noise_val < water_level {
+            TileKind::Water
+        } else if noise_val < grass_level {
+            TileKind::Mud
+        } else {
+            TileKind::Grass
+        }
+    }
+
+    /// Generate the [TileKind]s of all tiles of a chunk
+    fn generate_chunk_tiles_kind(&self, chunk_pos: IVec2) -> Vec<TileKind> {
+        let coords = self.coords();
+        (0..coords.chunk_len())
+            .map(|index| coords.index_to_tile(chunk_pos, index))
+            .map(|tile| self.generate_tile_kind(tile.x, tile.y))
+            .collect()
+    }
+
+    /// [TileKind]s of a chunk, from the cache or generated
+    fn chunk_tiles_kind(&mut self, chunk_pos: IVec2) -> &[TileKind] {
+        if self.chunks_cache.get(chunk_pos).is_none() {
+            let tiles_kind = self.generate_chunk_tiles_kind(chunk_pos);
+            self.chunks_cache.insert(chunk_pos, tiles_kind);
+        }
+        self.chunks_cache
+            .get(chunk_pos)
+            .expect("The chunk was just cached")
+    }
+
+    fn tile_kind(&mut self, x: i32, y: i32) -> TileKind {
+        let coords = self.coords();
+        let tile = IVec2::new(x, y);
+        self.chunk_tiles_kind(coords.tile_to_chunk(tile))[coords.tile_index(tile)]
+    }
+
     #[allow(clippy::identity_op)]
     fn neighboors(&mut self, x: i32, y: i32) -> Neighbors {
         Neighbors([
@@ -183,24 +325,25 @@ impl ProceduralWorldMap {
         ])
     }
 
-    fn tile_index(&mut self, x: i32, y: i32, rng: &mut ThreadRng) -> u32 {
+    fn tile_index(&mut self, x: i32, y: i32, rng: &mut impl Rng) -> u32 {
         let n = self.neighboors(x, y);
+        let offset = self.tile_biome(x, y).tiles_offset(n.c());
         match n.c() {
             TileKind::Water => {
                 let rules = GenericNeighborRules(&n);
-                rules.index()
+                offset + rules.index()
             }
             TileKind::Mud => {
                 let rules = GenericNeighborRules(&n);
-                rules.index()
+                offset + rules.index()
             }
             TileKind::Grass => match rng.random_range(0..100) {
-                0..5 => 244,
-                5..10 => 265,
-                10..15 => 266,
-                15..20 => 267,
-                20..35 => 245,
-                _ => 264,
+                0..5 => offset + 90,
+                5..10 => offset + 111,
+                10..15 => offset + 112,
+                15..20 => offset + 113,
+                20..35 => offset + 91,
+                _ => offset + 110,
             },
         }
     }
@@ -212,22 +355,23 @@ impl ProceduralWorldMap {
         chunk_pos: IVec2,
     ) -> Entity {
         let chunk_size = self.config.chunk_size;
-        let x_offset = chunk_pos.x * chunk_size as i32;
-        let y_offset = chunk_pos.y * chunk_size as i32;
+        let coords = self.coords();
+        let first_tile = coords.chunk_to_tile(chunk_pos);
 
         let chunk_entity = commands
             .spawn((
-                WorldMapChunk,
+                WorldMapChunk(chunk_pos),
                 Name::new(format!("WorldMapChunk {chunk_pos}")),
             ))
             .id();
 
-        let mut rng = rand::rng();
+        let mut rng = self.chunk_rng(chunk_pos);
         let mut tile_storage = TileStorage::empty(UVec2::splat(chunk_size).into());
         for x in 0..chunk_size {
             for y in 0..chunk_size {
                 // (x, y) is in "chunk" coordinates, add offset to get the "world map" coordinates
-                let index = self.tile_index(x as i32 + x_offset, y as i32 + y_offset, &mut rng);
+                let tile = first_tile + UVec2::new(x, y).as_ivec2();
+                let index = self.tile_index(tile.x, tile.y, &mut rng);
                 let tile_pos = TilePos { x, y };
                 let tile_entity = commands
                     .spawn(TileBundle {
@@ -249,11 +393,7 @@ impl ProceduralWorldMap {
             y: f_tile_size,
         };
 
-        let translation = Vec3::new(
-            x_offset as f32 * f_tile_size,
-            y_offset as f32 * f_tile_size,
-            LAYER_MAP,
-        );
+        let translation = coords.chunk_to_world(chunk_pos).extend(LAYER_MAP);
         let chunk_size = UVec2::splat(chunk_size);
         commands.entity(chunk_entity).insert(TilemapBundle {
             grid_size: tile_size.into(),
@@ -269,33 +409,58 @@ impl ProceduralWorldMap {
             ..Default::default()
         });
         self.spawned_chunks.insert(chunk_pos);
+        self.explore_chunk(chunk_pos);
 
         chunk_entity
     }
 
-    pub fn remove_chunk_if_out_of_bound(&mut self, pos: Vec2, distance: f32) -> bool {
-        // TODO : retain self.tiles_kind
-        if distance > self.config.despawn_distance() {
-            let ratio = (self.config.chunk_size * self.config.tile_size) as f32;
-            let x = (pos.x / ratio).floor() as i32;
-            let y = (pos.y / ratio).floor() as i32;
-            self.spawned_chunks.remove(&IVec2::new(x, y));
-            true
-        } else {
-            false
+    /// Generate the [Prop]s of a chunk, with their position relative to the chunk.
+    ///
+    /// The props only depend on the map seed, except the destroyed ones
+    /// which are not generated again.
+    pub fn chunk_props(&mut self, chunk_pos: IVec2) -> Vec<(Prop, Vec2)> {
+        let chunk_size = self.config.chunk_size as i32;
+        let tile_size = self.config.tile_size as f32;
+        let mut rng = self.chunk_rng(chunk_pos);
+        let n_props = rng.random_range(0..=MAX_PROPS_PER_CHUNK);
+        let mut props = Vec::with_capacity(n_props);
+        for index in 0..n_props {
+            let x = rng.random_range(0..chunk_size);
+            let y = rng.random_range(0..chunk_size);
+            let tile = self.coords().chunk_to_tile(chunk_pos) + IVec2::new(x, y);
+            let kind = PropKind::gen(self.tile_biome(tile.x, tile.y), &mut rng);
+            if self.tile_kind(tile.x, tile.y) == TileKind::Water
+                || self.destroyed_props.contains(&(chunk_pos, index))
+            {
+                continue;
+            }
+            let prop = Prop {
+                kind,
+                chunk: chunk_pos,
+                index,
+            };
+            props.push((prop, Vec2::new(x as f32, y as f32) * tile_size));
         }
+        props
     }
 
-    #[allow(dead_code)]
-    pub fn pos_to_world(&self, x: i32, y: i32) -> Vec2 {
-        let pos = IVec2 { x, y };
-        let tile_size: IVec2 = IVec2::splat(self.config.tile_size as i32);
-        (pos * tile_size).as_vec2()
+    /// Keep the destroyed [Prop], so that it's not spawned again with its chunk.
+    ///
+    /// Returns `false` if the prop was already destroyed.
+    pub fn destroy_prop(&mut self, prop: &Prop) -> bool {
+        self.destroyed_props.insert((prop.chunk, prop.index))
     }
 
-    #[allow(dead_code)]
-    pub fn world_to_pos(&self, translation: Vec2) -> IVec2 {
-        (translation / Vec2::splat(self.config.tile_size as f32)).as_ivec2()
+    /// Forget the spawned chunk if its center is further than the
+    /// [WorldMapConfig::despawn_distance] from the camera
+    pub fn remove_chunk_if_out_of_bound(&mut self, chunk_pos: IVec2, camera_pos: Vec2) -> bool {
+        let distance = self.coords().chunk_center(chunk_pos).distance(camera_pos);
+        if distance > self.config.despawn_distance() {
+            self.spawned_chunks.remove(&chunk_pos);
+            true
+        } else {
+            false
+        }
     }
 }
 
@@ -393,20 +558,14 @@ macro_rules! tile_match {
 struct GenericNeighborRules<'a>(&'a Neighbors);
 
 impl GenericNeighborRules<'_> {
+    /// Index of the tile, relative to the [Biome::tiles_offset]
     fn index(&self) -> u32 {
-        let offset = match self.c() {
-            TileKind::Water => 11,
-            TileKind::Mud => 154,
-            _ => unreachable!("Only Water and Mud can have generic rules"),
-        };
-        let i = self
-            .rule_5()
+        self.rule_5()
             .or(self.rule_7())
             .or(self.rule_8())
             .or(self.rule_9())
             .or(self.rule_10())
-            .unwrap_or(23);
-        offset + i
+            .unwrap_or(23)
     }
 
     fn rule_5(&self) -> Option<u32> {
@@ -450,33 +609,184 @@ impl GenericNeighborRules<'_> {
     }
 }
 
-#[derive(Component)]
-pub struct WorldMapChunk;
+/// A chunk of the [ProceduralWorldMap], at its chunk position
+#[derive(Component, Deref)]
+pub struct WorldMapChunk(pub IVec2);
+
+///
+/// The tiles of a chunk revealed by the [super::player::LightRadius],
+/// with their [TileKind].
+///
+/// It is kept when the chunk is despawned or evicted from the [ChunksCache],
+/// so that the chunk is shown the same when the player comes back.
+///
+pub struct ChunkFog {
+    revealed: Vec<Option<TileKind>>,
+}
+
+impl ChunkFog {
+    fn new(chunk_len: usize) -> Self {
+        ChunkFog {
+            revealed: vec![None; chunk_len],
+        }
+    }
+
+    /// Is the tile at `index` in the chunk (row by row from the bottom) revealed
+    pub fn is_revealed(&self, index: usize) -> bool {
+        self.revealed.get(index).is_some_and(Option::is_some)
+    }
+}
+
+///
+/// Cache of the [TileKind]s of the chunks, to avoid generating them again
+/// for each tile and its neighbors.
+///
+/// When full, the least recently used chunk is evicted, so that the memory
+/// doesn't grow during a long run. An evicted chunk is generated again
+/// identically, as it only depends on the map seed.
+///
+struct ChunksCache {
+    capacity: usize,
+    chunks: HashMap<IVec2, CachedChunk>,
+    /// Incremented each time a chunk is used
+    counter: u64,
+}
+
+struct CachedChunk {
+    tiles_kind: Vec<TileKind>,
+    last_used: u64,
+}
+
+impl ChunksCache {
+    fn new(capacity: usize) -> Self {
+        ChunksCache {
+            capacity: capacity.max(1),
+            chunks: HashMap::new(),
+            counter: 0,
+        }
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    fn get(&mut self, chunk_pos: IVec2) -> Option<&[TileKind]> {
+        self.counter += 1;
+        let chunk = self.chunks.get_mut(&chunk_pos)?;
+        chunk.last_used = self.counter;
+        Some(&chunk.tiles_kind)
+    }
+
+    fn insert(&mut self, chunk_pos: IVec2, tiles_kind: Vec<TileKind>) {
+        if self.chunks.len() >= self.capacity {
+            let lru = self
+                .chunks
+                .iter()
+                .min_by_key(|(_, chunk)| chunk.last_used)
+                .map(|(pos, _)| *pos);
+            if let Some(lru) = lru {
+                self.chunks.remove(&lru);
+            }
+        }
+        self.counter += 1;
+        self.chunks.insert(
+            chunk_pos,
+            CachedChunk {
+                tiles_kind,
+                last_used: self.counter,
+            },
+        );
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq)]
-enum TileKind {
+pub enum TileKind {
     Water,
     Mud,
     Grass,
 }
 
+///
+/// Biome of an area of the world map, driven by a low frequency noise.
+///
+/// Each biome has its own tiles, and changes the monsters which spawn in it.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Reflect)]
+pub enum Biome {
+    #[default]
+    Forest,
+    Desert,
+    Snow,
+    Swamp,
+}
+
+impl Biome {
+    fn from_noise(noise_val: f64) -> Self {
+        if noise_val < -0.15 {
+            Biome::Desert
+        } else if noise_val < 0. {
+            Biome::Forest
+        } else if noise_val < 0.15 {
+            Biome::Swamp
+        } else {
+            Biome::Snow
+        }
+    }
+
+    /// Noise levels under which the tiles are [TileKind::Water] and [TileKind::Mud]
+    fn levels(&self) -> (f64, f64) {
+        match self {
+            Biome::Forest => (-0.4, 0.5),
+            Biome::Desert => (-0.6, 0.3),
+            Biome::Snow => (-0.4, 0.5),
+            Biome::Swamp => (-0.1, 0.6),
+        }
+    }
+
+    /// Offset of the tiles of the [TileKind] in `kte-floor.png`
+    ///
+    /// [TileKind::Grass] tiles are in the same tile set as [TileKind::Mud]
+    fn tiles_offset(&self, kind: TileKind) -> u32 {
+        match (self, kind) {
+            (Biome::Forest, TileKind::Water) => 11,
+            (Biome::Forest, _) => 154,
+            (Biome::Desert, TileKind::Water) => 11,
+            (Biome::Desert, _) => 0,
+            (Biome::Snow, TileKind::Water) => 462,
+            (Biome::Snow, _) => 308,
+            (Biome::Swamp, TileKind::Water) => 11,
+            (Biome::Swamp, _) => 165,
+        }
+    }
+}
+
 pub struct WorldMapConfig {
     pub noise_scale: f64,
+    pub biome_noise_scale: f64,
     pub chunk_size: u32,
     pub tile_size: u32,
+    /// Maximum number of chunks in the [TileKind]s cache
+    pub chunks_cache_size: usize,
 }
 
 impl Default for WorldMapConfig {
     fn default() -> Self {
         WorldMapConfig {
             noise_scale: 13.5,
+            biome_noise_scale: 250.,
             chunk_size: 20,
             tile_size: 16,
+            chunks_cache_size: 64,
         }
     }
 }
 
 impl WorldMapConfig {
+    pub fn coords(&self) -> MapCoords {
+        MapCoords::new(self.tile_size, self.chunk_size)
+    }
+
     pub fn despawn_distance(&self) -> f32 {
         (self.tile_size * self.chunk_size * 10) as f32
     }
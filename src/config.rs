@@ -2,17 +2,34 @@ use bevy::{
     asset::{io::file::FileAssetReader, ron},
     prelude::*,
 };
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::path::PathBuf;
 
 #[derive(Resource, Debug, Asset, TypePath, Deserialize)]
+#[serde(default)]
 pub struct GameConfig {
     pub monster_spawn_delay: u64,
+    /// Show the floating damage numbers
+    pub damage_numbers: bool,
+    pub map_mode: MapMode,
+}
+
+/// Kind of world map
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum MapMode {
+    /// Infinite map, generated from a noise
+    #[default]
+    Procedural,
+    /// Finite levels of the LDtk project
+    Handcrafted,
 }
 
 impl Default for GameConfig {
     fn default() -> Self {
         GameConfig {
             monster_spawn_delay: 15,
+            damage_numbers: true,
+            map_mode: MapMode::Procedural,
         }
     }
 }
@@ -29,12 +46,24 @@ impl Plugin for GameConfigPlugin {
     }
 }
 
-fn load_config(mut commands: Commands) -> Result {
+/// Path of a file located in the `assets` folder
+pub fn asset_path(filename: &str) -> PathBuf {
     let mut path = FileAssetReader::get_base_path();
     path.push("assets");
-    path.push("config.ron");
-    let config_string = std::fs::read_to_string(path.as_path())?;
-    let config: GameConfig = ron::from_str(&config_string)?;
+    path.push(filename);
+    path
+}
+
+///
+/// Read and deserialize a RON file located in the `assets` folder
+///
+pub fn load_ron_file<T: DeserializeOwned>(filename: &str) -> Result<T> {
+    let content = std::fs::read_to_string(asset_path(filename))?;
+    Ok(ron::from_str(&content)?)
+}
+
+fn load_config(mut commands: Commands) -> Result {
+    let config: GameConfig = load_ron_file("config.ron")?;
     info!("load_config: {config:?}");
     commands.insert_resource(config);
     commands.trigger(ConfigLoaded);
@@ -63,7 +63,11 @@ impl Plugin for DebugPlugin {
             Update,
             (
                 toggle_debug_mode.run_if(input_just_released(KeyCode::KeyD)),
-                (count_entities, show_player_pos).run_if(input_just_released(KeyCode::KeyL)),
+                (
+                    count_entities,
+                    show_player_pos.run_if(resource_exists::<ProceduralWorldMap>),
+                )
+                    .run_if(input_just_released(KeyCode::KeyL)),
                 (
                     log_transitions::<GameState>,
                     log_transitions::<InGameState>,
@@ -165,15 +169,16 @@ fn show_key_pressed(inputs: Res<ButtonInput<KeyCode>>) {
 fn show_player_pos(players: Query<&Transform, With<Player>>, world_map: Res<ProceduralWorldMap>) {
     if let Ok(transform) = players.single() {
         let player_translation = transform.translation.xy();
-        let player_pos = world_map.world_to_pos(player_translation);
-        let chunk_pos = world_map.chunk_pos(player_translation);
+        let coords = world_map.coords();
+        let player_pos = coords.world_to_tile(player_translation);
+        let chunk_pos = coords.world_to_chunk(player_translation);
         info!("Player - map pos: {player_pos}, chunk pos: {chunk_pos}, world translation: {player_translation}");
     }
 }
 
 fn show_map_axes(mut gizmos: Gizmos, world_map: Res<ProceduralWorldMap>) {
-    let zero = world_map.pos_to_world(0, 0);
-    let one = world_map.pos_to_world(1, 1);
+    let zero = world_map.coords().tile_to_world(IVec2::ZERO);
+    let one = world_map.coords().tile_to_world(IVec2::ONE);
     gizmos.line_2d(zero, zero + one, Color::srgba_u8(20, 172, 121, 255));
 }
 
@@ -1,16 +1,21 @@
 use crate::{
     components::{
         affix::{
-            Armour, ArmourUpdateQuery, IncreaseAreaOfEffect, IncreaseAttackSpeed, IncreaseDamage,
-            IncreaseMaxLife, IncreaseMovementSpeed, LifeRegen, MoreArmour, MoreDamage, MoreLife,
-            PierceChance,
+            Armour, ArmourUpdateQuery, Dexterity, EnergyShield, EnergyShieldUpdateQuery, Evasion,
+            EvasionUpdateQuery, IncreaseAreaOfEffect, IncreaseAttackSpeed, IncreaseDamage,
+            IncreaseItemQuantity, IncreaseItemRarity, IncreaseLightRadius, IncreaseMaxLife,
+            IncreaseMovementSpeed, Intelligence, LifeLeech, LifeRegen, MoreArmour, MoreDamage,
+            MoreEnergyShield, MoreEvasion, MoreLife, PierceChance, Strength,
+        },
+        character::{
+            BaseAttributes, BaseLife, BaseMovementSpeed, Character, Life, MaxLife, MovementSpeed,
         },
-        character::{BaseLife, BaseMovementSpeed, Character, Life, MaxLife, MovementSpeed},
         damage::{BaseDamageOverTime, BaseHitDamageRange, DamageOverTime, HitDamageRange},
         equipment::{
             weapon::{AttackSpeed, AttackTimer, BaseAttackSpeed},
             Equipment, Weapon,
         },
+        player::{LightRadius, Player},
         skills::Skill,
     },
     schedule::game_is_running,
@@ -20,6 +25,7 @@ use bevy::prelude::*;
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, SystemSet)]
 enum PreUpdateAffixes {
     LocalEquipment,
+    Attributes,
     Characters,
     Skills,
 }
@@ -33,6 +39,7 @@ impl Plugin for AffixUpdatesPlugin {
             PreUpdate,
             (
                 PreUpdateAffixes::LocalEquipment,
+                PreUpdateAffixes::Attributes,
                 PreUpdateAffixes::Characters,
                 PreUpdateAffixes::Skills,
             )
@@ -44,19 +51,28 @@ impl Plugin for AffixUpdatesPlugin {
             (
                 (
                     update_equipment_armour,
+                    update_equipment_evasion,
+                    update_equipment_energy_shield,
                     update_weapon_attack_speed,
                     update_weapon_hit_damage_range,
                 )
                     .in_set(PreUpdateAffixes::LocalEquipment),
+                update_character_attributes.in_set(PreUpdateAffixes::Attributes),
                 (
                     (update_max_life, update_life_regen).chain(),
                     update_character_armour,
+                    update_character_evasion,
+                    update_character_energy_shield,
                     update_character_movement_speed,
                     update_character_increase_attack_speed,
                     update_character_pierce_chance,
+                    update_character_life_leech,
                     update_character_more_damage,
                     update_character_increase_damage,
                     update_increase_area_of_effect,
+                    update_character_item_quantity,
+                    update_character_item_rarity,
+                    update_player_light_radius,
                 )
                     .in_set(PreUpdateAffixes::Characters),
                 (
@@ -97,6 +113,18 @@ fn update_equipment_armour(mut equipments: Query<ArmourUpdateQuery, With<Equipme
     }
 }
 
+fn update_equipment_evasion(mut equipments: Query<EvasionUpdateQuery, With<Equipment>>) {
+    for mut evasion in &mut equipments {
+        evasion.update();
+    }
+}
+
+fn update_equipment_energy_shield(mut equipments: Query<EnergyShieldUpdateQuery, With<Equipment>>) {
+    for mut energy_shield in &mut equipments {
+        energy_shield.update();
+    }
+}
+
 /// Weapon [AttackSpeed] = [BaseAttackSpeed] * [IncreaseAttackSpeed]
 ///
 /// Update also the [AttackTimer] based on the new [AttackSpeed].
@@ -141,29 +169,130 @@ fn update_character_armour(
     }
 }
 
-/// [MaxLife] = ([BaseLife] + sum([MoreLife])) * sum([IncreaseMaxLife]) %
+/// [Evasion] = sum([Equipment] [Evasion]) + sum ([MoreEvasion] affixes)
+fn update_character_evasion(
+    mut characters: Query<&mut Evasion, With<Character>>,
+    equipment_evasions: Query<(&Evasion, &ChildOf), (With<Equipment>, Without<Character>)>,
+    more_evasions: Query<(&MoreEvasion, &ChildOf), (Without<Equipment>, Without<Character>)>,
+) {
+    for mut evasion in &mut characters {
+        evasion.reset();
+    }
+    for (eqp_evasion, child_of) in &equipment_evasions {
+        if let Ok(mut evasion) = characters.get_mut(child_of.parent()) {
+            evasion.add(eqp_evasion);
+        }
+    }
+    for (more_evasion, child_of) in &more_evasions {
+        if let Ok(mut evasion) = characters.get_mut(child_of.parent()) {
+            evasion.more(more_evasion);
+        }
+    }
+}
+
+/// [EnergyShield] = sum([Equipment] [EnergyShield]) + sum ([MoreEnergyShield] affixes)
+fn update_character_energy_shield(
+    mut characters: Query<&mut EnergyShield, With<Character>>,
+    equipment_energy_shields: Query<
+        (&EnergyShield, &ChildOf),
+        (With<Equipment>, Without<Character>),
+    >,
+    more_energy_shields: Query<
+        (&MoreEnergyShield, &ChildOf),
+        (Without<Equipment>, Without<Character>),
+    >,
+) {
+    for mut energy_shield in &mut characters {
+        energy_shield.reset();
+    }
+    for (eqp_energy_shield, child_of) in &equipment_energy_shields {
+        if let Ok(mut energy_shield) = characters.get_mut(child_of.parent()) {
+            energy_shield.add(eqp_energy_shield);
+        }
+    }
+    for (more_energy_shield, child_of) in &more_energy_shields {
+        if let Ok(mut energy_shield) = characters.get_mut(child_of.parent()) {
+            energy_shield.more(more_energy_shield);
+        }
+    }
+}
+
+/// [Strength] = [BaseAttributes] + sum([Strength]), and the same for
+/// [Dexterity] and [Intelligence]
+fn update_character_attributes(
+    mut characters: Query<
+        (
+            &BaseAttributes,
+            &mut Strength,
+            &mut Dexterity,
+            &mut Intelligence,
+        ),
+        With<Character>,
+    >,
+    strengths: Query<(&Strength, &ChildOf), Without<Character>>,
+    dexterities: Query<(&Dexterity, &ChildOf), Without<Character>>,
+    intelligences: Query<(&Intelligence, &ChildOf), Without<Character>>,
+) {
+    for (base, mut strength, mut dexterity, mut intelligence) in &mut characters {
+        *strength = Strength(base.strength);
+        *dexterity = Dexterity(base.dexterity);
+        *intelligence = Intelligence(base.intelligence);
+    }
+    for (affix, child_of) in &strengths {
+        if let Ok((_, mut strength, _, _)) = characters.get_mut(child_of.parent()) {
+            strength.add(affix);
+        }
+    }
+    for (affix, child_of) in &dexterities {
+        if let Ok((_, _, mut dexterity, _)) = characters.get_mut(child_of.parent()) {
+            dexterity.add(affix);
+        }
+    }
+    for (affix, child_of) in &intelligences {
+        if let Ok((_, _, _, mut intelligence)) = characters.get_mut(child_of.parent()) {
+            intelligence.add(affix);
+        }
+    }
+}
+
+/// [MaxLife] = ([BaseLife] + [Strength] bonus + sum([MoreLife])) * sum([IncreaseMaxLife]) %
 fn update_max_life(
-    mut characters: Query<(&BaseLife, &mut MaxLife, &mut IncreaseMaxLife), With<Character>>,
+    mut characters: Query<
+        (
+            &BaseLife,
+            &Strength,
+            &mut MaxLife,
+            &mut IncreaseMaxLife,
+            &mut Life,
+        ),
+        With<Character>,
+    >,
     more_affixes: Query<(&MoreLife, &ChildOf), Without<Character>>,
     incr_affixes: Query<(&IncreaseMaxLife, &ChildOf), Without<Character>>,
 ) {
-    for (base_life, mut max_life, mut incr_life) in &mut characters {
+    for (base_life, strength, mut max_life, mut incr_life, _) in &mut characters {
         max_life.init(base_life);
+        max_life.more(&strength.more_life());
         incr_life.reset();
     }
     for (more_life, child_of) in &more_affixes {
-        if let Ok((_base_life, mut max_life, _incr_life)) = characters.get_mut(child_of.parent()) {
+        if let Ok((_, _, mut max_life, _, _)) = characters.get_mut(child_of.parent()) {
             max_life.more(more_life);
         }
     }
     for (incr_life, child_of) in &incr_affixes {
-        if let Ok((_base_life, _life, mut incr_char_life)) = characters.get_mut(child_of.parent()) {
+        if let Ok((_, _, _, mut incr_char_life, _)) = characters.get_mut(child_of.parent()) {
             incr_char_life.add(incr_life);
         }
     }
 
-    for (_base_life, mut max_life, incr_life) in &mut characters {
+    for (_, _, mut max_life, incr_life, mut life) in &mut characters {
         max_life.increase(&incr_life);
+        // A new character starts with its full life, including the affixes
+        // spawned with it
+        if life.is_added() {
+            **life = **max_life;
+        }
     }
 }
 
@@ -208,16 +337,17 @@ fn update_character_movement_speed(
     }
 }
 
-/// [IncreaseAttackSpeed] = sum([IncreaseAttackSpeed])
+/// [IncreaseAttackSpeed] = [Dexterity] bonus + sum([IncreaseAttackSpeed])
 fn update_character_increase_attack_speed(
-    mut characters: Query<&mut IncreaseAttackSpeed, With<Character>>,
+    mut characters: Query<(&mut IncreaseAttackSpeed, &Dexterity), With<Character>>,
     affixes: Query<(&IncreaseAttackSpeed, &ChildOf), (Without<Character>, Without<Weapon>)>,
 ) {
-    for mut character_incr_attack_speed in &mut characters {
+    for (mut character_incr_attack_speed, dexterity) in &mut characters {
         character_incr_attack_speed.reset();
+        character_incr_attack_speed.add(&dexterity.increase_attack_speed());
     }
     for (incr_attack_speed, child_of) in &affixes {
-        if let Ok(mut character_incr_attack_speed) = characters.get_mut(child_of.parent()) {
+        if let Ok((mut character_incr_attack_speed, _)) = characters.get_mut(child_of.parent()) {
             character_incr_attack_speed.add(incr_attack_speed);
         }
     }
@@ -239,6 +369,70 @@ fn update_character_pierce_chance(
     }
 }
 
+/// [LifeLeech] = sum([LifeLeech])
+fn update_character_life_leech(
+    mut characters: Query<&mut LifeLeech, With<Character>>,
+    affixes: Query<(&LifeLeech, &ChildOf), Without<Character>>,
+) {
+    for mut life_leech in &mut characters {
+        life_leech.reset();
+    }
+
+    for (affix, child_of) in &affixes {
+        if let Ok(mut life_leech) = characters.get_mut(child_of.parent()) {
+            life_leech.add(affix);
+        }
+    }
+}
+
+/// [IncreaseItemQuantity] = sum([IncreaseItemQuantity])
+fn update_character_item_quantity(
+    mut characters: Query<&mut IncreaseItemQuantity, With<Character>>,
+    affixes: Query<(&IncreaseItemQuantity, &ChildOf), Without<Character>>,
+) {
+    for mut item_quantity in &mut characters {
+        item_quantity.reset();
+    }
+    for (increase, child_of) in &affixes {
+        if let Ok(mut item_quantity) = characters.get_mut(child_of.parent()) {
+            item_quantity.add(increase);
+        }
+    }
+}
+
+/// [IncreaseItemRarity] = sum([IncreaseItemRarity])
+fn update_character_item_rarity(
+    mut characters: Query<&mut IncreaseItemRarity, With<Character>>,
+    affixes: Query<(&IncreaseItemRarity, &ChildOf), Without<Character>>,
+) {
+    for mut item_rarity in &mut characters {
+        item_rarity.reset();
+    }
+    for (increase, child_of) in &affixes {
+        if let Ok(mut item_rarity) = characters.get_mut(child_of.parent()) {
+            item_rarity.add(increase);
+        }
+    }
+}
+
+/// [LightRadius] = base * [IncreaseLightRadius], with [IncreaseLightRadius] = sum([IncreaseLightRadius])
+fn update_player_light_radius(
+    mut players: Query<(&mut LightRadius, &mut IncreaseLightRadius), With<Player>>,
+    affixes: Query<(&IncreaseLightRadius, &ChildOf), Without<Player>>,
+) {
+    for (_, mut increase_light_radius) in &mut players {
+        increase_light_radius.reset();
+    }
+    for (increase, child_of) in &affixes {
+        if let Ok((_, mut increase_light_radius)) = players.get_mut(child_of.parent()) {
+            increase_light_radius.add(increase);
+        }
+    }
+    for (mut light_radius, increase) in &mut players {
+        light_radius.update(&increase);
+    }
+}
+
 /// [MoreDamage] = sum([MoreDamage])
 fn update_character_more_damage(
     mut characters: Query<&mut MoreDamage, With<Character>>,
@@ -269,16 +463,17 @@ fn update_character_increase_damage(
     }
 }
 
-/// [IncreaseAreaOfEffect] = sum([IncreaseAreaOfEffect])
+/// [IncreaseAreaOfEffect] = [Intelligence] bonus + sum([IncreaseAreaOfEffect])
 fn update_increase_area_of_effect(
-    mut characters: Query<&mut IncreaseAreaOfEffect, With<Character>>,
+    mut characters: Query<(&mut IncreaseAreaOfEffect, &Intelligence), With<Character>>,
     affixes: Query<(&IncreaseAreaOfEffect, &ChildOf), Without<Character>>,
 ) {
-    for mut incr_aoe in &mut characters {
+    for (mut incr_aoe, intelligence) in &mut characters {
         incr_aoe.reset();
+        incr_aoe.add(&intelligence.increase_area_of_effect());
     }
     for (incr_aoe, child_of) in &affixes {
-        if let Ok(mut char_incr_aoe) = characters.get_mut(child_of.parent()) {
+        if let Ok((mut char_incr_aoe, _)) = characters.get_mut(child_of.parent()) {
             char_incr_aoe.add(incr_aoe);
         }
     }
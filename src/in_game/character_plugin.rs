@@ -1,28 +1,42 @@
 use crate::{
     components::{
         affix::{
-            Armour, BaseArmour, IncreaseAreaOfEffect, IncreaseAttackSpeed, IncreaseDamage,
-            IncreaseMaxLife, IncreaseMovementSpeed, LifeRegen, MoreArmour, MoreDamage, MoreLife,
-            PierceChance,
+            Armour, BaseArmour, BaseEnergyShield, BaseEvasion, EnergyShield, Evasion,
+            IncreaseAreaOfEffect, IncreaseAttackSpeed, IncreaseDamage, IncreaseMaxLife,
+            IncreaseMovementSpeed, LifeLeech, LifeRegen, MoreArmour, MoreDamage, MoreEnergyShield,
+            MoreEvasion, MoreLife, PierceChance,
         },
         animation::AnimationTimer,
         character::{
             BaseLife, BaseMovementSpeed, Character, CharacterDiedEvent, CharacterDyingEvent,
-            CharacterLevel, HitEvent, Life, LooseLifeEvent, MaxLife, MovementAction, MovementSpeed,
-            Target,
+            CharacterLevel, CurrentEnergyShield, HitEvent, Life, LifeLeechPool, LooseLifeEvent,
+            MaxLife, MovementAction, MovementSpeed, Target,
+        },
+        damage::{
+            BaseDamageOverTime, BaseHitDamageRange, DamageKind, DamageOverTime,
+            DamageOverTimeSource, DamageSource, HitDamageRange,
         },
-        damage::{BaseDamageOverTime, BaseHitDamageRange, DamageOverTime, HitDamageRange},
         equipment::{
             weapon::{AttackSpeed, AttackTimer, BaseAttackSpeed},
             Equipment,
         },
         inventory::TakeDroppedItemEvent,
         item::DroppedItem,
+        map_item::ActiveMap,
+        player::Player,
+        status_effect::{AddStatusEffectEvent, StatusEffect},
     },
     schedule::GameRunningSet,
 };
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::Velocity;
+use std::time::Duration;
+
+/// Icon of the damage over time [StatusEffect]
+const DAMAGE_OVER_TIME_TILE_INDEX: usize = 301;
+
+/// Duration of the damage over time [StatusEffect] after leaving the zone
+const DAMAGE_OVER_TIME_STATUS_DURATION: Duration = Duration::from_millis(500);
 
 pub struct CharacterPlugin;
 
@@ -34,6 +48,10 @@ impl Plugin for CharacterPlugin {
             .register_type::<MoreLife>()
             .register_type::<IncreaseMaxLife>()
             .register_type::<LifeRegen>()
+            .register_type::<LifeLeech>()
+            .register_type::<LifeLeechPool>()
+            .register_type::<DamageSource>()
+            .register_type::<DamageOverTimeSource>()
             .register_type::<BaseMovementSpeed>()
             .register_type::<MovementSpeed>()
             .register_type::<IncreaseMovementSpeed>()
@@ -41,6 +59,13 @@ impl Plugin for CharacterPlugin {
             .register_type::<PierceChance>()
             .register_type::<Armour>()
             .register_type::<MoreArmour>()
+            .register_type::<Evasion>()
+            .register_type::<BaseEvasion>()
+            .register_type::<MoreEvasion>()
+            .register_type::<EnergyShield>()
+            .register_type::<BaseEnergyShield>()
+            .register_type::<MoreEnergyShield>()
+            .register_type::<CurrentEnergyShield>()
             .register_type::<MoreDamage>()
             .register_type::<IncreaseDamage>()
             .register_type::<IncreaseAreaOfEffect>()
@@ -62,7 +87,13 @@ impl Plugin for CharacterPlugin {
             .add_event::<CharacterDiedEvent>()
             .add_systems(
                 Update,
-                (regen_life, mitigate_damage_over_time, do_character_action)
+                (
+                    regen_life,
+                    leech_life,
+                    recharge_energy_shield,
+                    mitigate_damage_over_time,
+                    do_character_action,
+                )
                     .in_set(GameRunningSet::EntityUpdate),
             )
             .add_systems(
@@ -95,13 +126,32 @@ fn add_life_observers(trigger: Trigger<OnAdd, Character>, mut commands: Commands
 fn mitigate_damage_on_hit(
     trigger: Trigger<HitEvent>,
     mut commands: Commands,
-    characters: Query<&Armour, With<Character>>,
+    characters: Query<(&Armour, &Evasion), With<Character>>,
+    mut leechers: Query<(&LifeLeech, &mut LifeLeechPool)>,
 ) {
-    if let Ok(armour) = characters.get(trigger.target()) {
+    if let Ok((armour, evasion)) = characters.get(trigger.target()) {
+        let mut rng = rand::rng();
+        if evasion.try_evade(&mut rng) {
+            info!("trigger_take_hit: evaded");
+            return;
+        }
         let damage = armour.mitigate(trigger.damage);
         info!("trigger_take_hit: damage: {:.1}", *damage);
         if *damage > 0. {
-            commands.trigger_targets(LooseLifeEvent(damage), trigger.target());
+            let source = trigger.source;
+            commands.trigger_targets(
+                LooseLifeEvent {
+                    damage,
+                    kind: DamageKind::Hit,
+                    source,
+                },
+                trigger.target(),
+            );
+
+            // The owner of the damager leeches a part of the damage
+            if let Ok((leech, mut pool)) = leechers.get_mut(source.owner) {
+                pool.add(leech.leech(damage));
+            }
         }
     }
 }
@@ -109,12 +159,14 @@ fn mitigate_damage_on_hit(
 fn loose_life(
     trigger: Trigger<LooseLifeEvent>,
     mut commands: Commands,
-    mut characters: Query<&mut Life, With<Character>>,
+    mut characters: Query<(&mut Life, &mut CurrentEnergyShield), With<Character>>,
 ) {
-    if let Ok(mut life) = characters.get_mut(trigger.target()) {
-        life.damage(**trigger);
+    if let Ok((mut life, mut energy_shield)) = characters.get_mut(trigger.target()) {
+        let damage = energy_shield.absorb(trigger.damage);
+        life.damage(damage);
         if life.is_dead() {
-            commands.trigger_targets(CharacterDyingEvent, trigger.target());
+            let killer = trigger.source;
+            commands.trigger_targets(CharacterDyingEvent { killer }, trigger.target());
         }
     }
 }
@@ -126,25 +178,79 @@ fn despawn_character_on_death(mut events: EventReader<CharacterDiedEvent>, mut c
 }
 
 /// [Character] is curently having [DamageOverTime]. Mitigate it whith [Armour]
+///
+/// Only the [Player] shows the status effect, as it is only displayed in the HUD
 fn mitigate_damage_over_time(
     mut commands: Commands,
-    characters: Query<(Entity, &Armour, &DamageOverTime), With<Character>>,
+    characters: Query<
+        (
+            Entity,
+            &Armour,
+            &DamageOverTime,
+            &DamageOverTimeSource,
+            Has<Player>,
+        ),
+        With<Character>,
+    >,
     time: Res<Time>,
 ) {
-    for (entity, armour, dot) in &characters {
+    for (entity, armour, dot, &DamageOverTimeSource(source), is_player) in &characters {
         let damage = armour.mitigate(dot.damage(&time));
-        commands.trigger_targets(LooseLifeEvent(damage), entity);
+        commands.trigger_targets(
+            LooseLifeEvent {
+                damage,
+                kind: DamageKind::OverTime,
+                source,
+            },
+            entity,
+        );
+        if !is_player {
+            continue;
+        }
+        commands.trigger_targets(
+            AddStatusEffectEvent(StatusEffect::new(
+                "Damage over time",
+                DAMAGE_OVER_TIME_TILE_INDEX,
+                DAMAGE_OVER_TIME_STATUS_DURATION,
+            )),
+            entity,
+        );
     }
 }
 
 /// Regenerate [Character]'s [Life]
-fn regen_life(mut query: Query<(&mut Life, &MaxLife, &LifeRegen)>, time: Res<Time>) {
-    for (mut life, &max_life, &regen) in &mut query {
-        let life_per_sec = *max_life * (*regen / 100.);
+///
+/// The [ActiveMap] can reduce the regeneration of the [Player]
+fn regen_life(
+    mut query: Query<(&mut Life, &MaxLife, &LifeRegen, Has<Player>)>,
+    active_map: Option<Res<ActiveMap>>,
+    time: Res<Time>,
+) {
+    let player_multiplier = active_map.map_or(1., |map| map.rules.player_regen_multiplier());
+    for (mut life, &max_life, &regen, is_player) in &mut query {
+        let multiplier = if is_player { player_multiplier } else { 1. };
+        let life_per_sec = *max_life * (*regen / 100.) * multiplier;
         life.regenerate(life_per_sec * time.delta_secs(), max_life);
     }
 }
 
+/// Recover the [Life] leeched by [Character]s
+fn leech_life(mut query: Query<(&mut Life, &MaxLife, &mut LifeLeechPool)>, time: Res<Time>) {
+    for (mut life, &max_life, mut pool) in &mut query {
+        pool.recover(&mut life, max_life, time.delta_secs());
+    }
+}
+
+/// Recharge [Character]'s [CurrentEnergyShield], up to its [EnergyShield]
+fn recharge_energy_shield(
+    mut query: Query<(&mut CurrentEnergyShield, &EnergyShield)>,
+    time: Res<Time>,
+) {
+    for (mut current, max) in &mut query {
+        current.recharge(time.delta(), max);
+    }
+}
+
 fn do_character_action(
     mut commands: Commands,
     mut characters: Query<
@@ -1,7 +1,11 @@
 use crate::components::character::{Character, HitEvent, MovementAction};
-use crate::components::damage::{DamageOverTime, Damager, HitDamageRange};
+use crate::components::damage::{
+    DamageOverTime, DamageOverTimeSource, DamageSource, Damager, HitDamageRange,
+};
 use crate::components::monster::Monster;
 use crate::components::player::Player;
+use crate::components::prop::{DestructibleProp, Prop, PropDestroyedEvent};
+use crate::components::world_map::ProceduralWorldMap;
 use crate::schedule::GameRunningSet;
 use crate::utils::collision::{start_event_filter, QueryEither};
 use bevy::prelude::*;
@@ -17,6 +21,7 @@ impl Plugin for CollisionsPlugin {
                 check_if_character_is_hit,
                 check_if_character_is_in_damage_over_time_zone,
                 player_touched_by_monster,
+                check_if_prop_is_hit.run_if(resource_exists::<ProceduralWorldMap>),
                 stop_move_on_collision_between_characters,
             )
                 .in_set(GameRunningSet::EntityUpdate),
@@ -31,7 +36,7 @@ fn check_if_character_is_hit(
     mut commands: Commands,
     mut collisions: EventReader<CollisionEvent>,
     characters: Query<(), With<Character>>,
-    damagers: Query<&HitDamageRange, With<Damager>>,
+    damagers: Query<(&HitDamageRange, &DamageSource), With<Damager>>,
 ) {
     let mut rng = rand::rng();
 
@@ -43,12 +48,19 @@ fn check_if_character_is_hit(
             let (_, character, other) = characters.get_either(e1, e2)?;
             damagers
                 .get(other)
-                .map(|damage_range| (character, other, damage_range))
+                .map(|(damage_range, &source)| (character, other, damage_range, source))
                 .ok()
         })
-        .for_each(|(character, damager, damage_range)| {
+        .for_each(|(character, damager, damage_range, source)| {
             let damage = damage_range.gen(&mut rng);
-            commands.trigger_targets(HitEvent { damager, damage }, character);
+            commands.trigger_targets(
+                HitEvent {
+                    damager,
+                    source,
+                    damage,
+                },
+                character,
+            );
         });
 }
 
@@ -62,7 +74,7 @@ fn check_if_character_is_in_damage_over_time_zone(
     mut commands: Commands,
     mut collisions: EventReader<CollisionEvent>,
     characters: Query<(), With<Character>>,
-    damagers: Query<&DamageOverTime, With<Damager>>,
+    damagers: Query<(&DamageOverTime, &DamageSource), With<Damager>>,
 ) {
     let get_dot = |e1, e2| {
         characters
@@ -74,13 +86,17 @@ fn check_if_character_is_in_damage_over_time_zone(
     for &event in collisions.read() {
         match event {
             CollisionEvent::Started(e1, e2, _) => {
-                if let Ok((entity, &dot)) = get_dot(e1, e2).or(get_dot(e2, e1)) {
-                    commands.entity(entity).insert(dot);
+                if let Ok((entity, (&dot, &source))) = get_dot(e1, e2).or(get_dot(e2, e1)) {
+                    commands
+                        .entity(entity)
+                        .insert((dot, DamageOverTimeSource(source)));
                 }
             }
             CollisionEvent::Stopped(e1, e2, _) => {
                 if let Ok((entity, _)) = get_dot(e1, e2).or(get_dot(e2, e1)) {
-                    commands.entity(entity).remove::<DamageOverTime>();
+                    commands
+                        .entity(entity)
+                        .remove::<(DamageOverTime, DamageOverTimeSource)>();
                 }
             }
         }
@@ -113,6 +129,7 @@ fn player_touched_by_monster(
             commands.trigger_targets(
                 HitEvent {
                     damager: monster,
+                    source: DamageSource::from_character(monster),
                     damage,
                 },
                 player,
@@ -120,6 +137,33 @@ fn player_touched_by_monster(
         });
 }
 
+///
+/// [DestructibleProp] hit by a [Damager]
+///
+fn check_if_prop_is_hit(
+    mut commands: Commands,
+    mut collisions: EventReader<CollisionEvent>,
+    props: Query<(&Prop, &GlobalTransform), With<DestructibleProp>>,
+    damagers: Query<(), With<Damager>>,
+    mut world_map: ResMut<ProceduralWorldMap>,
+    mut prop_destroyed_events: EventWriter<PropDestroyedEvent>,
+) {
+    for (&e1, &e2) in collisions.read().filter_map(start_event_filter) {
+        let Some(((prop, transform), prop_entity, other)) = props.get_either(e1, e2) else {
+            continue;
+        };
+        // A prop can be hit by multiple damagers at the same time
+        if damagers.contains(other) && world_map.destroy_prop(prop) {
+            info!("Prop {:?} destroyed", prop.kind);
+            prop_destroyed_events.write(PropDestroyedEvent {
+                pos: transform.translation().xy(),
+                kind: prop.kind,
+            });
+            commands.entity(prop_entity).despawn();
+        }
+    }
+}
+
 fn stop_move_on_collision_between_characters(
     mut characters: Query<&mut MovementAction, With<Character>>,
     mut collisions: EventReader<CollisionEvent>,
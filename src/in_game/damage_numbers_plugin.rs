@@ -0,0 +1,161 @@
+use crate::{
+    components::{
+        character::LooseLifeEvent, damage::DamageKind, despawn_all, player::Player,
+        world_map::LAYER_PLAYER,
+    },
+    config::GameConfig,
+    schedule::{GameRunningSet, GameState},
+    utils::despawn_after::DespawnAfter,
+};
+use bevy::{
+    color::palettes::css::{RED, VIOLET},
+    prelude::*,
+};
+use std::time::Duration;
+
+/// Maximum number of [DamageNumber]s shown at the same time
+const MAX_DAMAGE_NUMBERS: usize = 60;
+
+/// Speed (in pixels per second) of the [DamageNumber]s drifting up
+const DRIFT_SPEED: f32 = 30.;
+
+/// Delay to sum the damage over time ticks before showing them
+const DAMAGE_OVER_TIME_DELAY: f32 = 0.5;
+
+/// Plugin that shows floating damage numbers over the damaged characters
+pub struct DamageNumbersPlugin;
+
+impl Plugin for DamageNumbersPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DamageNumberCount>()
+            .add_systems(OnExit(GameState::InGame), despawn_all::<DamageNumber>)
+            .add_systems(
+                Update,
+                (show_damage_over_time, animate_damage_numbers)
+                    .in_set(GameRunningSet::EntityUpdate),
+            )
+            .add_observer(show_damage_on_loose_life)
+            .add_observer(count_despawned_damage_number);
+    }
+}
+
+/// Number of [DamageNumber]s currently spawned, including the ones spawned
+/// by [Commands] which are not applied yet
+#[derive(Resource, Default, Deref, DerefMut)]
+struct DamageNumberCount(usize);
+
+///
+/// A floating damage number, which drifts up and fades out
+///
+#[derive(Component)]
+#[require(
+    Name::new("DamageNumber"),
+    Text2d,
+    TextFont::from_font_size(12.),
+    TextColor,
+    DespawnAfter::new(Duration::from_millis(800))
+)]
+struct DamageNumber;
+
+///
+/// The damage over time taken by a character, summed until it is shown
+///
+#[derive(Component)]
+struct DamageOverTimeNumber {
+    damage: f32,
+    timer: Timer,
+}
+
+fn damage_number(damage: f32, color: Color, pos: Vec2) -> impl Bundle {
+    (
+        DamageNumber,
+        Text2d::new(format!("{damage:.0}")),
+        TextColor(color),
+        Transform::from_translation(pos.extend(LAYER_PLAYER + 1.)),
+    )
+}
+
+/// Hits are white when dealt to monsters, and red when dealt to the player.
+/// Damage over time is violet.
+fn damage_color(kind: DamageKind, is_player: bool) -> Color {
+    match (kind, is_player) {
+        (DamageKind::OverTime, _) => VIOLET.into(),
+        (DamageKind::Hit, true) => RED.into(),
+        (DamageKind::Hit, false) => Color::WHITE,
+    }
+}
+
+fn show_damage_on_loose_life(
+    trigger: Trigger<LooseLifeEvent>,
+    mut commands: Commands,
+    mut characters: Query<(&Transform, Option<&mut DamageOverTimeNumber>)>,
+    players: Query<(), With<Player>>,
+    mut count: ResMut<DamageNumberCount>,
+    config: Res<GameConfig>,
+) {
+    if !config.damage_numbers {
+        return;
+    }
+    let Ok((transform, dot_number)) = characters.get_mut(trigger.target()) else {
+        return;
+    };
+    match trigger.kind {
+        DamageKind::Hit => {
+            if **count < MAX_DAMAGE_NUMBERS {
+                let color = damage_color(trigger.kind, players.contains(trigger.target()));
+                let pos = transform.translation.xy() + Vec2::new(0., 16.);
+                commands.spawn(damage_number(*trigger.damage, color, pos));
+                **count += 1;
+            }
+        }
+        DamageKind::OverTime => match dot_number {
+            Some(mut dot_number) => dot_number.damage += *trigger.damage,
+            None => {
+                commands
+                    .entity(trigger.target())
+                    .insert(DamageOverTimeNumber {
+                        damage: *trigger.damage,
+                        timer: Timer::from_seconds(DAMAGE_OVER_TIME_DELAY, TimerMode::Once),
+                    });
+            }
+        },
+    }
+}
+
+/// Show the damage over time summed during [DAMAGE_OVER_TIME_DELAY]
+fn show_damage_over_time(
+    mut commands: Commands,
+    mut characters: Query<(Entity, &Transform, &mut DamageOverTimeNumber)>,
+    mut count: ResMut<DamageNumberCount>,
+    time: Res<Time>,
+) {
+    for (entity, transform, mut dot_number) in &mut characters {
+        dot_number.timer.tick(time.delta());
+        if dot_number.timer.finished() {
+            commands.entity(entity).remove::<DamageOverTimeNumber>();
+            if dot_number.damage >= 0.5 && **count < MAX_DAMAGE_NUMBERS {
+                let pos = transform.translation.xy() + Vec2::new(0., 24.);
+                let color = damage_color(DamageKind::OverTime, false);
+                commands.spawn(damage_number(dot_number.damage, color, pos));
+                **count += 1;
+            }
+        }
+    }
+}
+
+fn count_despawned_damage_number(
+    _trigger: Trigger<OnRemove, DamageNumber>,
+    mut count: ResMut<DamageNumberCount>,
+) {
+    **count = count.saturating_sub(1);
+}
+
+fn animate_damage_numbers(
+    mut numbers: Query<(&mut Transform, &mut TextColor, &DespawnAfter), With<DamageNumber>>,
+    time: Res<Time>,
+) {
+    for (mut transform, mut color, despawn_after) in &mut numbers {
+        transform.translation.y += DRIFT_SPEED * time.delta_secs();
+        color.0.set_alpha(1. - despawn_after.fraction());
+    }
+}
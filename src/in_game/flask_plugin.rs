@@ -0,0 +1,176 @@
+use crate::{
+    components::{
+        character::{Life, MaxLife},
+        flask::{
+            EquipFlaskEvent, Flask, FlaskCharges, FlaskEffect, PlayerFlasks, RemoveFlaskEvent,
+            UseFlaskEvent, N_FLASK_SLOTS,
+        },
+        inventory::{AddToInventoryEvent, PlayerEquipmentChanged, RemoveFromInventoryEvent},
+        monster::{MonsterDeathEvent, MonsterRarity},
+        player::Player,
+        status_effect::{AddStatusEffectEvent, StatusEffect},
+    },
+    schedule::GameRunningSet,
+};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Keys to use the flasks of the belt
+const FLASK_KEYS: [KeyCode; N_FLASK_SLOTS] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+];
+
+pub struct FlaskPlugin;
+
+impl Plugin for FlaskPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Flask>()
+            .register_type::<FlaskCharges>()
+            .register_type::<PlayerFlasks>()
+            .add_systems(Update, trigger_use_flask.in_set(GameRunningSet::UserInput))
+            .add_systems(
+                Update,
+                (gain_flask_charges, update_flask_effects).in_set(GameRunningSet::EntityUpdate),
+            )
+            .add_observer(equip_flask)
+            .add_observer(remove_flask)
+            .add_observer(use_flask);
+    }
+}
+
+fn trigger_use_flask(mut commands: Commands, keys: Res<ButtonInput<KeyCode>>) {
+    for (slot, key) in FLASK_KEYS.iter().enumerate() {
+        if keys.just_pressed(*key) {
+            commands.trigger(UseFlaskEvent(slot));
+        }
+    }
+}
+
+fn equip_flask(
+    trigger: Trigger<EquipFlaskEvent>,
+    mut commands: Commands,
+    flasks: Query<(), With<Flask>>,
+    mut players: Query<(Entity, &mut PlayerFlasks), With<Player>>,
+) {
+    let flask = trigger.flask;
+    if !flasks.contains(flask) {
+        warn!("Can't equip {flask} as it's not a Flask");
+        return;
+    }
+    if trigger.slot >= N_FLASK_SLOTS {
+        warn!("Can't equip {flask} in slot {}", trigger.slot);
+        return;
+    }
+
+    let (player, mut player_flasks) = players
+        .single_mut()
+        .expect("Player should have a PlayerFlasks");
+    if player_flasks.get(trigger.slot) == Some(flask) {
+        // same flask: no need to continue
+        return;
+    }
+    player_flasks.remove(flask);
+    let old_flask = player_flasks.set(trigger.slot, flask);
+
+    // Manage inventory
+    commands.trigger(RemoveFromInventoryEvent(flask));
+    if let Some(old_flask) = old_flask {
+        commands.trigger(AddToInventoryEvent::new(old_flask));
+    }
+
+    info!("Equip flask {flask} in slot {}", trigger.slot);
+
+    commands.entity(player).add_child(flask);
+    commands.trigger(PlayerEquipmentChanged);
+}
+
+fn remove_flask(trigger: Trigger<RemoveFlaskEvent>, mut players: Query<&mut PlayerFlasks>) {
+    if let Ok(mut player_flasks) = players.single_mut() {
+        if player_flasks.remove(trigger.0) {
+            info!("Remove flask {}", trigger.0);
+        }
+    }
+}
+
+fn use_flask(
+    trigger: Trigger<UseFlaskEvent>,
+    mut commands: Commands,
+    mut players: Query<(Entity, &PlayerFlasks, &mut Life, &MaxLife), With<Player>>,
+    mut flasks: Query<(&Flask, &mut FlaskCharges)>,
+) {
+    let Ok((player, player_flasks, mut life, &max_life)) = players.single_mut() else {
+        return;
+    };
+    let Some((flask, mut charges)) = player_flasks
+        .get(trigger.0)
+        .and_then(|entity| flasks.get_mut(entity).ok())
+    else {
+        return;
+    };
+    if !charges.consume(flask.kind.charges_per_use()) {
+        info!("Not enough charges to use flask {}", trigger.0 + 1);
+        return;
+    }
+
+    if flask.kind.recovery_duration().is_none() {
+        life.regenerate(flask.kind.life(), max_life);
+    }
+    let mut effect = commands.spawn((FlaskEffect::new(flask.kind), ChildOf(player)));
+    if let Some(affix) = flask.affix {
+        affix.insert(&mut effect);
+    }
+
+    let duration = Duration::from_secs_f32(flask.kind.effect_duration());
+    let status = StatusEffect::new(flask.kind.title(), flask.kind.tile_index(), duration)
+        .with_max_stacks(N_FLASK_SLOTS as u16);
+    commands.trigger_targets(AddStatusEffectEvent(status), player);
+}
+
+/// Recover the life of the flasks over time, and remove the finished effects
+fn update_flask_effects(
+    mut commands: Commands,
+    mut effects: Query<(Entity, &mut FlaskEffect, &ChildOf)>,
+    mut characters: Query<(&mut Life, &MaxLife)>,
+    time: Res<Time>,
+) {
+    for (entity, mut effect, &ChildOf(character)) in &mut effects {
+        effect.timer.tick(time.delta());
+        if let Ok((mut life, &max_life)) = characters.get_mut(character) {
+            life.regenerate(effect.life_per_second * time.delta_secs(), max_life);
+        }
+        if effect.timer.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Charges given to all flasks of the belt when a monster is killed
+fn charges_on_kill(rarity: MonsterRarity) -> u16 {
+    match rarity {
+        MonsterRarity::Normal => 1,
+        MonsterRarity::Magic => 3,
+        MonsterRarity::Rare => 6,
+    }
+}
+
+fn gain_flask_charges(
+    mut monster_death_events: EventReader<MonsterDeathEvent>,
+    players: Query<&PlayerFlasks, With<Player>>,
+    mut flasks: Query<&mut FlaskCharges>,
+) {
+    let Ok(player_flasks) = players.single() else {
+        return;
+    };
+    for event in monster_death_events.read() {
+        let charges = charges_on_kill(event.rarity);
+        for flask in player_flasks.iter() {
+            if let Ok(mut flask_charges) = flasks.get_mut(flask) {
+                flask_charges.add(charges);
+            }
+        }
+    }
+}
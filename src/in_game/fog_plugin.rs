@@ -0,0 +1,203 @@
+use crate::{
+    components::{
+        affix::IncreaseLightRadius,
+        map_coords::MapCoords,
+        monster::Monster,
+        player::{LightRadius, Player},
+        world_map::{ProceduralWorldMap, WorldMapChunk, LAYER_FOG},
+    },
+    schedule::{AreaState, GameRunningSet},
+};
+use bevy::{
+    asset::RenderAssetUsages,
+    image::ImageSampler,
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+    sprite::Anchor,
+};
+
+/// Alpha of the fog over the revealed tiles out of the light
+const DIM_ALPHA: u8 = 140;
+
+///
+/// The fog of war of the [ProceduralWorldMap]: out of the [LightRadius] of the
+/// player, the revealed tiles are dimmed and the other ones are dark.
+///
+/// The monsters are only visible in the light.
+///
+pub struct FogPlugin;
+
+impl Plugin for FogPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<LightRadius>()
+            .register_type::<IncreaseLightRadius>()
+            .init_resource::<LitArea>()
+            .add_systems(OnExit(AreaState::Hunting), reset_lit_area)
+            .add_systems(
+                Update,
+                (
+                    (spawn_chunk_fogs, update_fog)
+                        .chain()
+                        .run_if(resource_exists::<ProceduralWorldMap>),
+                    update_monsters_visibility,
+                )
+                    .in_set(GameRunningSet::EntityUpdate),
+            );
+    }
+}
+
+/// The tiles lit by the player, in "world map" coordinates
+#[derive(Clone, Copy, PartialEq)]
+struct LitTiles {
+    center: IVec2,
+    radius: i32,
+}
+
+impl LitTiles {
+    fn contains(&self, tile: IVec2) -> bool {
+        (tile - self.center).length_squared() <= self.radius * self.radius
+    }
+
+    /// Does the light reach the `chunk`
+    fn reaches(&self, chunk: IVec2, coords: &MapCoords) -> bool {
+        let low = coords.tile_to_chunk(self.center - IVec2::splat(self.radius));
+        let high = coords.tile_to_chunk(self.center + IVec2::splat(self.radius));
+        (low.x..=high.x).contains(&chunk.x) && (low.y..=high.y).contains(&chunk.y)
+    }
+}
+
+/// The [LitTiles] when the fog was last drawn
+#[derive(Resource, Default, Deref, DerefMut)]
+struct LitArea(Option<LitTiles>);
+
+/// The fog over a [WorldMapChunk], as its child, with one pixel per tile
+#[derive(Component, Deref)]
+#[require(Name::new("ChunkFog"))]
+struct ChunkFogSprite(IVec2);
+
+fn reset_lit_area(mut lit_area: ResMut<LitArea>) {
+    **lit_area = None;
+}
+
+/// Draw the fog of a chunk: clear in the light, dimmed over the revealed tiles,
+/// and dark elsewhere
+fn draw_fog(
+    image: &mut Image,
+    chunk_pos: IVec2,
+    world_map: &ProceduralWorldMap,
+    lit: Option<LitTiles>,
+) {
+    let Some(data) = image.data.as_mut() else {
+        return;
+    };
+    let coords = world_map.coords();
+    let chunk_size = coords.chunk_size();
+    let fog = world_map.chunk_fog(chunk_pos);
+    for (pixel_index, pixel) in data.chunks_exact_mut(4).enumerate() {
+        // The rows of the image go down, the tiles go up
+        let x = pixel_index as i32 % chunk_size;
+        let y = chunk_size - 1 - pixel_index as i32 / chunk_size;
+        let tile = coords.chunk_to_tile(chunk_pos) + IVec2::new(x, y);
+        let alpha = if lit.is_some_and(|lit| lit.contains(tile)) {
+            0
+        } else if fog.is_some_and(|fog| fog.is_revealed(coords.tile_index(tile))) {
+            DIM_ALPHA
+        } else {
+            u8::MAX
+        };
+        pixel.copy_from_slice(&[0, 0, 0, alpha]);
+    }
+}
+
+fn spawn_chunk_fogs(
+    mut commands: Commands,
+    chunks: Query<(Entity, &WorldMapChunk), Added<WorldMapChunk>>,
+    world_map: Res<ProceduralWorldMap>,
+    lit_area: Res<LitArea>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let coords = world_map.coords();
+    let chunk_size = coords.chunk_size() as u32;
+    let tile_size = coords.tile_size();
+    for (chunk, &WorldMapChunk(chunk_pos)) in &chunks {
+        let mut image = Image::new_fill(
+            Extent3d {
+                width: chunk_size,
+                height: chunk_size,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[0, 0, 0, u8::MAX],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        // Smooth the border of the light
+        image.sampler = ImageSampler::linear();
+        draw_fog(&mut image, chunk_pos, &world_map, **lit_area);
+        commands.spawn((
+            ChunkFogSprite(chunk_pos),
+            Sprite {
+                image: images.add(image),
+                custom_size: Some(Vec2::splat(chunk_size as f32 * tile_size)),
+                anchor: Anchor::BottomLeft,
+                ..Default::default()
+            },
+            // The tiles are centered on their position in the chunk
+            Transform::from_xyz(-tile_size / 2., -tile_size / 2., LAYER_FOG),
+            ChildOf(chunk),
+        ));
+    }
+}
+
+/// Reveal the tiles around the player, and draw again the fog of the chunks
+/// reached by the light, when the player moves to another tile
+fn update_fog(
+    fogs: Query<(&Sprite, &ChunkFogSprite)>,
+    player: Single<(&Transform, &LightRadius), With<Player>>,
+    mut world_map: ResMut<ProceduralWorldMap>,
+    mut lit_area: ResMut<LitArea>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let (transform, light_radius) = *player;
+    let coords = world_map.coords();
+    let lit = LitTiles {
+        center: coords.world_to_tile(transform.translation.xy()),
+        radius: (**light_radius / coords.tile_size()).round() as i32,
+    };
+    let previous = lit_area.replace(lit);
+    if previous == Some(lit) {
+        return;
+    }
+
+    world_map.reveal(lit.center, lit.radius);
+    for (sprite, fog) in &fogs {
+        // Also clear the light from its previous position
+        let reached = [Some(lit), previous]
+            .into_iter()
+            .flatten()
+            .any(|lit| lit.reaches(**fog, &coords));
+        if !reached {
+            continue;
+        }
+        if let Some(image) = images.get_mut(&sprite.image) {
+            draw_fog(image, **fog, &world_map, Some(lit));
+        }
+    }
+}
+
+/// The monsters are only visible in the [LightRadius] of the player
+fn update_monsters_visibility(
+    mut monsters: Query<(&Transform, &mut Visibility), With<Monster>>,
+    player: Single<(&Transform, &LightRadius), With<Player>>,
+) {
+    let (player_transform, light_radius) = *player;
+    let center = player_transform.translation.xy();
+    for (transform, mut visibility) in &mut monsters {
+        let visible = if transform.translation.xy().distance(center) <= **light_radius {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        visibility.set_if_neq(visible);
+    }
+}
@@ -0,0 +1,83 @@
+use super::Hud;
+use crate::{
+    components::{
+        flask::{FlaskCharges, PlayerFlasks, N_FLASK_SLOTS},
+        inventory::PlayerEquipmentChanged,
+        player::Player,
+    },
+    schedule::{GameRunningSet, GameState},
+    ui::panel_flasks::flask_location,
+};
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
+
+pub struct HudFlasksPlugin;
+
+impl Plugin for HudFlasksPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::InGame), spawn_hud_flasks)
+            .add_systems(
+                Update,
+                update_charges_texts.in_set(GameRunningSet::EntityUpdate),
+            );
+    }
+}
+
+#[derive(Component)]
+#[require(
+    Hud,
+    Name::new("HUD - Flasks"),
+    Node {
+        position_type: PositionType::Absolute,
+        align_items: AlignItems::End,
+        left: Val::Px(50.),
+        bottom: Val::Px(15.),
+        column_gap: Val::Px(2.),
+        ..Default::default()
+    }
+)]
+struct HudFlasksPanel;
+
+/// Charges of the flask of a slot
+#[derive(Component)]
+#[require(
+    Text,
+    TextFont = TextFont::from_font_size(10.),
+    TextColor(Color::WHITE)
+)]
+struct FlaskChargesText(usize);
+
+fn spawn_hud_flasks(mut commands: Commands) {
+    commands.spawn((
+        HudFlasksPanel,
+        Children::spawn(SpawnIter((0..N_FLASK_SLOTS).map(|slot| {
+            (
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                children![flask_location(slot), FlaskChargesText(slot)],
+            )
+        }))),
+    ));
+
+    // to force to update flasks
+    commands.trigger(PlayerEquipmentChanged);
+}
+
+fn update_charges_texts(
+    players: Query<&PlayerFlasks, With<Player>>,
+    flasks: Query<&FlaskCharges>,
+    mut texts: Query<(&mut Text, &FlaskChargesText)>,
+) {
+    let Ok(player_flasks) = players.single() else {
+        return;
+    };
+    for (mut text, &FlaskChargesText(slot)) in &mut texts {
+        text.0 = player_flasks
+            .get(slot)
+            .and_then(|flask| flasks.get(flask).ok())
+            .map(|charges| format!("{}: {charges}", slot + 1))
+            .unwrap_or_else(|| format!("{}", slot + 1));
+    }
+}
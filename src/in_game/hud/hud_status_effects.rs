@@ -0,0 +1,150 @@
+use super::Hud;
+use crate::{
+    components::{item::ItemAssets, player::Player, status_effect::StatusEffect},
+    schedule::{GameRunningSet, GameState},
+};
+use bevy::prelude::*;
+
+/// Size of a [StatusEffect] icon
+const ICON_SIZE: f32 = 32.;
+
+pub struct HudStatusEffectsPlugin;
+
+impl Plugin for HudStatusEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::InGame), spawn_hud_status_effects)
+            .add_systems(
+                Update,
+                (
+                    spawn_status_effect_icons,
+                    despawn_status_effect_icons,
+                    update_status_effect_texts,
+                )
+                    .chain()
+                    .in_set(GameRunningSet::EntityUpdate),
+            );
+    }
+}
+
+/// The active [StatusEffect]s of the player, below the life bar
+#[derive(Component)]
+#[require(
+    Hud,
+    Name::new("HUD - StatusEffects"),
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(50.),
+        top: Val::Px(58.),
+        column_gap: Val::Px(4.),
+        ..Default::default()
+    }
+)]
+struct HudStatusEffectsPanel;
+
+/// Icon of the [StatusEffect] entity
+#[derive(Component)]
+#[require(
+    Name::new("HUD - StatusEffect"),
+    Node {
+        width: Val::Px(ICON_SIZE),
+        height: Val::Px(ICON_SIZE),
+        ..Default::default()
+    },
+    BackgroundColor(Srgba::rgba_u8(40, 40, 40, 160).into())
+)]
+struct HudStatusEffect(Entity);
+
+/// Text of a [HudStatusEffect] icon
+#[derive(Component, Clone, Copy)]
+#[require(Text, TextFont::from_font_size(10.), TextColor(Color::WHITE))]
+enum StatusEffectText {
+    /// Remaining duration, in the bottom of the icon
+    Duration,
+    /// Stack count, in the top right corner of the icon
+    Stacks,
+}
+
+fn status_effect_text(text: StatusEffectText) -> impl Bundle {
+    let node = match text {
+        StatusEffectText::Duration => Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(1.),
+            bottom: Val::Px(0.),
+            ..Default::default()
+        },
+        StatusEffectText::Stacks => Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(1.),
+            top: Val::Px(0.),
+            ..Default::default()
+        },
+    };
+    (text, node)
+}
+
+fn spawn_hud_status_effects(mut commands: Commands) {
+    commands.spawn(HudStatusEffectsPanel);
+}
+
+fn spawn_status_effect_icons(
+    mut commands: Commands,
+    panel: Single<Entity, With<HudStatusEffectsPanel>>,
+    effects: Query<(Entity, &StatusEffect, &ChildOf), Added<StatusEffect>>,
+    players: Query<(), With<Player>>,
+    assets: Res<ItemAssets>,
+) {
+    for (entity, effect, child_of) in &effects {
+        if players.contains(child_of.parent()) {
+            commands.spawn((
+                HudStatusEffect(entity),
+                ChildOf(*panel),
+                children![
+                    (
+                        assets.image_node(effect.tile_index),
+                        Node {
+                            width: Val::Percent(100.),
+                            height: Val::Percent(100.),
+                            ..Default::default()
+                        }
+                    ),
+                    status_effect_text(StatusEffectText::Duration),
+                    status_effect_text(StatusEffectText::Stacks)
+                ],
+            ));
+        }
+    }
+}
+
+/// Despawn the icons of the finished [StatusEffect]s
+fn despawn_status_effect_icons(
+    mut commands: Commands,
+    icons: Query<(Entity, &HudStatusEffect)>,
+    effects: Query<(), With<StatusEffect>>,
+) {
+    for (icon, &HudStatusEffect(effect)) in &icons {
+        if !effects.contains(effect) {
+            commands.entity(icon).despawn();
+        }
+    }
+}
+
+fn update_status_effect_texts(
+    mut texts: Query<(&mut Text, &StatusEffectText, &ChildOf)>,
+    icons: Query<&HudStatusEffect>,
+    effects: Query<&StatusEffect>,
+) {
+    for (mut text, status_text, child_of) in &mut texts {
+        let Some(effect) = icons
+            .get(child_of.parent())
+            .ok()
+            .and_then(|icon| effects.get(icon.0).ok())
+        else {
+            continue;
+        };
+        text.0 = match status_text {
+            StatusEffectText::Duration => format!("{:.0}s", effect.remaining_secs().ceil()),
+            StatusEffectText::Stacks if effect.stacks > 1 => format!("x{}", effect.stacks),
+            StatusEffectText::Stacks => String::new(),
+        };
+    }
+}
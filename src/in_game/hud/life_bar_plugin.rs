@@ -1,20 +1,27 @@
 use super::Hud;
 use crate::{
     components::{
-        character::{Life, MaxLife},
+        affix::EnergyShield,
+        character::{CurrentEnergyShield, Life, MaxLife},
         player::{Player, PlayerDeathEvent},
     },
     schedule::{GameRunningSet, GameState},
     ui::progressbar::{ProgressBar, ProgressBarColor},
 };
-use bevy::{color::palettes::css::RED, prelude::*};
+use bevy::{
+    color::palettes::css::{DEEP_SKY_BLUE, RED},
+    prelude::*,
+};
 
 pub struct LifeBarPlugin;
 
 impl Plugin for LifeBarPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(GameState::InGame), spawn_life_bar)
-            .add_systems(Update, update_life_bar.in_set(GameRunningSet::EntityUpdate))
+            .add_systems(
+                Update,
+                (update_life_bar, update_energy_shield_bar).in_set(GameRunningSet::EntityUpdate),
+            )
             .add_observer(update_life_bar_on_death);
     }
 }
@@ -39,8 +46,30 @@ impl Plugin for LifeBarPlugin {
 )]
 struct LifeBar;
 
+/// Energy shield of the player, below the [LifeBar]
+#[derive(Component)]
+#[require(
+    Hud,
+    Name::new("HUD - EnergyShieldBar"),
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(50.),
+        top: Val::Px(42.),
+        width: Val::Px(300.),
+        height: Val::Px(10.),
+        border: UiRect::all(Val::Px(2.)),
+        ..Default::default()
+    },
+    BackgroundColor(Color::BLACK),
+    BorderColor(Color::BLACK),
+    ProgressBar,
+    ProgressBarColor(DEEP_SKY_BLUE.into())
+)]
+struct EnergyShieldBar;
+
 fn spawn_life_bar(mut commands: Commands) {
     commands.spawn(LifeBar);
+    commands.spawn(EnergyShieldBar);
 }
 
 fn update_life_bar(
@@ -55,6 +84,24 @@ fn update_life_bar(
     }
 }
 
+fn update_energy_shield_bar(
+    q_player: Query<(&CurrentEnergyShield, &EnergyShield), With<Player>>,
+    mut q_bar: Query<(&mut ProgressBar, &mut Visibility), With<EnergyShieldBar>>,
+) {
+    if let Ok((mut progressbar, mut visibility)) = q_bar.single_mut() {
+        if let Ok((energy_shield, max_energy_shield)) = q_player.single() {
+            progressbar.max = **max_energy_shield;
+            progressbar.value = energy_shield.value();
+            // Only show the bar when the player has energy shield
+            visibility.set_if_neq(if **max_energy_shield > 0. {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            });
+        }
+    }
+}
+
 fn update_life_bar_on_death(
     _trigger: Trigger<PlayerDeathEvent>,
     mut q_bar: Query<&mut ProgressBar, With<LifeBar>>,
@@ -1,6 +1,6 @@
 use super::Hud;
 use crate::{
-    components::world_map::CurrentMapLevel,
+    components::{map_item::ActiveMap, world_map::CurrentMapLevel},
     schedule::{GameRunningSet, GameState},
 };
 use bevy::prelude::*;
@@ -44,9 +44,16 @@ fn spawn_panel(mut commands: Commands) {
 fn update_panel(
     mut texts: Query<&mut Text, With<MapLevelText>>,
     current_map_level: Res<CurrentMapLevel>,
+    active_map: Option<Res<ActiveMap>>,
 ) {
     for mut text in &mut texts {
-        let info = format!("monster_level: {}", **current_map_level);
+        let mut info = format!("monster_level: {}", **current_map_level);
+        if let Some(map) = &active_map {
+            info.push_str(&format!(
+                "\nmap tier {}: {}/{} kills",
+                map.tier, map.kills, map.goal
+            ));
+        }
         *text = Text(info);
     }
 }
@@ -0,0 +1,298 @@
+use super::Hud;
+use crate::{
+    components::{
+        item::DroppedItem,
+        loot_filter::LootFilterHidden,
+        monster::{Monster, MonsterRarity},
+        player::Player,
+        world_map::{ProceduralWorldMap, TileKind},
+    },
+    schedule::{GameRunningSet, GameState},
+};
+use bevy::{
+    asset::RenderAssetUsages,
+    image::ImageSampler,
+    input::common_conditions::input_just_pressed,
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+    time::common_conditions::on_timer,
+};
+use std::time::Duration;
+
+/// Side of the [Minimap] texture, in tiles, with one pixel per tile
+const MINIMAP_TILES: u32 = 96;
+
+/// Side of the [MapOverlay] texture, in tiles
+const OVERLAY_TILES: u32 = 192;
+
+/// Period of the redraw of the maps
+const REDRAW_PERIOD: Duration = Duration::from_millis(200);
+
+/// Size of the [Minimap] on the screen
+const MINIMAP_SIZE: f32 = 144.;
+
+const UNEXPLORED_COLOR: [u8; 4] = [10, 10, 10, 160];
+const PLAYER_COLOR: [u8; 4] = [255, 255, 255, 255];
+const MONSTER_COLOR: [u8; 4] = [220, 40, 40, 255];
+const RARE_MONSTER_COLOR: [u8; 4] = [255, 200, 30, 255];
+const ITEM_COLOR: [u8; 4] = [80, 230, 230, 255];
+
+///
+/// The maps of the revealed tiles of the [ProceduralWorldMap]: a small one
+/// in the corner of the screen, and a full-screen overlay toggled with `M`.
+///
+/// They are drawn into a texture from the revealed [TileKind]s of the chunks,
+/// with the monsters in the light, and the dropped items shown by the loot filter.
+///
+pub struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MapOverlayOpen>()
+            .add_systems(OnEnter(GameState::InGame), spawn_maps)
+            .add_systems(
+                Update,
+                toggle_overlay
+                    .run_if(input_just_pressed(KeyCode::KeyM))
+                    .in_set(GameRunningSet::UserInput),
+            )
+            .add_systems(
+                Update,
+                (
+                    show_maps,
+                    draw_maps
+                        .run_if(resource_exists::<ProceduralWorldMap>.and(on_timer(REDRAW_PERIOD))),
+                )
+                    .chain()
+                    .in_set(GameRunningSet::EntityUpdate),
+            );
+    }
+}
+
+/// Is the [MapOverlay] shown instead of the [Minimap]
+#[derive(Resource, Default, Deref, DerefMut)]
+struct MapOverlayOpen(bool);
+
+/// A map drawn into the [Image] of its [ImageNode], with `tiles` tiles per side
+#[derive(Component)]
+struct MapTexture {
+    tiles: u32,
+    /// What is currently drawn, to only draw again when it changes
+    drawn: Option<MapContent>,
+}
+
+impl MapTexture {
+    fn new(tiles: u32) -> Self {
+        MapTexture { tiles, drawn: None }
+    }
+}
+
+/// Content of a [MapTexture]
+#[derive(Clone, PartialEq)]
+struct MapContent {
+    fog_revision: u32,
+    center: IVec2,
+    dots: Vec<(IVec2, [u8; 4])>,
+}
+
+/// The map in the corner of the screen
+#[derive(Component)]
+#[require(
+    Hud,
+    Name::new("HUD - Minimap"),
+    Node {
+        position_type: PositionType::Absolute,
+        right: Val::Px(0.),
+        top: Val::Px(140.),
+        width: Val::Px(MINIMAP_SIZE),
+        height: Val::Px(MINIMAP_SIZE),
+        border: UiRect::all(Val::Px(1.)),
+        ..Default::default()
+    },
+    BorderColor(Color::BLACK),
+    Visibility::Hidden
+)]
+struct Minimap;
+
+/// The full-screen map
+#[derive(Component)]
+#[require(
+    Hud,
+    Name::new("HUD - MapOverlay"),
+    Node {
+        position_type: PositionType::Absolute,
+        width: Val::Percent(100.),
+        height: Val::Percent(100.),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..Default::default()
+    },
+    BackgroundColor(Color::srgba(0., 0., 0., 0.5)),
+    Visibility::Hidden
+)]
+struct MapOverlay;
+
+fn map_image(images: &mut Assets<Image>, tiles: u32) -> Handle<Image> {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: tiles,
+            height: tiles,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &UNEXPLORED_COLOR,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.sampler = ImageSampler::nearest();
+    images.add(image)
+}
+
+fn spawn_maps(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut overlay_open: ResMut<MapOverlayOpen>,
+) {
+    **overlay_open = false;
+    commands.spawn((
+        Minimap,
+        MapTexture::new(MINIMAP_TILES),
+        ImageNode::new(map_image(&mut images, MINIMAP_TILES)),
+    ));
+    commands.spawn((
+        MapOverlay,
+        children![(
+            MapTexture::new(OVERLAY_TILES),
+            ImageNode::new(map_image(&mut images, OVERLAY_TILES)),
+            Node {
+                height: Val::Percent(90.),
+                aspect_ratio: Some(1.),
+                ..Default::default()
+            },
+        )],
+    ));
+}
+
+fn toggle_overlay(mut overlay_open: ResMut<MapOverlayOpen>) {
+    **overlay_open = !**overlay_open;
+}
+
+/// The maps are only shown on the [ProceduralWorldMap]
+fn show_maps(
+    mut minimaps: Query<&mut Visibility, (With<Minimap>, Without<MapOverlay>)>,
+    mut overlays: Query<&mut Visibility, With<MapOverlay>>,
+    overlay_open: Res<MapOverlayOpen>,
+    world_map: Option<Res<ProceduralWorldMap>>,
+) {
+    let visibility = |shown: bool| {
+        if shown && world_map.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        }
+    };
+    for mut minimap in &mut minimaps {
+        minimap.set_if_neq(visibility(!**overlay_open));
+    }
+    for mut overlay in &mut overlays {
+        overlay.set_if_neq(visibility(**overlay_open));
+    }
+}
+
+fn tile_color(kind: TileKind) -> [u8; 4] {
+    match kind {
+        TileKind::Water => [40, 70, 160, 220],
+        TileKind::Mud => [110, 90, 60, 220],
+        TileKind::Grass => [60, 120, 50, 220],
+    }
+}
+
+fn draw_maps(
+    mut maps: Query<(&mut MapTexture, &ImageNode, &InheritedVisibility)>,
+    player: Single<&GlobalTransform, With<Player>>,
+    monsters: Query<(&GlobalTransform, &MonsterRarity, &Visibility), With<Monster>>,
+    items: Query<&GlobalTransform, (With<DroppedItem>, Without<LootFilterHidden>)>,
+    world_map: Res<ProceduralWorldMap>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let coords = world_map.coords();
+    let tile_pos = |transform: &GlobalTransform| coords.world_to_tile(transform.translation().xy());
+    let center = tile_pos(*player);
+    let mut dots = items
+        .iter()
+        .map(|transform| (tile_pos(transform), ITEM_COLOR))
+        .collect::<Vec<_>>();
+    dots.extend(
+        monsters
+            .iter()
+            .filter(|(_, _, visibility)| **visibility != Visibility::Hidden)
+            .map(|(transform, rarity, _)| {
+                let color = match rarity {
+                    MonsterRarity::Rare => RARE_MONSTER_COLOR,
+                    MonsterRarity::Normal | MonsterRarity::Magic => MONSTER_COLOR,
+                };
+                (tile_pos(transform), color)
+            }),
+    );
+    dots.push((center, PLAYER_COLOR));
+    let content = MapContent {
+        fog_revision: world_map.fog_revision(),
+        center,
+        dots,
+    };
+
+    for (mut map, image_node, visibility) in &mut maps {
+        if !visibility.get() {
+            continue;
+        }
+        // A new world map can have the same content
+        if !world_map.is_added() && map.drawn.as_ref() == Some(&content) {
+            continue;
+        }
+        if let Some(image) = images.get_mut(&image_node.image) {
+            draw_map(image, map.tiles, &world_map, &content);
+            map.drawn = Some(content.clone());
+        }
+    }
+}
+
+/// Draw the revealed tiles around the `center` of the [MapContent],
+/// then its `dots` over them
+fn draw_map(image: &mut Image, tiles: u32, world_map: &ProceduralWorldMap, content: &MapContent) {
+    let Some(data) = image.data.as_mut() else {
+        return;
+    };
+    for pixel in data.chunks_exact_mut(4) {
+        pixel.copy_from_slice(&UNEXPLORED_COLOR);
+    }
+
+    let tiles = tiles as i32;
+    let min = content.center - IVec2::splat(tiles / 2);
+    let mut set_pixel = |pos: IVec2, color: [u8; 4]| {
+        let pos = pos - min;
+        if (0..tiles).contains(&pos.x) && (0..tiles).contains(&pos.y) {
+            // The rows of the image go down, the tiles go up
+            let index = (((tiles - 1 - pos.y) * tiles + pos.x) * 4) as usize;
+            data[index..index + 4].copy_from_slice(&color);
+        }
+    };
+
+    let coords = world_map.coords();
+    let min_chunk = coords.tile_to_chunk(min);
+    let max_chunk = coords.tile_to_chunk(min + IVec2::splat(tiles - 1));
+    for chunk_y in min_chunk.y..=max_chunk.y {
+        for chunk_x in min_chunk.x..=max_chunk.x {
+            let chunk_pos = IVec2::new(chunk_x, chunk_y);
+            for (index, kind) in world_map.revealed_tiles_kind(chunk_pos) {
+                set_pixel(coords.index_to_tile(chunk_pos, index), tile_color(kind));
+            }
+        }
+    }
+
+    // The dots are 2x2 pixels, to be visible on the small map
+    for &(pos, color) in &content.dots {
+        for offset in [IVec2::ZERO, IVec2::X, IVec2::Y, IVec2::ONE] {
+            set_pixel(pos + offset, color);
+        }
+    }
+}
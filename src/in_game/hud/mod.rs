@@ -1,14 +1,19 @@
+mod hud_flasks;
 mod hud_skills;
+mod hud_status_effects;
 mod life_bar_plugin;
 mod map_level_plugin;
+mod minimap_plugin;
 mod xp_bar_plugin;
 
 pub use plugin::{Hud, HudPlugin};
 
 mod plugin {
     use super::{
-        hud_skills::HudSkillsPlugin, life_bar_plugin::LifeBarPlugin,
-        map_level_plugin::MapLevelPlugin, xp_bar_plugin::ExperienceBarPlugin,
+        hud_flasks::HudFlasksPlugin, hud_skills::HudSkillsPlugin,
+        hud_status_effects::HudStatusEffectsPlugin, life_bar_plugin::LifeBarPlugin,
+        map_level_plugin::MapLevelPlugin, minimap_plugin::MinimapPlugin,
+        xp_bar_plugin::ExperienceBarPlugin,
     };
     use crate::{components::despawn_all, schedule::GameState};
     use bevy::prelude::*;
@@ -25,7 +30,10 @@ mod plugin {
                 LifeBarPlugin,
                 ExperienceBarPlugin,
                 MapLevelPlugin,
+                MinimapPlugin,
                 HudSkillsPlugin,
+                HudFlasksPlugin,
+                HudStatusEffectsPlugin,
             ))
             .add_systems(OnExit(GameState::InGame), despawn_all::<Hud>);
         }
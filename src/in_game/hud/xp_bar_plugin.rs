@@ -35,13 +35,32 @@ impl Plugin for ExperienceBarPlugin {
 )]
 struct ExperienceBar;
 
+/// Text showing the player level, next to the [ExperienceBar]
+#[derive(Component)]
+#[require(
+    Hud,
+    Name::new("HUD - ExperienceLevel"),
+    Node {
+        position_type: PositionType::Absolute,
+        right: Val::Px(360.),
+        top: Val::Px(20.),
+        ..Default::default()
+    },
+    Text,
+    TextFont::from_font_size(16.),
+    TextColor(GOLD.into())
+)]
+struct ExperienceLevelText;
+
 fn spawn_xp_bar(mut commands: Commands) {
     commands.spawn(ExperienceBar);
+    commands.spawn(ExperienceLevelText);
 }
 
 fn update_xp_bar(
     q_player: Query<&Experience, With<Player>>,
     mut q_bar: Query<&mut ProgressBar, With<ExperienceBar>>,
+    mut q_text: Query<&mut Text, With<ExperienceLevelText>>,
 ) {
     if let Ok(mut progressbar) = q_bar.single_mut() {
         if let Ok(xp) = q_player.single() {
@@ -51,4 +70,9 @@ fn update_xp_bar(
             progressbar.value = xp.current() as f32;
         }
     }
+    if let Ok(mut text) = q_text.single_mut() {
+        if let Ok(xp) = q_player.single() {
+            text.0 = format!("Level {}", xp.level() + 1);
+        }
+    }
 }
@@ -1,19 +1,30 @@
 use crate::{
     camera::MainCamera,
     components::{
-        character::MovementAction,
+        affix::{IncreaseItemQuantity, IncreaseItemRarity},
+        character::{CharacterAttributes, MovementAction},
+        damage::DamageSource,
         despawn_all,
-        inventory::{Inventory, PlayerEquipmentChanged, RemoveFromInventoryEvent},
+        equipment::Equipment,
+        flask::RemoveFlaskEvent,
+        inventory::{
+            AddToInventoryEvent, Inventory, PlayerEquipmentChanged, RemoveFromInventoryEvent,
+        },
         item::{
             DropItemEvent, DroppedItem, Item, ItemAssets, ItemDescription, ItemLevel, ItemProvider,
-            ItemRarity, ItemTileIndex, ItemTitle, ITEM_SIZE,
+            ItemRarity, ItemTileIndex, ItemTitle, Requirements, ITEM_SIZE,
         },
-        monster::MonsterDeathEvent,
+        loot::{LootBonus, LootTables},
+        loot_filter::LootFilterHidden,
+        map_item::ActiveMap,
+        monster::{MonsterDeathEvent, MonsterDefinitions},
         player::{Player, RemoveSkillBookEvent},
-        world_map::{WorldMap, LAYER_ITEM},
+        prop::{PropDestroyedEvent, PropKind},
+        world_map::{CurrentMapLevel, WorldMap, LAYER_ITEM},
     },
+    config::load_ron_file,
     dnd::{DndCursor, DraggedEntity},
-    schedule::{game_is_running, GameRunningSet, GameState},
+    schedule::{game_is_running, AreaState, GameRunningSet, GameState},
     utils::picking::{WorldPosition, ITEM_DEPTH},
 };
 use bevy::{
@@ -39,10 +50,14 @@ impl Plugin for ItemPlugin {
             .register_type::<ItemDescription>()
             .register_type::<ItemTileIndex>()
             .init_resource::<ItemAssets>()
+            .init_resource::<LootTables>()
+            .add_systems(PreStartup, load_loot_tables)
             .add_systems(
                 OnExit(GameState::InGame),
                 (despawn_all::<DroppedItem>, despawn_all::<Item>),
             )
+            .add_systems(OnExit(AreaState::Town), despawn_dropped_items)
+            .add_systems(OnExit(AreaState::Hunting), despawn_dropped_items)
             .add_systems(
                 PreUpdate,
                 item_picking_backend
@@ -51,7 +66,12 @@ impl Plugin for ItemPlugin {
             )
             .add_systems(
                 Update,
-                drop_item_on_monster_death.in_set(GameRunningSet::EntityUpdate),
+                (
+                    drop_item_on_monster_death,
+                    drop_item_on_prop_destroyed,
+                    unequip_unqualified_equipments,
+                )
+                    .in_set(GameRunningSet::EntityUpdate),
             )
             .add_observer(update_player_on_drop_item)
             .add_observer(update_dropped_item_sprite)
@@ -63,16 +83,31 @@ impl Plugin for ItemPlugin {
     }
 }
 
+/// The items left on the ground are lost when leaving the area
+fn despawn_dropped_items(mut commands: Commands, dropped_items: Query<(Entity, &DroppedItem)>) {
+    for (entity, &DroppedItem(item)) in &dropped_items {
+        commands.entity(entity).despawn();
+        commands.entity(item).despawn();
+    }
+}
+
 const ITEM_WORLD_SCALE: f32 = 0.3;
 const ITEM_WORLD_SIZE: Vec2 = vec2(
     ITEM_SIZE.x as f32 * ITEM_WORLD_SCALE,
     ITEM_SIZE.y as f32 * ITEM_WORLD_SCALE,
 );
 
+fn load_loot_tables(mut commands: Commands) -> Result {
+    let loot_tables: LootTables = load_ron_file("loot_tables.ron")?;
+    info!("load_loot_tables: {} tables", loot_tables.len());
+    commands.insert_resource(loot_tables);
+    Ok(())
+}
+
 fn item_picking_backend(
     pointers: Query<(&PointerId, &PointerLocation)>,
     camera: Single<(Entity, &Camera, &GlobalTransform), With<MainCamera>>,
-    items: Query<(Entity, &GlobalTransform), With<DroppedItem>>,
+    items: Query<(Entity, &GlobalTransform), (With<DroppedItem>, Without<LootFilterHidden>)>,
     mut output: EventWriter<PointerHits>,
 ) {
     let (camera_entity, camera, camera_transform) = *camera;
@@ -110,26 +145,91 @@ fn update_dropped_item_sprite(
     Ok(())
 }
 
+/// The [LootBonus] of the character which owns the damage `source`, if any
+pub fn killer_loot_bonus(
+    source: DamageSource,
+    characters: &Query<(&IncreaseItemQuantity, &IncreaseItemRarity)>,
+) -> LootBonus {
+    characters
+        .get(source.owner)
+        .map(|(quantity, rarity)| LootBonus::new(quantity, rarity))
+        .unwrap_or_default()
+}
+
 fn drop_item_on_monster_death(
     mut commands: Commands,
     mut monster_death_events: EventReader<MonsterDeathEvent>,
+    loot_tables: Res<LootTables>,
+    definitions: Res<MonsterDefinitions>,
+    characters: Query<(&IncreaseItemQuantity, &IncreaseItemRarity)>,
+    active_map: Option<Res<ActiveMap>>,
 ) {
+    let map_quantity = active_map.map_or(0., |map| map.rules.item_quantity);
     let mut rng = rand::rng();
     for event in monster_death_events.read() {
+        let monster = &definitions.get_kind(event.kind).name;
+        let Some(table) = loot_tables.find(monster, event.rarity, event.mlevel) else {
+            continue;
+        };
+        let mut killer_bonus = killer_loot_bonus(event.killer, &characters);
+        killer_bonus.item_quantity += map_quantity;
+        let rarity_bonus = table.total_bonus(killer_bonus).item_rarity;
+        let loot = table.roll(killer_bonus, &mut rng);
+
         let provider = ItemProvider(event.mlevel);
-        if let Some(item) = provider.spawn(&mut commands, &mut rng) {
-            let translation = event.pos.with_z(LAYER_ITEM);
-            commands
-                .spawn((
-                    DroppedItem(item),
-                    Transform::from_translation(translation)
-                        .with_scale(Vec3::splat(ITEM_WORLD_SCALE)),
-                ))
-                .observe(take_dropped_item);
+        let n_loot = loot.len();
+        for (i, kind) in loot.into_iter().enumerate() {
+            let Some(item) = provider.spawn_loot(kind, rarity_bonus, &mut commands, &mut rng)
+            else {
+                continue;
+            };
+            // Spread the items around the monster position
+            let offset = if n_loot > 1 {
+                let angle = 2. * PI * i as f32 / n_loot as f32;
+                12. * vec2(angle.cos(), angle.sin())
+            } else {
+                Vec2::ZERO
+            };
+            let translation = (event.pos.xy() + offset).extend(LAYER_ITEM);
+            spawn_dropped_item(&mut commands, item, translation);
+        }
+    }
+}
+
+/// Percent of chance to drop an item when destroying a [PropKind]
+fn prop_drop_chance(kind: PropKind) -> u16 {
+    match kind {
+        PropKind::Crate => 50,
+        PropKind::Urn => 30,
+        PropKind::Tree | PropKind::Rock | PropKind::Ruin => 0,
+    }
+}
+
+fn drop_item_on_prop_destroyed(
+    mut commands: Commands,
+    mut prop_destroyed_events: EventReader<PropDestroyedEvent>,
+    level: Res<CurrentMapLevel>,
+) {
+    let mut rng = rand::rng();
+    for event in prop_destroyed_events.read() {
+        if rng.random_range(0..100) >= prop_drop_chance(event.kind) {
+            continue;
+        }
+        if let Some(item) = ItemProvider(**level).spawn(&mut commands, &mut rng) {
+            spawn_dropped_item(&mut commands, item, event.pos.extend(LAYER_ITEM));
         }
     }
 }
 
+fn spawn_dropped_item(commands: &mut Commands, item: Entity, translation: Vec3) {
+    commands
+        .spawn((
+            DroppedItem(item),
+            Transform::from_translation(translation).with_scale(Vec3::splat(ITEM_WORLD_SCALE)),
+        ))
+        .observe(take_dropped_item);
+}
+
 fn update_player_on_drop_item(
     trigger: Trigger<DropItemEvent>,
     mut commands: Commands,
@@ -162,6 +262,7 @@ fn update_player_on_drop_item(
 
     match change {
         Change::Player => {
+            commands.trigger(RemoveFlaskEvent(item));
             commands.entity(item).remove::<ChildOf>();
         }
         Change::Inventory => {
@@ -200,12 +301,17 @@ fn player_drop_item(
     // Remove it from player
     commands.trigger(RemoveFromInventoryEvent(item));
     commands.trigger(RemoveSkillBookEvent { book_entity: item });
+    commands.trigger(RemoveFlaskEvent(item));
     commands.entity(item).try_remove::<ChildOf>();
     commands.trigger(PlayerEquipmentChanged);
 
-    // Spawn the DroppedItem next to the player
-    let mut rng = rand::rng();
     let player_pos = players.single().expect("Player").translation;
+    drop_next_to_player(&mut commands, item, player_pos);
+}
+
+/// Spawn the [DroppedItem] of `item` next to the player
+fn drop_next_to_player(commands: &mut Commands, item: Entity, player_pos: Vec3) {
+    let mut rng = rand::rng();
     let dist = rng.random_range(5..10) as f32;
     let angle = rng.random_range(0. ..(2. * PI));
     let pos = Vec3 {
@@ -213,10 +319,34 @@ fn player_drop_item(
         y: player_pos.y + dist * angle.sin(),
         z: LAYER_ITEM,
     };
-    commands
-        .spawn((
-            DroppedItem(item),
-            Transform::from_translation(pos).with_scale(Vec3::splat(ITEM_WORLD_SCALE)),
-        ))
-        .observe(take_dropped_item);
+    spawn_dropped_item(commands, item, pos);
+}
+
+///
+/// The [Requirements] of the equipped items are checked again when the attributes
+/// of the player decrease, after a passive refund for example: the equipments which
+/// are not usable anymore go back to the inventory, or on the ground if it is full
+///
+fn unequip_unqualified_equipments(
+    mut commands: Commands,
+    player: Single<(Entity, &Transform, CharacterAttributes), With<Player>>,
+    equipments: Query<(Entity, &Requirements, &ChildOf), With<Equipment>>,
+    inventory: Single<&Inventory>,
+) {
+    let (player, transform, attributes) = player.into_inner();
+    let mut free_slots = inventory.free_slots();
+    for (item, requirements, &ChildOf(parent)) in &equipments {
+        if parent != player || requirements.are_met(&attributes) {
+            continue;
+        }
+        info!("Unequip {item}: requirements not met anymore");
+        if free_slots == 0 {
+            commands.entity(item).remove::<ChildOf>();
+            drop_next_to_player(&mut commands, item, transform.translation);
+        } else {
+            commands.trigger(AddToInventoryEvent::new(item));
+            free_slots -= 1;
+        }
+        commands.trigger(PlayerEquipmentChanged);
+    }
 }
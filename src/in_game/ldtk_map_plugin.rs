@@ -0,0 +1,316 @@
+use crate::{
+    components::{
+        ldtk::{LdtkLevel, LdtkProject, LDTK_PROJECT},
+        monster::{MonsterDeathEvent, SpawnMonstersEvent},
+        player::Player,
+        world_map::{
+            CurrentMapLevel, MapLevelConfig, WorldMap, WorldMapAssets, WorldMapLoadingFinished,
+            LAYER_ITEM, LAYER_MAP,
+        },
+        GROUP_ENEMY, GROUP_ITEM, GROUP_PLAYER, GROUP_PROP,
+    },
+    config::{GameConfig, MapMode},
+    schedule::{AreaState, GameRunningSet},
+    utils::collision::{start_event_filter, QueryEither},
+};
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_ecs_tilemap::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+/// Identifier of the optional LDtk entities where the [LevelExit]s are spawned
+const LEVEL_EXIT: &str = "LevelExit";
+
+///
+/// Handcrafted levels of the LDtk project, used in [MapMode::Handcrafted]
+/// instead of the procedural world map.
+///
+/// The [LevelExit] appears when all the monsters of the level are killed,
+/// and leads to the next level.
+///
+pub struct LdtkMapPlugin;
+
+impl Plugin for LdtkMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AreaState::Hunting), load_ldtk_project)
+            .add_systems(OnExit(AreaState::Hunting), remove_ldtk_levels)
+            .add_systems(
+                Update,
+                (
+                    spawn_pending_level,
+                    count_killed_monsters,
+                    spawn_level_exits,
+                    take_level_exit,
+                )
+                    .chain()
+                    .run_if(resource_exists::<LdtkLevels>)
+                    .in_set(GameRunningSet::EntityUpdate),
+            );
+    }
+}
+
+#[derive(Resource)]
+struct LdtkLevels {
+    project: LdtkProject,
+    /// Index of the current level in the project
+    current: usize,
+    /// The current level is not spawned yet
+    pending: bool,
+    /// Monsters of the current level which are still alive
+    remaining_monsters: usize,
+    /// Initial position of the player in the current level
+    player_start: Vec2,
+}
+
+impl LdtkLevels {
+    fn level(&self) -> &LdtkLevel {
+        &self.project.levels[self.current]
+    }
+
+    /// Go to the next level, back to the first one after the last level
+    fn next(&mut self) {
+        self.current = (self.current + 1) % self.project.levels.len();
+        self.pending = true;
+    }
+}
+
+/// Exit of a handcrafted level, to move to the next one
+#[derive(Component)]
+#[require(
+    Name::new("LevelExit"),
+    Sprite::from_color(Srgba::rgb_u8(80, 60, 160), Vec2::splat(16.)),
+    Collider::ball(8.),
+    Sensor,
+    CollisionGroups::new(GROUP_ITEM, GROUP_PLAYER)
+)]
+struct LevelExit;
+
+/// Load the levels of the LDtk project in [MapMode::Handcrafted].
+///
+/// When the project can't be used, fall back to the [MapMode::Procedural] map,
+/// spawned after this system.
+pub fn load_ldtk_project(mut commands: Commands, mut config: ResMut<GameConfig>) {
+    if config.map_mode != MapMode::Handcrafted {
+        return;
+    }
+    let project = match LdtkProject::load(LDTK_PROJECT) {
+        Ok(project) if !project.levels.is_empty() => project,
+        Ok(_) => {
+            warn!("The LDtk project {LDTK_PROJECT} has no level, use the procedural map");
+            config.map_mode = MapMode::Procedural;
+            return;
+        }
+        Err(err) => {
+            warn!("Can't load the LDtk project {LDTK_PROJECT}, use the procedural map: {err}");
+            config.map_mode = MapMode::Procedural;
+            return;
+        }
+    };
+    commands.insert_resource(LdtkLevels {
+        project,
+        current: 0,
+        pending: true,
+        remaining_monsters: 0,
+        player_start: Vec2::ZERO,
+    });
+}
+
+fn remove_ldtk_levels(mut commands: Commands) {
+    commands.remove_resource::<LdtkLevels>();
+}
+
+/// Spawn the tilemap, the colliders and the monsters of the current level,
+/// and move the player to its initial position
+fn spawn_pending_level(
+    mut commands: Commands,
+    mut levels: ResMut<LdtkLevels>,
+    map_level: Res<CurrentMapLevel>,
+    assets: Res<WorldMapAssets>,
+    mut spawn_monsters: EventWriter<SpawnMonstersEvent>,
+) {
+    if !levels.pending {
+        return;
+    }
+    levels.pending = false;
+    let level = levels.level();
+    let config = level.config().unwrap_or_else(|| MapLevelConfig {
+        name: level.identifier.clone(),
+        monster_level: 0,
+    });
+    info!("Loading level {} - {}", level.identifier, config.name);
+
+    let mlevel = **map_level + config.monster_level;
+    let map = commands.spawn((WorldMap, config)).id();
+    spawn_level_tilemap(&mut commands, level, &assets, map);
+    for rect in level.colliders() {
+        commands.spawn((
+            Name::new("LevelCollider"),
+            RigidBody::Fixed,
+            Collider::cuboid(rect.half_size().x, rect.half_size().y),
+            CollisionGroups::new(GROUP_PROP, GROUP_PLAYER | GROUP_ENEMY),
+            Transform::from_translation(rect.center().extend(LAYER_MAP)),
+            ChildOf(map),
+        ));
+    }
+
+    let monsters = level
+        .entities("MonsterInitialPosition")
+        .map(|entity| {
+            let count = entity.int_field("count").unwrap_or(1);
+            (level.entity_pos(entity), count as u16)
+        })
+        .filter(|&(_, count)| count > 0)
+        .collect::<Vec<_>>();
+    let remaining_monsters = monsters.iter().map(|&(_, count)| usize::from(count)).sum();
+    spawn_monsters.write(SpawnMonstersEvent {
+        mlevel,
+        monsters,
+        ..Default::default()
+    });
+
+    let player_start = level
+        .entities("PlayerInitialPosition")
+        .next()
+        .map(|entity| level.entity_pos(entity))
+        .unwrap_or_else(|| {
+            level.to_local(Vec2::new(level.px_wid as f32, level.px_hei as f32) / 2.)
+        });
+    commands.trigger(WorldMapLoadingFinished {
+        translation: player_start,
+    });
+
+    levels.remaining_monsters = remaining_monsters;
+    levels.player_start = player_start;
+}
+
+/// Spawn the tiles of the LDtk auto layer.
+///
+/// When several tiles are on the same cell, only the last one is kept.
+fn spawn_level_tilemap(
+    commands: &mut Commands,
+    level: &LdtkLevel,
+    assets: &WorldMapAssets,
+    map: Entity,
+) {
+    let Some(layer) = level.tiles_layer() else {
+        warn!("Level {} has no tiles layer", level.identifier);
+        return;
+    };
+    let grid = layer.grid_size;
+    let tiles = layer
+        .auto_layer_tiles
+        .iter()
+        .map(|tile| ((tile.px[0] / grid, tile.px[1] / grid), tile))
+        .collect::<HashMap<_, _>>();
+
+    let size = TilemapSize {
+        x: layer.c_wid as u32,
+        y: layer.c_hei as u32,
+    };
+    let tilemap = commands.spawn(ChildOf(map)).id();
+    let mut storage = TileStorage::empty(size);
+    for ((cx, cy), tile) in tiles {
+        let position = TilePos {
+            x: cx as u32,
+            y: (layer.c_hei - 1 - cy) as u32,
+        };
+        let tile_entity = commands
+            .spawn((
+                TileBundle {
+                    position,
+                    tilemap_id: TilemapId(tilemap),
+                    texture_index: TileTextureIndex(tile.t),
+                    flip: TileFlip {
+                        x: tile.f & 1 != 0,
+                        y: tile.f & 2 != 0,
+                        d: false,
+                    },
+                    ..Default::default()
+                },
+                ChildOf(tilemap),
+            ))
+            .id();
+        storage.set(&position, tile_entity);
+    }
+
+    let tile_size = TilemapTileSize {
+        x: grid as f32,
+        y: grid as f32,
+    };
+    commands.entity(tilemap).insert((
+        Name::new("LevelTilemap"),
+        TilemapBundle {
+            grid_size: tile_size.into(),
+            size,
+            storage,
+            texture: TilemapTexture::Single(assets.sprites.clone()),
+            tile_size,
+            transform: Transform::from_translation(Vec3::new(0., 0., LAYER_MAP)),
+            ..Default::default()
+        },
+    ));
+}
+
+fn count_killed_monsters(
+    mut levels: ResMut<LdtkLevels>,
+    mut monster_death_events: EventReader<MonsterDeathEvent>,
+) {
+    let killed = monster_death_events.read().count();
+    if killed > 0 {
+        levels.remaining_monsters = levels.remaining_monsters.saturating_sub(killed);
+    }
+}
+
+/// Spawn the [LevelExit]s when all the monsters of the level are killed.
+///
+/// They are located at the `LevelExit` entities of the level, or at the
+/// initial position of the player if the level has none.
+fn spawn_level_exits(
+    mut commands: Commands,
+    levels: Res<LdtkLevels>,
+    map: Single<Entity, With<WorldMap>>,
+    exits: Query<(), With<LevelExit>>,
+) {
+    if levels.pending || levels.remaining_monsters > 0 || !exits.is_empty() {
+        return;
+    }
+    let level = levels.level();
+    let mut positions = level
+        .entities(LEVEL_EXIT)
+        .map(|entity| level.entity_pos(entity))
+        .collect::<Vec<_>>();
+    if positions.is_empty() {
+        positions.push(levels.player_start);
+    }
+    info!("Level {} cleared", level.identifier);
+    for pos in positions {
+        commands.spawn((
+            LevelExit,
+            Transform::from_translation(pos.extend(LAYER_ITEM)),
+            ChildOf(*map),
+        ));
+    }
+}
+
+fn take_level_exit(
+    mut commands: Commands,
+    mut collisions: EventReader<CollisionEvent>,
+    mut levels: ResMut<LdtkLevels>,
+    mut map_level: ResMut<CurrentMapLevel>,
+    maps: Query<Entity, With<WorldMap>>,
+    players: Query<(), With<Player>>,
+    exits: Query<(), With<LevelExit>>,
+) {
+    let exit_taken = collisions
+        .read()
+        .filter_map(start_event_filter)
+        .filter_map(|(&e1, &e2)| players.get_either(e1, e2))
+        .any(|(_, _, other)| exits.contains(other));
+    if !exit_taken || levels.pending {
+        return;
+    }
+    for map in &maps {
+        commands.entity(map).despawn();
+    }
+    map_level.next();
+    levels.next();
+}
@@ -82,14 +82,21 @@ fn clear_life_bars(mut commands: Commands, mut life_bar_map: ResMut<LifeBarMap>)
 
 fn update_life_bar(
     cameras: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
-    monsters: Query<(Entity, &Transform, &Life, &MaxLife), With<Monster>>,
-    mut life_bars: Query<(&mut Node, &mut ProgressBar), With<LifeBar>>,
+    monsters: Query<(Entity, &Transform, &Life, &MaxLife, &Visibility), With<Monster>>,
+    mut life_bars: Query<
+        (&mut Node, &mut ProgressBar, &mut Visibility),
+        (With<LifeBar>, Without<Monster>),
+    >,
     life_bar_map: Res<LifeBarMap>,
 ) {
     let (camera, camera_transform) = cameras.single().expect("Single MainCamera");
-    for (entity, transform, life, max_life) in &monsters {
+    for (entity, transform, life, max_life, visibility) in &monsters {
         if let Some(life_bar_entity) = life_bar_map.get(&entity) {
-            if let Ok((mut node, mut progress)) = life_bars.get_mut(*life_bar_entity) {
+            if let Ok((mut node, mut progress, mut bar_visibility)) =
+                life_bars.get_mut(*life_bar_entity)
+            {
+                // The life bar is hidden with its monster, out of the light
+                bar_visibility.set_if_neq(*visibility);
                 if let Ok(pos) = camera.world_to_viewport(camera_transform, transform.translation) {
                     node.left = Val::Px(pos.x - BAR_WIDTH / 2.);
                     node.top = Val::Px(pos.y - 32.);
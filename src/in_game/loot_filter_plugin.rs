@@ -0,0 +1,201 @@
+use crate::{
+    audio::sound_effect,
+    components::{
+        item::{DroppedItem, Item, ITEM_SIZE},
+        loot_filter::{
+            LootFilter, LootFilterAction, LootFilterChanged, LootFilterDecoration,
+            LootFilterHidden, LootHighlight,
+        },
+    },
+    config::{asset_path, load_ron_file},
+    schedule::GameRunningSet,
+};
+use bevy::{ecs::world::EntityRef, prelude::*};
+use std::time::SystemTime;
+
+const LOOT_FILTER_FILE: &str = "loot_filter.ron";
+
+/// Manage the loot filter of the [DroppedItem]s
+pub struct LootFilterPlugin;
+
+impl Plugin for LootFilterPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LootFilter>()
+            .init_resource::<LootFilterWatcher>()
+            .add_systems(PreStartup, load_loot_filter)
+            .add_systems(
+                Update,
+                reload_loot_filter.in_set(GameRunningSet::EntityUpdate),
+            )
+            .add_observer(filter_dropped_item)
+            .add_observer(filter_all_dropped_items);
+    }
+}
+
+/// Check periodically if the loot filter file has been modified
+#[derive(Resource)]
+struct LootFilterWatcher {
+    timer: Timer,
+    modified: Option<SystemTime>,
+}
+
+impl Default for LootFilterWatcher {
+    fn default() -> Self {
+        LootFilterWatcher {
+            timer: Timer::from_seconds(1., TimerMode::Repeating),
+            modified: None,
+        }
+    }
+}
+
+fn loot_filter_modified_time() -> Option<SystemTime> {
+    std::fs::metadata(asset_path(LOOT_FILTER_FILE))
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// An invalid loot filter file doesn't prevent to play: the items are not filtered
+fn load_loot_filter(mut commands: Commands, mut watcher: ResMut<LootFilterWatcher>) {
+    watcher.modified = loot_filter_modified_time();
+    let loot_filter = load_ron_file::<LootFilter>(LOOT_FILTER_FILE).unwrap_or_else(|err| {
+        warn!("load_loot_filter: invalid {LOOT_FILTER_FILE}: {err}");
+        LootFilter::default()
+    });
+    info!("load_loot_filter: {} rules", loot_filter.len());
+    commands.insert_resource(loot_filter);
+}
+
+/// On an error while editing the loot filter file, the current [LootFilter] is kept
+fn reload_loot_filter(
+    mut commands: Commands,
+    mut watcher: ResMut<LootFilterWatcher>,
+    time: Res<Time>,
+) {
+    if !watcher.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    let modified = loot_filter_modified_time();
+    if modified == watcher.modified {
+        return;
+    }
+    watcher.modified = modified;
+
+    let loot_filter: LootFilter = match load_ron_file(LOOT_FILTER_FILE) {
+        Ok(loot_filter) => loot_filter,
+        Err(err) => {
+            warn!("reload_loot_filter: invalid {LOOT_FILTER_FILE}, keep the current one: {err}");
+            return;
+        }
+    };
+    info!("reload_loot_filter: {} rules", loot_filter.len());
+    commands.insert_resource(loot_filter);
+    commands.trigger(LootFilterChanged);
+}
+
+fn filter_dropped_item(
+    trigger: Trigger<OnAdd, DroppedItem>,
+    mut commands: Commands,
+    dropped_items: Query<&DroppedItem>,
+    items: Query<EntityRef, With<Item>>,
+    loot_filter: Res<LootFilter>,
+    asset_server: Res<AssetServer>,
+) -> Result {
+    let dropped_item = trigger.target();
+    let &DroppedItem(item) = dropped_items.get(dropped_item)?;
+    let item = items.get(item)?;
+    if let Some(LootFilterAction::Highlight(LootHighlight {
+        sound: Some(sound), ..
+    })) = loot_filter.action(&item)
+    {
+        commands.spawn(sound_effect(asset_server.load(sound)));
+    }
+    apply_loot_filter(&mut commands, dropped_item, &item, &loot_filter);
+    Ok(())
+}
+
+fn filter_all_dropped_items(
+    _trigger: Trigger<LootFilterChanged>,
+    mut commands: Commands,
+    dropped_items: Query<(Entity, &DroppedItem, Option<&Children>)>,
+    decorations: Query<(), With<LootFilterDecoration>>,
+    items: Query<EntityRef, With<Item>>,
+    loot_filter: Res<LootFilter>,
+) {
+    for (dropped_item, &DroppedItem(item), children) in &dropped_items {
+        // Remove the previous filter
+        if let Some(children) = children {
+            for decoration in children.iter().filter(|&child| decorations.contains(child)) {
+                commands.entity(decoration).despawn();
+            }
+        }
+        commands
+            .entity(dropped_item)
+            .remove::<LootFilterHidden>()
+            .insert(Visibility::Inherited);
+
+        if let Ok(item) = items.get(item) {
+            apply_loot_filter(&mut commands, dropped_item, &item, &loot_filter);
+        }
+    }
+}
+
+fn apply_loot_filter(
+    commands: &mut Commands,
+    dropped_item: Entity,
+    item: &EntityRef,
+    loot_filter: &LootFilter,
+) {
+    match loot_filter.action(item) {
+        Some(LootFilterAction::Hide) => {
+            commands
+                .entity(dropped_item)
+                .insert((LootFilterHidden, Visibility::Hidden));
+        }
+        Some(LootFilterAction::Highlight(highlight)) => {
+            highlight_dropped_item(commands, dropped_item, highlight);
+        }
+        None => {}
+    }
+}
+
+/// Add the highlight decorations as children of the [DroppedItem].
+///
+/// As the [DroppedItem] is scaled, the sizes are in the item texture coordinates.
+fn highlight_dropped_item(
+    commands: &mut Commands,
+    dropped_item: Entity,
+    highlight: &LootHighlight,
+) {
+    let color = highlight.color();
+    let item_size = ITEM_SIZE.as_vec2();
+    if highlight.border.is_some() {
+        commands.spawn((
+            LootFilterDecoration,
+            Name::new("LootFilterBorder"),
+            Sprite::from_color(color, item_size + 12.),
+            Transform::from_xyz(0., 0., -0.2),
+            ChildOf(dropped_item),
+        ));
+    }
+    if let Some(label) = &highlight.label {
+        commands.spawn((
+            LootFilterDecoration,
+            Name::new("LootFilterLabel"),
+            Text2d::new(label),
+            TextFont::from_font_size(30.),
+            TextColor(color),
+            Transform::from_xyz(0., item_size.y, 0.1),
+            ChildOf(dropped_item),
+        ));
+    }
+    if highlight.beam {
+        let beam_height = 20. * item_size.y;
+        commands.spawn((
+            LootFilterDecoration,
+            Name::new("LootFilterBeam"),
+            Sprite::from_color(color.with_alpha(0.4), Vec2::new(8., beam_height)),
+            Transform::from_xyz(0., beam_height / 2., -0.1),
+            ChildOf(dropped_item),
+        ));
+    }
+}
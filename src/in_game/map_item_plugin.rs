@@ -0,0 +1,118 @@
+use crate::{
+    components::{
+        inventory::RemoveFromInventoryEvent,
+        item::ItemLevel,
+        map_item::{ActivateMapEvent, ActiveMap, MapCompletedEvent, MapItem},
+        monster::{MonsterDeathEvent, MonsterRarity, SpawnMonstersEvent},
+        world_map::CurrentMapLevel,
+    },
+    schedule::{AreaState, GameRunningSet},
+};
+use bevy::prelude::*;
+use rand::Rng;
+use std::f32::consts::PI;
+
+/// Distance from the start of the map where the extra rare packs spawn
+const RARE_PACKS_DISTANCE: f32 = 400.;
+
+///
+/// Maps start a new hunting area from the town, with the rules of their modifiers.
+///
+/// Killing the number of monsters of the map completes it, and brings the player
+/// back to the town.
+///
+pub struct MapItemPlugin;
+
+impl Plugin for MapItemPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<MapItem>()
+            .add_systems(
+                OnEnter(AreaState::Hunting),
+                spawn_extra_rare_packs.run_if(resource_exists::<ActiveMap>),
+            )
+            .add_systems(OnExit(AreaState::Hunting), remove_active_map)
+            .add_systems(
+                Update,
+                count_map_kills
+                    .run_if(resource_exists::<ActiveMap>)
+                    .in_set(GameRunningSet::EntityUpdate),
+            )
+            .add_observer(activate_map)
+            .add_observer(complete_map);
+    }
+}
+
+fn remove_active_map(mut commands: Commands) {
+    commands.remove_resource::<ActiveMap>();
+}
+
+fn activate_map(
+    trigger: Trigger<ActivateMapEvent>,
+    mut commands: Commands,
+    maps: Query<(&MapItem, &ItemLevel)>,
+    area_state: Res<State<AreaState>>,
+    mut next_area_state: ResMut<NextState<AreaState>>,
+    mut map_level: ResMut<CurrentMapLevel>,
+) {
+    let item = trigger.0;
+    let Ok((map, &ItemLevel(ilevel))) = maps.get(item) else {
+        warn!("Can't activate {item} as it is not a map");
+        return;
+    };
+    if *area_state != AreaState::Town {
+        warn!("Maps can only be activated in the town");
+        return;
+    }
+    info!("activate_map: tier {}, level {ilevel}", map.tier);
+
+    commands.insert_resource(ActiveMap::new(map, ilevel));
+    commands.trigger(RemoveFromInventoryEvent(item));
+    commands.entity(item).despawn();
+    map_level.set(ilevel);
+    next_area_state.set(AreaState::Hunting);
+}
+
+/// The extra rare packs are around the start of the map
+fn spawn_extra_rare_packs(
+    active_map: Res<ActiveMap>,
+    mut spawn_monsters: EventWriter<SpawnMonstersEvent>,
+) {
+    let mut rng = rand::rng();
+    let n_packs = active_map.rules.extra_rare_packs;
+    let monsters = (0..n_packs)
+        .map(|i| {
+            let angle = 2. * PI * f32::from(i) / f32::from(n_packs);
+            let pos = RARE_PACKS_DISTANCE * Vec2::new(angle.cos(), angle.sin());
+            (pos, rng.random_range(3..=5))
+        })
+        .collect::<Vec<_>>();
+    if !monsters.is_empty() {
+        spawn_monsters.write(SpawnMonstersEvent {
+            mlevel: active_map.level,
+            monsters,
+            leader_rarity: Some(MonsterRarity::Rare),
+        });
+    }
+}
+
+fn count_map_kills(
+    mut commands: Commands,
+    mut active_map: ResMut<ActiveMap>,
+    mut monster_death_events: EventReader<MonsterDeathEvent>,
+) {
+    let kills = monster_death_events.read().count() as u16;
+    if kills > 0 && active_map.add_kills(kills) {
+        info!("Map of tier {} completed", active_map.tier);
+        commands.trigger(MapCompletedEvent);
+    }
+}
+
+/// Go back to the town when the map is completed
+fn complete_map(
+    _trigger: Trigger<MapCompletedEvent>,
+    mut commands: Commands,
+    mut area_state: ResMut<NextState<AreaState>>,
+) {
+    commands.remove_resource::<ActiveMap>();
+    area_state.set(AreaState::Town);
+}
@@ -2,14 +2,24 @@ pub mod affix_updates_plugin;
 pub mod animation_plugin;
 pub mod character_plugin;
 pub mod collisions_plugin;
+pub mod damage_numbers_plugin;
+pub mod flask_plugin;
+pub mod fog_plugin;
 pub mod hud;
 pub mod item_plugin;
+pub mod ldtk_map_plugin;
 pub mod life_bar_plugin;
+pub mod loot_filter_plugin;
+pub mod map_item_plugin;
 pub mod monster_plugin;
 pub mod music_plugin;
 pub mod orb_plugin;
+pub mod passive_tree_plugin;
 pub mod player_plugin;
 pub mod skills;
+pub mod status_effect_plugin;
+pub mod town_plugin;
+pub mod vendor_plugin;
 pub mod world_map_plugin;
 
 pub use plugin::{back_to_game, InGamePluginsGroup};
@@ -31,13 +41,23 @@ mod plugin {
             PluginGroupBuilder::start::<Self>()
                 .add(hud::HudPlugin)
                 .add(item_plugin::ItemPlugin)
+                .add(loot_filter_plugin::LootFilterPlugin)
                 .add(collisions_plugin::CollisionsPlugin)
                 .add(character_plugin::CharacterPlugin)
+                .add(damage_numbers_plugin::DamageNumbersPlugin)
                 .add(affix_updates_plugin::AffixUpdatesPlugin)
                 .add(monster_plugin::MonsterPlugin)
                 .add(orb_plugin::OrbPlugin)
+                .add(flask_plugin::FlaskPlugin)
+                .add(map_item_plugin::MapItemPlugin)
+                .add(status_effect_plugin::StatusEffectPlugin)
+                .add(passive_tree_plugin::PassiveTreePlugin)
                 .add(player_plugin::PlayerPlugin)
+                .add(town_plugin::TownPlugin)
+                .add(vendor_plugin::VendorPlugin)
                 .add(world_map_plugin::WorldMapPlugin)
+                .add(ldtk_map_plugin::LdtkMapPlugin)
+                .add(fog_plugin::FogPlugin)
                 .add(life_bar_plugin::LifeBarPlugin)
                 .add(animation_plugin::AnimationPlugin)
                 .add(skills::SkillsPlugin)
@@ -1,61 +1,60 @@
 use crate::{
     components::{
-        affix::MoreLife,
+        affix::{IncreaseMaxLife, MoreLife},
         animation::AnimationTimer,
         character::{CharacterDiedEvent, CharacterDyingEvent, MovementAction},
         despawn_all,
         equipment::{weapon::AttackTimer, Wand},
         item::ItemSpawner,
+        map_item::ActiveMap,
         monster::{
-            AllMonsterAssets, Monster, MonsterBuilder, MonsterDeathEvent, MonsterLevel,
-            MonsterRarity, MonsterType1, MonsterType2, MonsterType3, SpawnMonstersEvent, ViewRange,
-            XpOnDeath,
+            AllMonsterAssets, Monster, MonsterBuilder, MonsterDeathEvent, MonsterDefinitions,
+            MonsterKind, MonsterLevel, MonsterRarity, PackAura, PackFollower, PackLeader,
+            Scattered, SpawnMonstersEvent, ViewRange, XpOnDeath,
         },
         player::{Player, Score},
-        skills::{
-            death_aura::DeathAura, fireball::FireBallLauncher, shuriken::ShurikenLauncher,
-            ActivateSkill, Skill,
-        },
+        skills::{ActivateSkill, Skill},
         upgrade::UpgradeProvider,
-        world_map::CurrentMapLevel,
+        world_map::{CurrentMapLevel, ProceduralWorldMap},
     },
-    config::{ConfigLoaded, GameConfig},
-    schedule::{GameRunningSet, GameState},
+    config::{load_ron_file, ConfigLoaded, GameConfig},
+    schedule::{AreaState, GameRunningSet},
 };
 use bevy::{math::vec2, prelude::*};
 use bevy_rapier2d::prelude::*;
-use rand::Rng;
+use rand::{rngs::ThreadRng, Rng};
 use std::{f32::consts::PI, time::Duration};
 
 pub struct MonsterPlugin;
 
 impl Plugin for MonsterPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<AllMonsterAssets>()
+        app.init_resource::<MonsterDefinitions>()
+            .init_resource::<AllMonsterAssets>()
             .init_resource::<SpawnMonsterTimer>()
             .register_type::<MonsterLevel>()
+            .register_type::<MonsterKind>()
             .register_type::<ViewRange>()
             .register_type::<MonsterBuilder>()
             .add_event::<MonsterDeathEvent>()
             .add_event::<SpawnMonstersEvent>()
-            .add_systems(OnEnter(GameState::InGame), reset_monster_timer)
-            .add_systems(OnExit(GameState::InGame), despawn_all::<Monster>)
+            .add_systems(PreStartup, load_monster_definitions)
+            .add_systems(OnEnter(AreaState::Hunting), reset_monster_timer)
+            .add_systems(OnExit(AreaState::Hunting), despawn_all::<Monster>)
             .add_systems(
                 Update,
                 (
                     spawn_monsters,
                     monsters_moves,
+                    stop_scattering,
                     animate_sprite,
                     activate_skill,
-                    spawn_monster_timer,
+                    spawn_monster_timer.run_if(resource_exists::<ProceduralWorldMap>),
                 )
                     .in_set(GameRunningSet::EntityUpdate),
             )
             .add_observer(init_config)
-            .add_observer(update_monster)
-            .add_observer(customize_monster_type_1)
-            .add_observer(customize_monster_type_2)
-            .add_observer(customize_monster_type_3);
+            .add_observer(update_monster);
     }
 }
 
@@ -81,6 +80,22 @@ fn init_config(_trigger: Trigger<ConfigLoaded>, mut commands: Commands, config:
     commands.insert_resource(SpawnMonsterTimer::from(&*config));
 }
 
+fn load_monster_definitions(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+) -> Result {
+    let definitions: MonsterDefinitions = load_ron_file("monsters.ron")?;
+    info!("load_monster_definitions: {} kinds", definitions.len());
+    commands.insert_resource(AllMonsterAssets::load(
+        &definitions,
+        &asset_server,
+        &mut layouts,
+    ));
+    commands.insert_resource(definitions);
+    Ok(())
+}
+
 fn reset_monster_timer(mut timer: ResMut<SpawnMonsterTimer>) {
     timer.reset();
 }
@@ -115,6 +130,7 @@ fn spawn_monster_timer(
         spawn_monsters.write(SpawnMonstersEvent {
             mlevel: **mlevel,
             monsters: vec![(pos, count)],
+            ..Default::default()
         });
     }
     Ok(())
@@ -123,41 +139,122 @@ fn spawn_monster_timer(
 fn spawn_monsters(
     mut commands: Commands,
     mut monsters_to_spawn_reader: EventReader<SpawnMonstersEvent>,
+    definitions: Res<MonsterDefinitions>,
     assets: Res<AllMonsterAssets>,
+    world_map: Option<Res<ProceduralWorldMap>>,
 ) {
     let mut rng = rand::rng();
     for monsters_to_spawn in monsters_to_spawn_reader.read() {
-        for (pos, count) in monsters_to_spawn.monsters.iter() {
-            for i in 0..*count {
-                let angle = 2. * PI * f32::from(i) / f32::from(*count);
+        let mlevel = monsters_to_spawn.mlevel;
+        for &(pos, count) in monsters_to_spawn.monsters.iter() {
+            let biome = world_map
+                .as_ref()
+                .map(|world_map| world_map.biome(pos))
+                .unwrap_or_default();
+            let mut leader_builder =
+                MonsterBuilder::generate(mlevel, biome, &definitions, &mut rng);
+            if let Some(rarity) = monsters_to_spawn.leader_rarity {
+                leader_builder.rarity = rarity;
+            }
+            if count <= 1 {
+                spawn_monster(
+                    &mut commands,
+                    &leader_builder,
+                    pos,
+                    &definitions,
+                    &assets,
+                    &mut rng,
+                );
+                continue;
+            }
+
+            // The pack leader is at least magic
+            if let MonsterRarity::Normal = leader_builder.rarity {
+                leader_builder.rarity = MonsterRarity::Magic;
+            }
+            let aura = leader_builder.rarity.pack_aura();
+            let leader = spawn_monster(
+                &mut commands,
+                &leader_builder,
+                pos,
+                &definitions,
+                &assets,
+                &mut rng,
+            );
+            commands
+                .entity(leader)
+                .insert(PackLeader)
+                .observe(scatter_pack)
+                .with_child((aura, PackAura));
+
+            // Followers are around the leader, and of the same kind
+            let follower_builder = MonsterBuilder {
+                rarity: MonsterRarity::Normal,
+                kind: leader_builder.kind,
+                level: mlevel,
+            };
+            let n_followers = count - 1;
+            for i in 0..n_followers {
+                let angle = 2. * PI * f32::from(i) / f32::from(n_followers);
                 let dist = 20.;
                 let pos = pos + dist * vec2(angle.cos(), angle.sin());
-
-                let monster_builder = MonsterBuilder::generate(monsters_to_spawn.mlevel, &mut rng);
-                match monster_builder.kind {
-                    0 => {
-                        commands.spawn(MonsterType1::bundle(monster_builder, pos, &assets));
-                    }
-                    1 => {
-                        commands.spawn(MonsterType2::bundle(monster_builder, pos, &assets));
-                    }
-                    2 => {
-                        commands.spawn(MonsterType3::bundle(monster_builder, pos, &assets));
-                    }
-                    _ => unreachable!(),
-                }
+                let follower = spawn_monster(
+                    &mut commands,
+                    &follower_builder,
+                    pos,
+                    &definitions,
+                    &assets,
+                    &mut rng,
+                );
+                commands
+                    .entity(follower)
+                    .insert(PackFollower(leader))
+                    .with_child((aura, PackAura));
             }
         }
     }
 }
 
+///
+/// Spawn a monster with the skills of its kind
+///
+fn spawn_monster(
+    commands: &mut Commands,
+    builder: &MonsterBuilder,
+    pos: Vec2,
+    definitions: &MonsterDefinitions,
+    assets: &AllMonsterAssets,
+    rng: &mut ThreadRng,
+) -> Entity {
+    let monster_entity = commands
+        .spawn(builder.bundle(pos, definitions, assets))
+        .id();
+
+    let skills = builder.skills(definitions);
+    if !skills.is_empty() {
+        // A wand is needed to scale the skills damage
+        let spawner = ItemSpawner::new(builder.level, rng);
+        let weapon = spawner.spawn::<Wand>(commands, rng);
+        commands.entity(weapon).insert(ChildOf(monster_entity));
+        for skill in skills {
+            let skill_entity = skill.spawn_skill(commands);
+            commands
+                .entity(skill_entity)
+                .insert(ChildOf(monster_entity));
+        }
+    }
+
+    monster_entity
+}
+
 ///
 /// Update monster to add affixes and observers
 ///
-fn update_monster(
+pub fn update_monster(
     trigger: Trigger<OnAdd, Monster>,
     mut commands: Commands,
     monsters: Query<(&MonsterRarity, &MonsterLevel)>,
+    active_map: Option<Res<ActiveMap>>,
 ) {
     let monster_entity = trigger.target();
     commands
@@ -165,18 +262,28 @@ fn update_monster(
         .observe(monster_dying)
         .observe(increment_score);
 
-    // Customize Rare monsters
-    let Ok((MonsterRarity::Rare, &MonsterLevel(mlevel))) = monsters.get(monster_entity) else {
-        return;
+    // The map can increase the life of all monsters
+    if let Some(map) = active_map.filter(|map| map.rules.monster_life > 0.) {
+        commands.spawn((
+            IncreaseMaxLife(map.rules.monster_life),
+            ChildOf(monster_entity),
+        ));
+    }
+
+    // Customize Magic and Rare monsters
+    let (more_life, n_upgrades) = match monsters.get(monster_entity) {
+        Ok((MonsterRarity::Magic, &MonsterLevel(mlevel))) => (5., mlevel.saturating_sub(1) / 2),
+        Ok((MonsterRarity::Rare, &MonsterLevel(mlevel))) => (10., mlevel.saturating_sub(1)),
+        _ => return,
     };
 
-    // All rare monster have more life
-    commands.spawn((MoreLife(10.), ChildOf(monster_entity)));
+    // Magic and rare monster have more life
+    commands.spawn((MoreLife(more_life), ChildOf(monster_entity)));
 
     // Add upgrades depending on map level
     let mut upgrade_provider = UpgradeProvider::new();
     let mut rng = rand::rng();
-    for _ in 0..mlevel.saturating_sub(1) {
+    for _ in 0..n_upgrades {
         if let Some(upgrade) = upgrade_provider.gen(&mut rng) {
             let upgrade_view = upgrade.generate(&mut commands, &mut rng);
             commands
@@ -186,95 +293,136 @@ fn update_monster(
     }
 }
 
-fn customize_monster_type_1(
-    trigger: Trigger<OnAdd, MonsterType1>,
-    mut commands: Commands,
-    monsters: Query<(&MonsterRarity, &MonsterLevel)>,
-) {
-    let monster_entity = trigger.target();
-    let Ok((MonsterRarity::Rare, &MonsterLevel(mlevel))) = monsters.get(monster_entity) else {
-        return;
-    };
+/// Distance from the leader a follower tries to stay within
+const PACK_RADIUS: f32 = 40.;
 
-    // Add a wand and the fireball skill
-    let mut rng = rand::rng();
-    let spawner = ItemSpawner::new(mlevel, &mut rng);
-    let weapon = spawner.spawn::<Wand>(&mut commands, &mut rng);
-    commands.entity(weapon).insert(ChildOf(monster_entity));
-    commands.spawn((FireBallLauncher, ChildOf(monster_entity)));
+///
+/// Monsters moves in direction of the Player, or stay near their pack leader
+///
+fn monsters_moves(
+    mut monsters: Query<
+        (
+            &mut MovementAction,
+            &Transform,
+            &ViewRange,
+            Option<&PackFollower>,
+        ),
+        (With<Monster>, Without<Scattered>),
+    >,
+    leaders: Query<&Transform, With<PackLeader>>,
+    players: Query<&Transform, With<Player>>,
+) {
+    if let Ok(player_pos) = players.single().map(|t| t.translation.xy()) {
+        for (mut action, monster_transform, view_range, follower) in &mut monsters {
+            let monster_pos = monster_transform.translation.xy();
+            let distance = (monster_pos - player_pos).length();
+            // warn!("distance={distance}");
+            if distance < **view_range {
+                action.goto(player_pos);
+                continue;
+            }
+            let leader_pos = follower
+                .and_then(|follower| leaders.get(**follower).ok())
+                .map(|t| t.translation.xy());
+            match leader_pos {
+                Some(leader_pos) if (leader_pos - monster_pos).length() > PACK_RADIUS => {
+                    action.goto(leader_pos);
+                }
+                _ => action.stop(),
+            }
+        }
+    }
 }
 
-fn customize_monster_type_2(
-    trigger: Trigger<OnAdd, MonsterType2>,
+///
+/// When the pack leader dies, its followers loose the aura and flee
+///
+pub fn scatter_pack(
+    trigger: Trigger<CharacterDiedEvent>,
     mut commands: Commands,
-    monsters: Query<(&MonsterRarity, &MonsterLevel)>,
+    leaders: Query<&Transform, With<PackLeader>>,
+    mut followers: Query<(
+        Entity,
+        &PackFollower,
+        &Transform,
+        &mut MovementAction,
+        &Children,
+    )>,
+    auras: Query<(), With<PackAura>>,
 ) {
-    let monster_entity = trigger.target();
-    let Ok((MonsterRarity::Rare, &MonsterLevel(mlevel))) = monsters.get(monster_entity) else {
+    let leader = trigger.target();
+    let Ok(leader_pos) = leaders.get(leader).map(|t| t.translation.xy()) else {
         return;
     };
-
-    // Add a wand and the death aura skill
     let mut rng = rand::rng();
-    let spawner = ItemSpawner::new(mlevel, &mut rng);
-    let weapon = spawner.spawn::<Wand>(&mut commands, &mut rng);
-    commands.entity(weapon).insert(ChildOf(monster_entity));
-    commands.spawn((DeathAura, ChildOf(monster_entity)));
-}
+    for (follower, &PackFollower(follower_leader), transform, mut action, children) in
+        &mut followers
+    {
+        if follower_leader != leader {
+            continue;
+        }
+        for aura in children.iter().filter(|&child| auras.contains(child)) {
+            commands.entity(aura).despawn();
+        }
 
-fn customize_monster_type_3(
-    trigger: Trigger<OnAdd, MonsterType3>,
-    mut commands: Commands,
-    monsters: Query<(&MonsterRarity, &MonsterLevel)>,
-) {
-    let monster_entity = trigger.target();
-    let Ok((MonsterRarity::Rare, &MonsterLevel(mlevel))) = monsters.get(monster_entity) else {
-        return;
-    };
+        // Run away from the leader, in a random direction
+        let pos = transform.translation.xy();
+        let away = (pos - leader_pos).try_normalize().unwrap_or(Vec2::X);
+        let angle = rng.random_range(-0.8..0.8);
+        let dist = rng.random_range(80. ..150.);
+        action.goto(pos + dist * Vec2::from_angle(angle).rotate(away));
 
-    // Add a wand and the fireball skill
-    let mut rng = rand::rng();
-    let spawner = ItemSpawner::new(mlevel, &mut rng);
-    let weapon = spawner.spawn::<Wand>(&mut commands, &mut rng);
-    commands.entity(weapon).insert(ChildOf(monster_entity));
-    commands.spawn((ShurikenLauncher, ChildOf(monster_entity)));
+        commands
+            .entity(follower)
+            .remove::<PackFollower>()
+            .insert(Scattered(Timer::from_seconds(2., TimerMode::Once)));
+    }
 }
 
-///
-/// Monsters moves in direction of the Player
-///
-fn monsters_moves(
-    mut monsters: Query<(&mut MovementAction, &Transform, &ViewRange), With<Monster>>,
-    players: Query<&Transform, With<Player>>,
+fn stop_scattering(
+    mut commands: Commands,
+    mut monsters: Query<(Entity, &mut Scattered)>,
+    time: Res<Time>,
 ) {
-    if let Ok(player_pos) = players.single().map(|t| t.translation.xy()) {
-        for (mut action, monster_transform, view_range) in &mut monsters {
-            let distance = (monster_transform.translation.xy() - player_pos).length();
-            // warn!("distance={distance}");
-            if distance < **view_range {
-                action.goto(player_pos);
-            } else {
-                action.stop();
-            }
+    for (entity, mut scattered) in &mut monsters {
+        if scattered.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Scattered>();
         }
     }
 }
 
-fn monster_dying(
+pub fn monster_dying(
     trigger: Trigger<CharacterDyingEvent>,
-    monsters: Query<(&Transform, &MonsterLevel, &XpOnDeath), With<Monster>>,
+    mut commands: Commands,
+    monsters: Query<
+        (
+            &Transform,
+            &MonsterLevel,
+            &XpOnDeath,
+            &MonsterKind,
+            &MonsterRarity,
+        ),
+        With<Monster>,
+    >,
     mut monster_death_events: EventWriter<MonsterDeathEvent>,
     mut character_died_events: EventWriter<CharacterDiedEvent>,
 ) {
     info!("monster_dying");
-    if let Ok((transform, mlevel, xp)) = monsters.get(trigger.target()) {
+    if let Ok((transform, mlevel, xp, kind, rarity)) = monsters.get(trigger.target()) {
         monster_death_events.write(MonsterDeathEvent {
             pos: transform.translation,
             xp: **xp,
             mlevel: **mlevel,
+            kind: **kind,
+            rarity: *rarity,
+            killer: trigger.killer,
         });
 
-        character_died_events.write(CharacterDiedEvent(trigger.target()));
+        // The observers of the monster (score, pack) are notified
+        // before it is despawned by the buffered event
+        let monster = trigger.target();
+        commands.trigger_targets(CharacterDiedEvent(monster), monster);
+        character_died_events.write(CharacterDiedEvent(monster));
     }
 }
 
@@ -290,22 +438,21 @@ fn increment_score(_trigger: Trigger<CharacterDiedEvent>, mut score: ResMut<Scor
 ///
 fn animate_sprite(
     time: Res<Time>,
-    mut q_monster: Query<(&Velocity, &mut AnimationTimer, &mut Sprite), With<Monster>>,
+    mut q_monster: Query<
+        (&Velocity, &MonsterKind, &mut AnimationTimer, &mut Sprite),
+        With<Monster>,
+    >,
+    definitions: Res<MonsterDefinitions>,
 ) {
-    for (&velocity, mut timer, mut sprite) in q_monster.iter_mut() {
+    for (&velocity, kind, mut timer, mut sprite) in q_monster.iter_mut() {
         timer.tick(time.delta());
         if timer.just_finished() {
+            let sprite_sheet = &definitions.get_kind(**kind).sprite;
             if let Some(atlas) = &mut sprite.texture_atlas {
                 atlas.index = if velocity == Velocity::zero() {
-                    0
+                    sprite_sheet.idle_frame()
                 } else {
-                    match atlas.index {
-                        0 => 4,
-                        4 => 8,
-                        8 => 12,
-                        12 => 0,
-                        _ => 0,
-                    }
+                    sprite_sheet.next_walk_frame(atlas.index)
                 };
             }
         }
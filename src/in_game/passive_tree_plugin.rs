@@ -0,0 +1,173 @@
+use crate::{
+    components::{
+        inventory::{Inventory, RemoveFromInventoryEvent},
+        orb::Orb,
+        passive_tree::{
+            AllocatePassiveEvent, PassiveNode, PassiveSkills, PassiveSkillsChanged, PassiveTree,
+            ResetPassivesEvent, RespecPassiveEvent, RESET_COST, RESPEC_COST,
+        },
+        player::{LevelUpEvent, Player},
+    },
+    config::load_ron_file,
+};
+use bevy::prelude::*;
+
+/// Manage the passive skill tree of the [Player]
+pub struct PassiveTreePlugin;
+
+impl Plugin for PassiveTreePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<PassiveSkills>()
+            .register_type::<PassiveNode>()
+            .init_resource::<PassiveTree>()
+            .add_systems(PreStartup, load_passive_tree)
+            .add_observer(allocate_start_passive)
+            .add_observer(add_passive_point_on_level_up)
+            .add_observer(allocate_passive)
+            .add_observer(respec_passive)
+            .add_observer(reset_passives);
+    }
+}
+
+fn load_passive_tree(mut commands: Commands) -> Result {
+    let tree: PassiveTree = load_ron_file("passive_tree.ron")?;
+    info!("load_passive_tree: {} nodes", tree.nodes.len());
+    commands.insert_resource(tree);
+    Ok(())
+}
+
+/// Grant the affixes of the start node, which is always allocated
+fn allocate_start_passive(
+    trigger: Trigger<OnAdd, PassiveSkills>,
+    mut commands: Commands,
+    players: Query<&PassiveSkills>,
+    tree: Res<PassiveTree>,
+) -> Result {
+    let player = trigger.target();
+    let start = players.get(player)?.start;
+    let node = tree.node(start).ok_or("Passive start node out of range")?;
+    for affix in &node.affixes {
+        affix.spawn(&mut commands, player, start);
+    }
+    Ok(())
+}
+
+fn add_passive_point_on_level_up(
+    _trigger: Trigger<LevelUpEvent>,
+    mut commands: Commands,
+    mut players: Query<&mut PassiveSkills, With<Player>>,
+) -> Result {
+    let mut passives = players.single_mut()?;
+    passives.points += 1;
+    commands.trigger(PassiveSkillsChanged);
+    Ok(())
+}
+
+fn allocate_passive(
+    trigger: Trigger<AllocatePassiveEvent>,
+    mut commands: Commands,
+    mut players: Query<(Entity, &mut PassiveSkills), With<Player>>,
+    tree: Res<PassiveTree>,
+) -> Result {
+    let id = trigger.0;
+    let (player, mut passives) = players.single_mut()?;
+    if passives.points == 0 {
+        info!("Can't allocate passive {id}: no point available");
+        return Ok(());
+    }
+    if !tree.can_allocate(&passives.allocated_with_start(), id) {
+        info!("Can't allocate passive {id}: not linked to an allocated node");
+        return Ok(());
+    }
+    let node = tree.node(id).ok_or("Passive node out of range")?;
+    info!("Allocate passive {id} ({})", node.name);
+    for affix in &node.affixes {
+        affix.spawn(&mut commands, player, id);
+    }
+    passives.points -= 1;
+    passives.allocated.insert(id);
+    commands.trigger(PassiveSkillsChanged);
+    Ok(())
+}
+
+fn respec_passive(
+    trigger: Trigger<RespecPassiveEvent>,
+    mut commands: Commands,
+    mut players: Query<&mut PassiveSkills, With<Player>>,
+    passive_nodes: Query<(Entity, &PassiveNode)>,
+    inventory: Single<&Inventory>,
+    orbs: Query<&Orb>,
+    tree: Res<PassiveTree>,
+) -> Result {
+    let id = trigger.0;
+    let mut passives = players.single_mut()?;
+    if !tree.can_refund(&passives.allocated_with_start(), passives.start, id) {
+        info!("Can't refund passive {id}: other nodes depend on it");
+        return Ok(());
+    }
+    if !pay(&mut commands, &inventory, &orbs, RESPEC_COST) {
+        info!("Can't refund passive {id}: not enough currency");
+        return Ok(());
+    }
+    info!("Refund passive {id}");
+    for (entity, _) in passive_nodes.iter().filter(|(_, node)| node.0 == id) {
+        commands.entity(entity).despawn();
+    }
+    passives.allocated.remove(&id);
+    passives.points += 1;
+    commands.trigger(PassiveSkillsChanged);
+    Ok(())
+}
+
+fn reset_passives(
+    _trigger: Trigger<ResetPassivesEvent>,
+    mut commands: Commands,
+    mut players: Query<&mut PassiveSkills, With<Player>>,
+    passive_nodes: Query<(Entity, &PassiveNode)>,
+    inventory: Single<&Inventory>,
+    orbs: Query<&Orb>,
+) -> Result {
+    let mut passives = players.single_mut()?;
+    if passives.allocated.is_empty() {
+        return Ok(());
+    }
+    if !pay(&mut commands, &inventory, &orbs, RESET_COST) {
+        info!("Can't reset passives: not enough currency");
+        return Ok(());
+    }
+    info!("Reset {} passives", passives.allocated.len());
+    for (entity, _) in passive_nodes
+        .iter()
+        .filter(|(_, node)| node.0 != passives.start)
+    {
+        commands.entity(entity).despawn();
+    }
+    passives.points += passives.allocated.len() as u16;
+    passives.allocated.clear();
+    commands.trigger(PassiveSkillsChanged);
+    Ok(())
+}
+
+/// Consume `count` [Orb]s of the given kind from the [Inventory].
+///
+/// Returns `false`, without consuming anything, if there is not enough orbs.
+fn pay(
+    commands: &mut Commands,
+    inventory: &Inventory,
+    orbs: &Query<&Orb>,
+    (orb, count): (Orb, u16),
+) -> bool {
+    let payment = (0..Inventory::len())
+        .filter_map(|index| inventory.at(index))
+        .filter(|&item| orbs.get(item).is_ok_and(|o| *o == orb))
+        .take(count as usize)
+        .collect::<Vec<_>>();
+    if payment.len() < count as usize {
+        return false;
+    }
+    for item in payment {
+        commands.trigger(RemoveFromInventoryEvent(item));
+        commands.entity(item).despawn();
+    }
+    true
+}
@@ -3,29 +3,32 @@ use crate::{
     components::{
         animation::AnimationTimer,
         character::{
-            CharacterDiedEvent, CharacterDyingEvent, CharacterLevel, Life, LooseLifeEvent, MaxLife,
-            MovementAction,
+            CharacterAttributes, CharacterDiedEvent, CharacterDyingEvent, CharacterLevel, Life,
+            LooseLifeEvent, MaxLife, MovementAction,
         },
+        character_class::{CharacterClasses, PlayerClass},
+        damage::DamageKind,
         despawn_all,
         equipment::{weapon::AttackTimer, Equipment},
+        flask::PlayerFlasks,
         inventory::{
             AddToInventoryEvent, Inventory, InventoryChanged, InventoryPos, PlayerEquipmentChanged,
             RemoveFromInventoryEvent, TakeDroppedItemEvent,
         },
-        item::{DroppedItem, EquipEquipmentEvent, Item},
+        item::{DroppedItem, EquipEquipmentEvent, Item, Requirements},
         monster::MonsterDeathEvent,
         player::{
-            EquipSkillBookEvent, Experience, LevelUpEvent, NextPositionIndicator,
-            NextPositionIndicatorAssets, Player, PlayerAction, PlayerAssets, PlayerBooks,
-            PlayerDeathEvent, RemoveSkillBookEvent, Score,
-        },
-        skills::{
-            shuriken::ShurikenLauncherBook, ActivateSkill, AssociatedSkill, Skill, SkillBook,
+            AllPlayerAssets, EquipSkillBookEvent, Experience, LevelUpEvent, NextPositionIndicator,
+            NextPositionIndicatorAssets, Player, PlayerAction, PlayerBooks, PlayerDeathEvent,
+            RemoveSkillBookEvent, Score,
         },
+        skills::{ActivateSkill, AssociatedSkill, Skill, SkillBook},
+        status_effect::{AddStatusEffectEvent, StatusEffect},
         world_map::{WorldMap, WorldMapLoadingFinished, LAYER_PLAYER},
         GROUP_ENEMY,
     },
-    schedule::{GameRunningSet, GameState},
+    config::load_ron_file,
+    schedule::{AreaState, GameRunningSet, GameState},
     utils::{blink::Blink, invulnerable::Invulnerable},
 };
 use bevy::{prelude::*, window::PrimaryWindow};
@@ -36,7 +39,9 @@ pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<PlayerAssets>()
+        app.init_resource::<CharacterClasses>()
+            .init_resource::<AllPlayerAssets>()
+            .init_resource::<PlayerClass>()
             .init_resource::<NextPositionIndicatorAssets>()
             .init_resource::<Score>()
             .add_event::<PlayerDeathEvent>()
@@ -46,6 +51,8 @@ impl Plugin for PlayerPlugin {
             .register_type::<Inventory>()
             .register_type::<InventoryPos>()
             .register_type::<PlayerBooks>()
+            .register_type::<PlayerClass>()
+            .add_systems(PreStartup, load_character_classes)
             .add_systems(OnEnter(GameState::InGame), (spawn_player, unpause))
             .add_systems(
                 OnExit(GameState::InGame),
@@ -57,7 +64,7 @@ impl Plugin for PlayerPlugin {
                     animate_player_sprite,
                     player_invulnerability_finished,
                     increment_player_experience,
-                    activate_skill,
+                    activate_skill.run_if(in_state(AreaState::Hunting)),
                 )
                     .in_set(GameRunningSet::EntityUpdate),
             )
@@ -116,15 +123,35 @@ fn manage_player_movement_with_mouse(trigger: Trigger<OnAdd, WorldMap>, mut comm
         );
 }
 
-fn spawn_player(mut commands: Commands, assets: Res<PlayerAssets>) {
+fn load_character_classes(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+) -> Result {
+    let classes: CharacterClasses = load_ron_file("classes.ron")?;
+    info!("load_character_classes: {} classes", classes.len());
+    commands.insert_resource(AllPlayerAssets::load(&classes, &asset_server, &mut layouts));
+    commands.insert_resource(classes);
+    Ok(())
+}
+
+fn spawn_player(
+    mut commands: Commands,
+    class: Res<PlayerClass>,
+    classes: Res<CharacterClasses>,
+    assets: Res<AllPlayerAssets>,
+) {
     commands.spawn(Inventory::default());
     commands
-        .spawn(Player::bundle(&assets))
+        .spawn(Player::bundle(*class, &classes, &assets))
         .observe(set_invulnerable_on_hit)
         .observe(player_dying);
 
-    // Add a skill to the player
-    let book = commands.spawn(ShurikenLauncherBook).id();
+    // Add the starting skill of the class to the player
+    let book = classes
+        .get_class(**class)
+        .starting_skill
+        .spawn(&mut commands);
     commands.trigger(EquipSkillBookEvent {
         book_entity: book,
         action: PlayerAction::Skill1,
@@ -152,17 +179,33 @@ fn unpause(mut query: Query<(&mut Invulnerable, &mut Blink), With<Player>>) {
     }
 }
 
+/// Icon of the invulnerability [StatusEffect]
+const INVULNERABLE_TILE_INDEX: usize = 292;
+
 fn set_invulnerable_on_hit(
     trigger: Trigger<LooseLifeEvent>,
     mut commands: Commands,
     mut players: Query<&mut CollisionGroups, With<Player>>,
 ) {
+    // Damage over time doesn't set the player invulnerable
+    if trigger.kind != DamageKind::Hit {
+        return;
+    }
     if let Ok(mut collision_groups) = players.get_mut(trigger.target()) {
         // Set player invulnerable
+        let duration = Duration::from_secs_f32(1.0);
         commands.entity(trigger.target()).insert((
-            Invulnerable::new(Duration::from_secs_f32(1.0), GROUP_ENEMY),
+            Invulnerable::new(duration, GROUP_ENEMY),
             Blink::new(Duration::from_secs_f32(0.15)),
         ));
+        commands.trigger_targets(
+            AddStatusEffectEvent(StatusEffect::new(
+                "Invulnerable",
+                INVULNERABLE_TILE_INDEX,
+                duration,
+            )),
+            trigger.target(),
+        );
 
         // To allow player to not collide with enemies
         collision_groups.filters &= !GROUP_ENEMY;
@@ -184,22 +227,18 @@ fn player_dying(
 ///
 fn animate_player_sprite(
     time: Res<Time>,
-    mut q_player: Query<(&Velocity, &mut AnimationTimer, &mut Sprite), With<Player>>,
+    mut q_player: Query<(&Velocity, &PlayerClass, &mut AnimationTimer, &mut Sprite), With<Player>>,
+    classes: Res<CharacterClasses>,
 ) {
-    if let Ok((&velocity, mut timer, mut sprite)) = q_player.single_mut() {
+    if let Ok((&velocity, class, mut timer, mut sprite)) = q_player.single_mut() {
         timer.tick(time.delta());
         if timer.just_finished() {
+            let sprite_sheet = &classes.get_class(**class).sprite;
             if let Some(atlas) = &mut sprite.texture_atlas {
                 atlas.index = if velocity == Velocity::zero() {
-                    0
+                    sprite_sheet.idle_frame()
                 } else {
-                    match atlas.index {
-                        4 => 8,
-                        8 => 12,
-                        12 => 16,
-                        16 => 4,
-                        _ => 4,
-                    }
+                    sprite_sheet.next_walk_frame(atlas.index)
                 }
             }
         }
@@ -229,14 +268,16 @@ fn increment_player_experience(
 ) {
     if let Ok((mut experience, mut level)) = q_player.single_mut() {
         for monster_death_ev in monster_death_reader.read() {
-            experience.add(monster_death_ev.xp);
+            let xp = experience.monster_xp(monster_death_ev.xp, monster_death_ev.mlevel);
+            experience.add(xp);
             let current_level = experience.level();
-            if current_level > **level {
+            // A single kill can give several levels
+            for _ in **level..current_level {
                 // LEVEL UP !
-                **level = current_level;
                 info!("Level up : {current_level}");
                 commands.trigger(LevelUpEvent);
             }
+            **level = current_level;
         }
     }
 }
@@ -305,16 +346,24 @@ fn activate_skill(
 fn equip_equipment(
     trigger: Trigger<EquipEquipmentEvent>,
     mut commands: Commands,
-    players: Query<Entity, With<Player>>,
+    players: Query<(Entity, CharacterAttributes), With<Player>>,
     equipments: Query<(Entity, &Equipment, &ChildOf)>,
+    requirements: Query<&Requirements>,
 ) {
     let Ok(equipment_to_equip) = equipments.get(trigger.0).map(|(_, eqp, _)| *eqp) else {
         warn!("Can't equip {} as it's not an Equipment", trigger.0);
         return;
     };
 
+    let (player, attributes) = players.single().expect("Player");
+    if let Ok(requirements) = requirements.get(trigger.0) {
+        if !requirements.are_met(&attributes) {
+            info!("Can't equip {}: requirements not met", trigger.0);
+            return;
+        }
+    }
+
     // Check it the player already have an item of same type
-    let player = players.single().expect("Player");
     let old_equipment = equipments
         .iter()
         // same parent, same type, but different entity
@@ -432,7 +481,7 @@ fn add_to_inventory(
     trigger: Trigger<AddToInventoryEvent>,
     mut commands: Commands,
     mut inventories: Query<(Entity, &mut Inventory)>,
-    mut players: Query<&mut PlayerBooks>,
+    mut players: Query<(&mut PlayerBooks, &mut PlayerFlasks)>,
     books: Query<&AssociatedSkill, With<SkillBook>>,
 ) -> Result {
     let (inventory_entity, mut inventory) = inventories.single_mut()?;
@@ -450,16 +499,15 @@ fn add_to_inventory(
             .entity(trigger.item)
             .insert(ChildOf(inventory_entity));
 
+        let (mut player_books, mut player_flasks) = players.single_mut()?;
         // remove from Skill if it was a SkillBook
         if let Ok(&AssociatedSkill(skill)) = books.get(trigger.item) {
-            if players
-                .single_mut()
-                .expect("PlayerSkills")
-                .remove(trigger.item)
-            {
+            if player_books.remove(trigger.item) {
                 commands.entity(skill).despawn();
             }
         }
+        // remove from the belt if it was a Flask
+        player_flasks.remove(trigger.item);
         commands.trigger(InventoryChanged);
     }
     Ok(())
@@ -1,6 +1,6 @@
 use crate::components::{
     character::{Character, Target},
-    damage::Damager,
+    damage::{DamageSource, Damager},
     item::update_item_info,
     skills::death_aura::{DeathAura, DeathAuraAssets, DeathAuraBook, DeathAuraMaterial},
     world_map::LAYER_DAMAGER,
@@ -25,6 +25,7 @@ impl Plugin for DeathAuraPlugin {
 
 fn on_equip(
     trigger: Trigger<OnAdd, DeathAura>,
+    mut commands: Commands,
     mut death_auras: Query<
         (
             &mut Transform,
@@ -47,12 +48,16 @@ fn on_equip(
             mesh.0 = assets.mesh.clone();
             material.0 = assets.material.clone();
             *collision_groups = Damager::collision_groups(target);
+            commands
+                .entity(trigger.target())
+                .insert(DamageSource::from_skill(parent, trigger.target()));
         }
     }
 }
 
 fn on_unequip(
     trigger: Trigger<OnRemove, ChildOf>,
+    mut commands: Commands,
     mut death_auras: Query<
         (
             &mut Mesh2d,
@@ -68,5 +73,8 @@ fn on_unequip(
         *mesh = Mesh2d::default();
         *material = MeshMaterial2d::default();
         *collision_groups = CollisionGroups::default();
+        commands
+            .entity(trigger.target())
+            .try_remove::<DamageSource>();
     }
 }
@@ -2,7 +2,9 @@ use crate::{
     components::{
         affix::PierceChance,
         character::{Character, Target},
-        damage::{Damager, DamagerParams, HitDamageRange, Projectile, ProjectileParams},
+        damage::{
+            DamageSource, Damager, DamagerParams, HitDamageRange, Projectile, ProjectileParams,
+        },
         despawn_all,
         item::update_item_info,
         skills::{
@@ -60,6 +62,7 @@ fn cast_fireball(
                 DamagerParams {
                     transform: Transform::from_translation(origin.extend(LAYER_DAMAGER)),
                     collision_groups: Damager::collision_groups(*target),
+                    source: DamageSource::from_skill(child_of.parent(), skill_entity),
                 },
                 ProjectileParams {
                     pierce_chance: *pierce,
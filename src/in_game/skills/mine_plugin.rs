@@ -2,7 +2,7 @@ use crate::{
     components::{
         animation::OneShotAnimation,
         character::{Character, Target},
-        damage::{Damager, DamagerParams, HitDamageRange},
+        damage::{DamageSource, Damager, DamagerParams, HitDamageRange},
         despawn_all,
         item::update_item_info,
         skills::{
@@ -54,6 +54,7 @@ fn drop_mine(
                 DamagerParams {
                     transform: Transform::from_translation(translation.with_z(LAYER_DAMAGER)),
                     collision_groups: Damager::collision_groups(*target),
+                    source: DamageSource::from_skill(child_of.parent(), skill_entity),
                 },
                 Sprite::from_atlas_image(image, atlas),
             ));
@@ -69,11 +70,12 @@ fn mine_explosion(
         &HitDamageRange,
         &Transform,
         &CollisionGroups,
+        &DamageSource,
     )>,
     time: Res<Time>,
     assets: Res<MineAssets>,
 ) {
-    for (entity, mut timer, &damage_range, &transform, &collision_groups) in &mut mines {
+    for (entity, mut timer, &damage_range, &transform, &collision_groups, &source) in &mut mines {
         timer.tick(time.delta());
         if timer.just_finished() {
             commands.entity(entity).despawn();
@@ -87,6 +89,7 @@ fn mine_explosion(
                 DamagerParams {
                     collision_groups,
                     transform,
+                    source,
                 },
                 Sprite::from_atlas_image(image, atlas),
             ));
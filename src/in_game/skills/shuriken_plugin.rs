@@ -2,7 +2,7 @@ use crate::{
     components::{
         affix::PierceChance,
         character::{Character, Target},
-        damage::{Damager, DamagerParams, HitDamageRange, ProjectileParams},
+        damage::{DamageSource, Damager, DamagerParams, HitDamageRange, ProjectileParams},
         despawn_all,
         item::update_item_info,
         skills::{
@@ -48,6 +48,7 @@ fn launch_shuriken(
                 DamagerParams {
                     transform: Transform::from_translation(origin.extend(LAYER_DAMAGER)),
                     collision_groups: Damager::collision_groups(*target),
+                    source: DamageSource::from_skill(child_of.parent(), skill_entity),
                 },
                 ProjectileParams {
                     pierce_chance: *pierce_chance,
@@ -0,0 +1,53 @@
+use crate::{
+    components::status_effect::{AddStatusEffectEvent, StatusEffect},
+    schedule::GameRunningSet,
+};
+use bevy::prelude::*;
+
+pub struct StatusEffectPlugin;
+
+impl Plugin for StatusEffectPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<StatusEffect>()
+            .add_systems(
+                Update,
+                update_status_effects.in_set(GameRunningSet::EntityUpdate),
+            )
+            .add_observer(add_status_effect);
+    }
+}
+
+/// Add the [StatusEffect] to the character, or stack it if the character
+/// already has the same effect
+fn add_status_effect(
+    trigger: Trigger<AddStatusEffectEvent>,
+    mut commands: Commands,
+    characters: Query<&Children>,
+    mut effects: Query<&mut StatusEffect>,
+) {
+    let character = trigger.target();
+    if let Ok(children) = characters.get(character) {
+        for child in children.iter() {
+            if let Ok(mut effect) = effects.get_mut(child) {
+                if effect.label == trigger.label {
+                    effect.stack(trigger.duration());
+                    return;
+                }
+            }
+        }
+    }
+    commands.spawn((trigger.0.clone(), ChildOf(character)));
+}
+
+fn update_status_effects(
+    mut commands: Commands,
+    mut effects: Query<(Entity, &mut StatusEffect)>,
+    time: Res<Time>,
+) {
+    for (entity, mut effect) in &mut effects {
+        effect.tick(time.delta());
+        if effect.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
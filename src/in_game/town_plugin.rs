@@ -0,0 +1,189 @@
+use crate::{
+    components::{
+        despawn_all,
+        inventory::{Inventory, RemoveFromInventoryEvent},
+        item::Item,
+        orb::Orb,
+        player::Player,
+        town::{
+            AddToStashEvent, CraftingBench, EnterTownStationEvent, LeaveTownStationEvent,
+            PutOnCraftingBenchEvent, Stash, Town, TownStation, TownStorageChanged,
+        },
+        world_map::WorldMapLoadingFinished,
+    },
+    schedule::{AreaState, GameRunningSet, GameState},
+    utils::collision::QueryEither,
+};
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_rapier2d::prelude::*;
+
+///
+/// The [Town] is the safe hub between the runs, with a [Stash], a [CraftingBench],
+/// a vendor and a map device.
+///
+/// The player goes back to the [Town] with the town portal, or when a map is completed.
+///
+pub struct TownPlugin;
+
+impl Plugin for TownPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TownStation>()
+            .register_type::<Stash>()
+            .register_type::<CraftingBench>()
+            .add_systems(
+                OnEnter(GameState::InGame),
+                (spawn_town_storage, start_in_town),
+            )
+            .add_systems(
+                OnExit(GameState::InGame),
+                (despawn_all::<Stash>, despawn_all::<CraftingBench>),
+            )
+            .add_systems(OnEnter(AreaState::Town), spawn_town)
+            .add_systems(OnExit(AreaState::Town), despawn_all::<Town>)
+            .add_systems(
+                Update,
+                (
+                    take_town_portal.run_if(
+                        in_state(AreaState::Hunting).and(input_just_pressed(KeyCode::KeyT)),
+                    ),
+                    walk_on_stations.run_if(in_state(AreaState::Town)),
+                )
+                    .in_set(GameRunningSet::UserInput),
+            )
+            .add_observer(take_portal_station)
+            .add_observer(add_to_stash)
+            .add_observer(put_on_crafting_bench)
+            .add_observer(forget_items_leaving_town_storage);
+    }
+}
+
+fn spawn_town_storage(mut commands: Commands) {
+    commands.spawn(Stash::default());
+    commands.spawn(CraftingBench::default());
+}
+
+fn start_in_town(mut area_state: ResMut<NextState<AreaState>>) {
+    area_state.set(AreaState::Town);
+}
+
+fn spawn_town(mut commands: Commands) {
+    info!("spawn_town");
+    let town = commands.spawn(Town).id();
+    for station in TownStation::ALL {
+        commands.spawn((station.bundle(), ChildOf(town)));
+    }
+    commands.trigger(WorldMapLoadingFinished {
+        translation: Vec2::ZERO,
+    });
+}
+
+fn take_town_portal(mut area_state: ResMut<NextState<AreaState>>) {
+    info!("Take the town portal");
+    area_state.set(AreaState::Town);
+}
+
+fn take_portal_station(
+    trigger: Trigger<EnterTownStationEvent>,
+    mut area_state: ResMut<NextState<AreaState>>,
+) {
+    if **trigger == TownStation::Portal {
+        area_state.set(AreaState::Hunting);
+    }
+}
+
+fn walk_on_stations(
+    mut commands: Commands,
+    mut collisions: EventReader<CollisionEvent>,
+    players: Query<(), With<Player>>,
+    stations: Query<&TownStation>,
+) {
+    for event in collisions.read() {
+        match *event {
+            CollisionEvent::Started(e1, e2, _) => {
+                if let Some((&station, _, _)) = stations.get_either(e1, e2) {
+                    if players.get_either(e1, e2).is_some() {
+                        info!("Enter the {}", station.title());
+                        commands.trigger(EnterTownStationEvent(station));
+                    }
+                }
+            }
+            CollisionEvent::Stopped(e1, e2, _) => {
+                if let Some((&station, _, _)) = stations.get_either(e1, e2) {
+                    if players.get_either(e1, e2).is_some() {
+                        commands.trigger(LeaveTownStationEvent(station));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn add_to_stash(
+    trigger: Trigger<AddToStashEvent>,
+    mut commands: Commands,
+    stash: Single<(Entity, &mut Stash)>,
+    inventory: Single<&Inventory>,
+) {
+    let item = trigger.item;
+    let (stash_entity, mut stash) = stash.into_inner();
+    let from_stash = stash.contains(item);
+    if !from_stash && !inventory.contains(item) {
+        warn!("Only the items of the inventory can be stored in the stash");
+        return;
+    }
+
+    if stash.at(trigger.pos).is_some() {
+        return;
+    }
+
+    // Allow to move an item
+    stash.remove(item);
+    stash.add_at(item, trigger.pos);
+    if !from_stash {
+        commands.trigger(RemoveFromInventoryEvent(item));
+        commands.entity(item).insert(ChildOf(stash_entity));
+    }
+    commands.trigger(TownStorageChanged);
+}
+
+fn put_on_crafting_bench(
+    trigger: Trigger<PutOnCraftingBenchEvent>,
+    mut commands: Commands,
+    bench: Single<(Entity, &mut CraftingBench)>,
+    inventory: Single<&Inventory>,
+    orbs: Query<(), With<Orb>>,
+) {
+    let item = trigger.0;
+    let (bench_entity, mut bench) = bench.into_inner();
+    if bench.is_some() || orbs.contains(item) || !inventory.contains(item) {
+        return;
+    }
+    info!("Put {item} on the crafting bench");
+    **bench = Some(item);
+    commands.trigger(RemoveFromInventoryEvent(item));
+    commands.entity(item).insert(ChildOf(bench_entity));
+    commands.trigger(TownStorageChanged);
+}
+
+/// Remove the items from the [Stash] or the [CraftingBench] when they move
+/// elsewhere, or when they are despawned (e.g. a consumed [Orb])
+fn forget_items_leaving_town_storage(
+    trigger: Trigger<OnReplace, ChildOf>,
+    mut commands: Commands,
+    items: Query<&ChildOf, With<Item>>,
+    mut stashes: Query<&mut Stash>,
+    mut benches: Query<&mut CraftingBench>,
+) {
+    let item = trigger.target();
+    let Ok(&ChildOf(parent)) = items.get(item) else {
+        return;
+    };
+    let removed = match (stashes.get_mut(parent), benches.get_mut(parent)) {
+        (Ok(mut stash), _) => stash.remove(item),
+        (_, Ok(mut bench)) => bench.take().is_some(),
+        _ => false,
+    };
+    if removed {
+        commands.trigger(TownStorageChanged);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    components::{
+        despawn_all,
+        inventory::{AddToInventoryEvent, Inventory, RemoveFromInventoryEvent},
+        item::{AffixTiers, ItemLevel, ItemRarity},
+        orb::Orb,
+        vendor::{
+            item_value, orbs_for_value, BuyItemEvent, ExchangeCurrencyEvent, Price, SellItemEvent,
+            VendorStock, VendorStockChanged, CURRENCY_RECIPES,
+        },
+        world_map::CurrentMapLevel,
+    },
+    schedule::AreaState,
+};
+use bevy::prelude::*;
+
+///
+/// The vendor of the town buys the items for orbs, sells a stock of equipments,
+/// and exchanges orbs.
+///
+pub struct VendorPlugin;
+
+impl Plugin for VendorPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<AffixTiers>()
+            .add_systems(OnEnter(AreaState::Town), spawn_vendor_stock)
+            .add_systems(OnExit(AreaState::Town), despawn_all::<VendorStock>)
+            .add_observer(sell_item)
+            .add_observer(buy_item)
+            .add_observer(exchange_currency);
+    }
+}
+
+fn spawn_vendor_stock(mut commands: Commands, map_level: Res<CurrentMapLevel>) {
+    let mut rng = rand::rng();
+    VendorStock::spawn(&mut commands, **map_level, &mut rng);
+}
+
+/// Orbs of the [Inventory] used to pay the `price`, if there are enough of them
+fn payment(inventory: &Inventory, orbs: &Query<&Orb>, price: Price) -> Option<Vec<Entity>> {
+    let payment = inventory
+        .items()
+        .filter(|&item| orbs.get(item).is_ok_and(|&orb| orb == price.orb))
+        .take(usize::from(price.count))
+        .collect::<Vec<_>>();
+    (payment.len() == usize::from(price.count)).then_some(payment)
+}
+
+fn pay(commands: &mut Commands, payment: Vec<Entity>) {
+    for orb in payment {
+        commands.trigger(RemoveFromInventoryEvent(orb));
+        commands.entity(orb).despawn();
+    }
+}
+
+fn give_orbs(commands: &mut Commands, orbs: Vec<Orb>) {
+    for orb in orbs {
+        let orb = commands.spawn(orb).id();
+        commands.trigger(AddToInventoryEvent::new(orb));
+    }
+}
+
+fn sell_item(
+    trigger: Trigger<SellItemEvent>,
+    mut commands: Commands,
+    inventory: Single<&Inventory>,
+    items: Query<(Option<&ItemRarity>, Option<&ItemLevel>, Option<&AffixTiers>)>,
+    orbs: Query<(), With<Orb>>,
+) {
+    let item = trigger.0;
+    if !inventory.contains(item) {
+        warn!("Only the items of the inventory can be sold");
+        return;
+    }
+    if orbs.contains(item) {
+        info!("The orbs are exchanged with the recipes");
+        return;
+    }
+    let Ok((rarity, ilevel, tiers)) = items.get(item) else {
+        return;
+    };
+    let value = item_value(
+        rarity.copied().unwrap_or_default(),
+        ilevel.map(|l| **l).unwrap_or_default(),
+        tiers.map(|t| t.as_slice()).unwrap_or_default(),
+    );
+    let payment = orbs_for_value(value);
+    // The sold item frees its location
+    if payment.len() > inventory.free_slots() + 1 {
+        info!("Can't sell {item}: not enough room in the inventory");
+        return;
+    }
+
+    info!("Sell {item} for {value}");
+    commands.trigger(RemoveFromInventoryEvent(item));
+    commands.entity(item).despawn();
+    give_orbs(&mut commands, payment);
+}
+
+fn buy_item(
+    trigger: Trigger<BuyItemEvent>,
+    mut commands: Commands,
+    mut stock: Single<&mut VendorStock>,
+    inventory: Single<&Inventory>,
+    orbs: Query<&Orb>,
+) {
+    let Some(offer) = stock.get(trigger.0) else {
+        return;
+    };
+    let Some(payment) = payment(&inventory, &orbs, offer.price) else {
+        info!("Can't buy {}: {} needed", offer.item, offer.price);
+        return;
+    };
+
+    info!("Buy {} for {}", offer.item, offer.price);
+    stock.take(trigger.0);
+    pay(&mut commands, payment);
+    commands.trigger(AddToInventoryEvent::new(offer.item));
+    commands.trigger(VendorStockChanged);
+}
+
+fn exchange_currency(
+    trigger: Trigger<ExchangeCurrencyEvent>,
+    mut commands: Commands,
+    inventory: Single<&Inventory>,
+    orbs: Query<&Orb>,
+) {
+    let Some(recipe) = CURRENCY_RECIPES.get(trigger.0) else {
+        return;
+    };
+    let Some(payment) = payment(&inventory, &orbs, recipe.input) else {
+        info!("Can't exchange: {} needed", recipe.input);
+        return;
+    };
+
+    info!("Exchange {recipe}");
+    pay(&mut commands, payment);
+    give_orbs(&mut commands, vec![recipe.output]);
+}
@@ -1,7 +1,19 @@
 use crate::{
     camera::MainCamera,
-    components::{despawn_all, world_map::*},
-    schedule::{GameRunningSet, GameState},
+    components::{
+        affix::IncreaseMovementSpeed,
+        character::LooseLifeEvent,
+        damage::{Damage, DamageKind, DamageSource},
+        despawn_all,
+        map_item::ActiveMap,
+        player::Player,
+        prop::{DestructibleProp, Prop, PropAssets, PropDestroyedEvent},
+        status_effect::{AddStatusEffectEvent, StatusEffect},
+        world_map::*,
+    },
+    config::{GameConfig, MapMode},
+    in_game::ldtk_map_plugin::load_ldtk_project,
+    schedule::{AreaState, GameRunningSet, GameState},
     utils::picking::{WorldPosition, MAP_DEPTH},
 };
 use bevy::{
@@ -13,6 +25,16 @@ use bevy::{
     prelude::*,
 };
 use bevy_ecs_tilemap::TilemapPlugin;
+use std::time::Duration;
+
+/// [IncreaseMovementSpeed] of the player in the [Biome::Snow]
+const SNOW_MOVEMENT_SPEED: f32 = -30.;
+
+/// Poison damage per second taken by the player in the [Biome::Swamp]
+const SWAMP_POISON_DAMAGE: f32 = 0.5;
+
+/// Duration of the [StatusEffect] of a [Biome] after leaving it
+const BIOME_STATUS_DURATION: Duration = Duration::from_millis(500);
 
 pub struct WorldMapPlugin;
 
@@ -20,11 +42,25 @@ impl Plugin for WorldMapPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(TilemapPlugin)
             .register_type::<MapLevelConfig>()
+            .register_type::<Prop>()
+            .add_event::<PropDestroyedEvent>()
             .init_resource::<WorldMapAssets>()
+            .init_resource::<PropAssets>()
             .init_resource::<CurrentMapLevel>()
             .init_resource::<ChangeLevelTimer>()
-            .add_systems(OnEnter(GameState::InGame), (reset_level, spawn_worldmap))
-            .add_systems(OnExit(GameState::InGame), despawn_all::<WorldMap>)
+            .add_systems(OnEnter(GameState::InGame), reset_level)
+            .add_systems(
+                OnEnter(AreaState::Hunting),
+                spawn_worldmap.after(load_ldtk_project),
+            )
+            .add_systems(
+                OnExit(AreaState::Hunting),
+                (
+                    despawn_all::<WorldMap>,
+                    despawn_all::<BiomeEffect>,
+                    remove_worldmap,
+                ),
+            )
             .add_systems(
                 PreUpdate,
                 (
@@ -35,7 +71,13 @@ impl Plugin for WorldMapPlugin {
             )
             .add_systems(
                 Update,
-                (spawn_chunks, despawn_out_of_range_chunks, change_level)
+                (
+                    spawn_chunks,
+                    despawn_out_of_range_chunks,
+                    change_level.run_if(not(resource_exists::<ActiveMap>)),
+                    (update_player_biome, apply_biome_effects).chain(),
+                )
+                    .run_if(resource_exists::<ProceduralWorldMap>)
                     .in_set(GameRunningSet::EntityUpdate),
                 // (spawn_characters, spawn_colliders).in_set(GameRunningSet::EntityUpdate),
             );
@@ -45,6 +87,11 @@ impl Plugin for WorldMapPlugin {
 #[derive(Resource, Deref, DerefMut)]
 struct ChangeLevelTimer(Timer);
 
+/// Effect of the [Biome] under the player, as a child of the player
+#[derive(Component, Deref)]
+#[require(Name::new("BiomeEffect"))]
+struct BiomeEffect(Biome);
+
 impl Default for ChangeLevelTimer {
     fn default() -> Self {
         Self(Timer::from_seconds(20.0, TimerMode::Repeating))
@@ -56,12 +103,24 @@ fn reset_level(mut level: ResMut<CurrentMapLevel>, mut timer: ResMut<ChangeLevel
     timer.reset();
 }
 
-fn spawn_worldmap(mut commands: Commands) {
+/// The [ProceduralWorldMap] is only used in [MapMode::Procedural], see
+/// [super::ldtk_map_plugin] for the handcrafted levels, which falls back to it
+fn spawn_worldmap(mut commands: Commands, config: Res<GameConfig>) {
+    if config.map_mode != MapMode::Procedural {
+        return;
+    }
     let mut rng = rand::rng();
     let config = WorldMapConfig::default();
     let map = ProceduralWorldMap::new(config, &mut rng);
     commands.insert_resource(map);
     commands.spawn(WorldMap);
+    commands.trigger(WorldMapLoadingFinished {
+        translation: Vec2::ZERO,
+    });
+}
+
+fn remove_worldmap(mut commands: Commands) {
+    commands.remove_resource::<ProceduralWorldMap>();
 }
 
 fn change_level(
@@ -80,6 +139,7 @@ fn spawn_chunks(
     world_maps: Query<Entity, With<WorldMap>>,
     mut world_map: ResMut<ProceduralWorldMap>,
     assets: Res<WorldMapAssets>,
+    prop_assets: Res<PropAssets>,
 ) {
     let Ok(camera_pos) = cameras.single().map(|t| t.translation.xy()) else {
         return;
@@ -87,13 +147,20 @@ fn spawn_chunks(
     let Ok(map_entity) = world_maps.single() else {
         return;
     };
-    let camera_chunk_pos = world_map.chunk_pos(camera_pos);
+    let camera_chunk_pos = world_map.coords().world_to_chunk(camera_pos);
     let mut chunk_entities = Vec::with_capacity(9);
     for y in (camera_chunk_pos.y - 1)..=(camera_chunk_pos.y + 1) {
         for x in (camera_chunk_pos.x - 1)..=(camera_chunk_pos.x + 1) {
             let chunk_pos = IVec2::new(x, y);
             if !world_map.is_spawned(chunk_pos) {
                 let chunk_entity = world_map.spawn_chunk(&mut commands, &assets, chunk_pos);
+                for (prop, pos) in world_map.chunk_props(chunk_pos) {
+                    let mut prop_commands =
+                        commands.spawn((prop.bundle(pos, &prop_assets), ChildOf(chunk_entity)));
+                    if prop.kind.is_destructible() {
+                        prop_commands.insert(DestructibleProp);
+                    }
+                }
                 chunk_entities.push(chunk_entity);
             }
         }
@@ -104,25 +171,78 @@ fn spawn_chunks(
 fn despawn_out_of_range_chunks(
     mut commands: Commands,
     cameras: Query<&Transform, With<MainCamera>>,
-    chunks_query: Query<(Entity, &Transform), With<WorldMapChunk>>,
+    chunks_query: Query<(Entity, &WorldMapChunk)>,
     mut world_map: ResMut<ProceduralWorldMap>,
 ) {
     let Ok(camera_pos) = cameras.single().map(|t| t.translation.xy()) else {
         return;
     };
-    for (entity, chunk_transform) in chunks_query.iter() {
-        let chunk_pos = chunk_transform.translation.xy();
-        let distance = camera_pos.distance(chunk_pos);
-        if world_map.remove_chunk_if_out_of_bound(chunk_pos, distance) {
+    for (entity, chunk) in chunks_query.iter() {
+        if world_map.remove_chunk_if_out_of_bound(**chunk, camera_pos) {
             commands.entity(entity).despawn();
         }
     }
 }
 
+/// Change the [BiomeEffect] of the player when entering another [Biome]
+fn update_player_biome(
+    mut commands: Commands,
+    player: Single<(Entity, &Transform), With<Player>>,
+    effects: Query<(Entity, &BiomeEffect)>,
+    world_map: Res<ProceduralWorldMap>,
+) {
+    let (player, transform) = *player;
+    let biome = world_map.biome(transform.translation.xy());
+    if let Ok((entity, effect)) = effects.single() {
+        if **effect == biome {
+            return;
+        }
+        commands.entity(entity).despawn();
+    }
+
+    info!("Player enters the {biome:?} biome");
+    let mut effect = commands.spawn((BiomeEffect(biome), ChildOf(player)));
+    if let Biome::Snow = biome {
+        effect.insert(IncreaseMovementSpeed(SNOW_MOVEMENT_SPEED));
+    }
+}
+
+/// The snow slows the movement, and the swamp poisons.
+///
+/// The poison damage comes from the [WorldMap], so no character is credited for it.
+fn apply_biome_effects(
+    mut commands: Commands,
+    effects: Query<(&BiomeEffect, &ChildOf)>,
+    world_map: Single<Entity, With<WorldMap>>,
+    time: Res<Time>,
+) {
+    for (effect, &ChildOf(character)) in &effects {
+        let status = match **effect {
+            Biome::Snow => StatusEffect::new("Chilled", 128, BIOME_STATUS_DURATION),
+            Biome::Swamp => {
+                commands.trigger_targets(
+                    LooseLifeEvent {
+                        damage: Damage(SWAMP_POISON_DAMAGE * time.delta_secs()),
+                        kind: DamageKind::OverTime,
+                        source: DamageSource::from_character(*world_map),
+                    },
+                    character,
+                );
+                StatusEffect::new("Poisoned", 226, BIOME_STATUS_DURATION)
+            }
+            Biome::Forest | Biome::Desert => continue,
+        };
+        commands.trigger_targets(AddStatusEffectEvent(status), character);
+    }
+}
+
+/// Pick the [WorldMap] under the pointer, only on the spawned chunks of
+/// a [ProceduralWorldMap]
 fn world_map_picking_backend(
     pointers: Query<(&PointerId, &PointerLocation)>,
     camera: Single<(Entity, &Camera, &GlobalTransform), With<MainCamera>>,
     worlds_maps: Query<Entity, With<WorldMap>>,
+    procedural_map: Option<Res<ProceduralWorldMap>>,
     mut output: EventWriter<PointerHits>,
 ) {
     let Ok(world_map) = worlds_maps.single() else {
@@ -134,6 +254,11 @@ fn world_map_picking_backend(
         else {
             continue;
         };
+        if let Some(map) = &procedural_map {
+            if !map.is_spawned(map.coords().world_to_chunk(pointer_world_pos)) {
+                continue;
+            }
+        }
 
         let depth = MAP_DEPTH;
         let position = Some(pointer_world_pos.extend(0.));
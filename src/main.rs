@@ -1,6 +1,7 @@
 mod asset_tracking;
 mod audio;
 mod camera;
+mod class_select;
 mod components;
 mod config;
 mod dnd;
@@ -75,6 +76,7 @@ fn main() {
         camera::camera_plugin,
         splash::splash_plugin,
         main_menu::main_menu_plugin,
+        class_select::class_select_plugin,
         dnd::DndPlugin,
         in_game::InGamePluginsGroup,
     ));
@@ -38,7 +38,7 @@ fn on_new_game(
     _trigger: Trigger<Pointer<Click>>,
     mut next_game_state: ResMut<NextState<GameState>>,
 ) {
-    next_game_state.set(GameState::InGame);
+    next_game_state.set(GameState::ClassSelect);
 }
 
 fn on_exit(_trigger: Trigger<Pointer<Click>>, mut app_exit_events: EventWriter<AppExit>) {
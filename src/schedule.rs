@@ -6,6 +6,7 @@ pub enum GameState {
     #[default]
     Splash,
     Menu,
+    ClassSelect,
     InGame,
 }
 
@@ -23,6 +24,17 @@ pub enum InGameState {
     LevelUp,
 }
 
+/// Represent the area where the player is, while in game
+#[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, States)]
+pub enum AreaState {
+    #[default]
+    Disabled,
+    /// The safe hub between the runs
+    Town,
+    /// The open field or a map, with monsters
+    Hunting,
+}
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, SystemSet)]
 pub enum GameRunningSet {
     UserInput,
@@ -33,6 +45,7 @@ pub enum GameRunningSet {
 pub fn schedule_plugin(app: &mut App) {
     app.init_state::<GameState>()
         .init_state::<InGameState>()
+        .init_state::<AreaState>()
         .configure_sets(
             Update,
             (
@@ -60,6 +73,10 @@ pub fn game_is_running(
     *game_state == GameState::InGame && *in_game_state == InGameState::Running
 }
 
-fn end_game(mut in_game_state: ResMut<NextState<InGameState>>) {
+fn end_game(
+    mut in_game_state: ResMut<NextState<InGameState>>,
+    mut area_state: ResMut<NextState<AreaState>>,
+) {
     in_game_state.set(InGameState::Disabled);
+    area_state.set(AreaState::Disabled);
 }
@@ -1,4 +1,23 @@
 mod test_affix_update;
+mod test_attributes;
+mod test_character_class;
+mod test_defences;
+mod test_experience;
+mod test_flask;
+mod test_item_quantity_rarity;
+mod test_ldtk;
+mod test_leech;
+mod test_loot;
+mod test_loot_filter;
+mod test_map_coords;
+mod test_map_item;
+mod test_monster_pack;
+mod test_passive_tree;
+mod test_props;
+mod test_status_effect;
+mod test_town;
+mod test_vendor;
+mod test_world_map;
 
 #[macro_export]
 macro_rules! assert_approx_eq {
@@ -0,0 +1,83 @@
+use crate::assert_approx_eq;
+use crate::components::{
+    affix::{IncreaseAttackSpeed, Strength},
+    character::{
+        BaseAttributes, BaseLife, Character, CharacterAttributes, CharacterLevel, MaxLife,
+    },
+    equipment::Helmet,
+    item::{ItemSpawnBundle, Requirements},
+};
+use crate::in_game::affix_updates_plugin::AffixUpdatesPlugin;
+use crate::schedule::{GameState, InGameState};
+use bevy::{prelude::*, state::app::StatesPlugin};
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, AffixUpdatesPlugin))
+        .insert_state(GameState::InGame)
+        .insert_state(InGameState::Running);
+    app
+}
+
+fn spawn_character(app: &mut App) -> Entity {
+    let mut rng = rand::rng();
+    let character = app
+        .world_mut()
+        .spawn((
+            Character,
+            BaseLife::bundle(10.),
+            BaseAttributes {
+                strength: 10.,
+                dexterity: 20.,
+                intelligence: 10.,
+            },
+            children![(Helmet::new(1, &mut rng).0, Strength(6.)), Strength(4.)],
+        ))
+        .id();
+    app.update();
+    character
+}
+
+#[test]
+fn test_attributes_bonus() {
+    let mut app = create_app();
+    let character = spawn_character(&mut app);
+
+    let strength = app.world().get::<Strength>(character).unwrap();
+    assert_eq!(20., **strength);
+    let max_life = app.world().get::<MaxLife>(character).unwrap();
+    assert_approx_eq!(14., **max_life);
+    let attack_speed = app.world().get::<IncreaseAttackSpeed>(character).unwrap();
+    assert_approx_eq!(4., **attack_speed);
+}
+
+#[test]
+fn test_item_requirements() {
+    let mut app = create_app();
+    let character = spawn_character(&mut app);
+
+    let requirements = Requirements::new(10, (2, 0, 0));
+    assert_eq!(8, requirements.level);
+    assert_eq!(24, requirements.strength);
+    assert_eq!(0, requirements.dexterity);
+
+    let mut query = app.world_mut().query::<CharacterAttributes>();
+    let attributes = query.get(app.world(), character).unwrap();
+    assert_eq!(
+        vec![
+            ("Level 9".to_string(), false),
+            ("24 Str".to_string(), false)
+        ],
+        requirements.check(&attributes)
+    );
+    assert!(Requirements::new(0, (0, 0, 0)).are_met(&attributes));
+    assert!(Requirements::new(0, (2, 0, 0)).are_met(&attributes));
+
+    app.world_mut()
+        .entity_mut(character)
+        .insert(CharacterLevel(8));
+    let mut query = app.world_mut().query::<CharacterAttributes>();
+    let attributes = query.get(app.world(), character).unwrap();
+    assert!(!requirements.are_met(&attributes));
+    assert!(Requirements::new(10, (0, 1, 0)).are_met(&attributes));
+}
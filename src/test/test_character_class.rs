@@ -0,0 +1,23 @@
+use crate::components::{character_class::CharacterClasses, passive_tree::PassiveTree};
+use crate::config::load_ron_file;
+
+#[test]
+fn test_character_classes_asset_is_valid() {
+    let classes: CharacterClasses = load_ron_file("classes.ron").expect("Valid classes");
+    let tree: PassiveTree = load_ron_file("passive_tree.ron").expect("Valid passive tree");
+    assert!(!classes.is_empty());
+    for class in classes.iter() {
+        assert!(class.life > 0., "{}", class.name);
+        assert!(tree.node(class.passive_start).is_some(), "{}", class.name);
+
+        let sprite = &class.sprite;
+        let n_frames = (sprite.columns * sprite.rows) as usize;
+        assert!(!sprite.walk_frames.is_empty(), "{}", class.name);
+        assert!(sprite.idle_frame() < n_frames, "{}", class.name);
+        assert!(
+            sprite.walk_frames.iter().all(|&frame| frame < n_frames),
+            "{}",
+            class.name
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::assert_approx_eq;
+use crate::components::{
+    affix::{BaseEnergyShield, BaseEvasion, EnergyShield, Evasion, MoreEnergyShield, MoreEvasion},
+    character::{Character, CurrentEnergyShield},
+    damage::Damage,
+    equipment::{DefenceImplicit, DefenceKind, Helmet},
+    item::ItemSpawnBundle,
+};
+use crate::in_game::affix_updates_plugin::AffixUpdatesPlugin;
+use crate::schedule::{GameState, InGameState};
+use bevy::{prelude::*, state::app::StatesPlugin};
+use std::time::Duration;
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, AffixUpdatesPlugin))
+        .insert_state(GameState::InGame)
+        .insert_state(InGameState::Running);
+    app
+}
+
+#[test]
+fn test_evade_chance() {
+    assert_eq!(0., Evasion(0.).evade_chance());
+    assert_approx_eq!(50., Evasion(100.).evade_chance());
+    assert_approx_eq!(75., Evasion(10000.).evade_chance());
+}
+
+#[test]
+fn test_energy_shield_absorb_and_recharge() {
+    let max = EnergyShield(10.);
+    let mut energy_shield = CurrentEnergyShield::default();
+
+    // Recharge only starts after a delay
+    energy_shield.recharge(Duration::from_secs(1), &max);
+    assert_eq!(0., energy_shield.value());
+    energy_shield.recharge(Duration::from_secs(1), &max);
+    assert_approx_eq!(3.3, energy_shield.value());
+    energy_shield.recharge(Duration::from_secs(5), &max);
+    assert_eq!(10., energy_shield.value());
+
+    let damage = energy_shield.absorb(Damage(4.));
+    assert_eq!(0., *damage);
+    assert_eq!(6., energy_shield.value());
+    let damage = energy_shield.absorb(Damage(8.));
+    assert_eq!(2., *damage);
+    assert_eq!(0., energy_shield.value());
+
+    // Taking damage restarts the recharge delay
+    energy_shield.recharge(Duration::from_secs(1), &max);
+    assert_eq!(0., energy_shield.value());
+}
+
+#[test]
+fn test_update_character_defences() {
+    let mut app = create_app();
+    let mut rng = rand::rng();
+
+    let character = app
+        .world_mut()
+        .spawn((
+            Character,
+            children![
+                (
+                    Helmet::new(1, &mut rng).0,
+                    DefenceImplicit::new(DefenceKind::Evasion, 3.),
+                    MoreEvasion(2.),
+                ),
+                (
+                    Helmet::new(1, &mut rng).0,
+                    BaseEnergyShield(4.),
+                    MoreEnergyShield(1.),
+                ),
+                MoreEvasion(10.),
+                MoreEnergyShield(5.),
+            ],
+        ))
+        .id();
+
+    app.update();
+
+    let evasion = app.world().get::<Evasion>(character).unwrap();
+    assert_eq!(15., **evasion);
+    let energy_shield = app.world().get::<EnergyShield>(character).unwrap();
+    assert_eq!(10., **energy_shield);
+}
+
+#[test]
+fn test_defence_implicit() {
+    let implicit = DefenceImplicit::new(DefenceKind::EnergyShield, 3.);
+    assert_eq!("3 Base energy shield", implicit.to_string());
+    assert_eq!((0, 0, 2), DefenceKind::EnergyShield.attributes());
+
+    let mut app = create_app();
+    let helmet = app
+        .world_mut()
+        .spawn((
+            Helmet::new(1, &mut rand::rng()).0,
+            DefenceImplicit::new(DefenceKind::Armour, 3.),
+        ))
+        .id();
+    app.update();
+    let base_evasion = app.world().get::<BaseEvasion>(helmet).unwrap();
+    assert_eq!(0., **base_evasion);
+    let energy_shield = app.world().get::<EnergyShield>(helmet).unwrap();
+    assert_eq!(0., **energy_shield);
+}
@@ -0,0 +1,63 @@
+use crate::components::player::Experience;
+
+#[test]
+fn test_experience_levels_up_to_max_level() {
+    let mut xp = Experience::default();
+    assert_eq!(0, xp.level());
+    assert_eq!(
+        (0, Experience::level_min_exp(1)),
+        xp.get_current_level_min_max_exp()
+    );
+
+    let mut previous_level = 0;
+    while xp.level() < Experience::MAX_LEVEL {
+        xp.add(37);
+        let level = xp.level();
+        assert!(level >= previous_level);
+        let (min, max) = xp.get_current_level_min_max_exp();
+        assert!(min < max);
+        assert!(min <= xp.current() && xp.current() <= max);
+        previous_level = level;
+    }
+
+    // The experience can't go beyond the max level
+    xp.add(u32::MAX);
+    assert_eq!(Experience::MAX_LEVEL, xp.level());
+    let (min, max) = xp.get_current_level_min_max_exp();
+    assert!(min < max);
+    assert_eq!(max, xp.current());
+}
+
+#[test]
+fn test_experience_level_thresholds() {
+    for level in 1..=Experience::MAX_LEVEL {
+        let threshold = Experience::level_min_exp(level);
+        assert!(threshold > Experience::level_min_exp(level - 1));
+
+        let mut xp = Experience::default();
+        xp.add(threshold - 1);
+        assert_eq!(level - 1, xp.level());
+        xp.add(1);
+        assert_eq!(level, xp.level());
+    }
+}
+
+#[test]
+fn test_experience_level_gap_multiplier() {
+    // Safe zone
+    assert_eq!(1., Experience::level_gap_multiplier(10, 10));
+    assert_eq!(1., Experience::level_gap_multiplier(10, 13));
+    assert_eq!(1., Experience::level_gap_multiplier(10, 7));
+    // The safe zone grows with the player level
+    assert_eq!(1., Experience::level_gap_multiplier(64, 57));
+    assert!(Experience::level_gap_multiplier(10, 3) < 1.);
+
+    // Bonus for higher monsters, capped
+    assert!(Experience::level_gap_multiplier(10, 15) > 1.);
+    assert_eq!(1.5, Experience::level_gap_multiplier(0, 99));
+
+    // Penalty for lower monsters, capped
+    assert!(Experience::level_gap_multiplier(10, 5) < 1.);
+    assert!(Experience::level_gap_multiplier(20, 5) < Experience::level_gap_multiplier(20, 10));
+    assert_eq!(0.05, Experience::level_gap_multiplier(99, 0));
+}
@@ -0,0 +1,112 @@
+use crate::components::{
+    character::{BaseLife, Life},
+    flask::{
+        EquipFlaskEvent, Flask, FlaskAffix, FlaskCharges, FlaskEffect, FlaskKind, PlayerFlasks,
+        UseFlaskEvent,
+    },
+    monster::MonsterDeathEvent,
+    player::Player,
+};
+use crate::in_game::flask_plugin::FlaskPlugin;
+use bevy::prelude::*;
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, FlaskPlugin))
+        .init_resource::<ButtonInput<KeyCode>>()
+        .add_event::<MonsterDeathEvent>();
+    app
+}
+
+/// Spawn a player with 5 life over 20, and a flask in the slot 0
+fn spawn_player_with_flask(app: &mut App, flask: Flask) -> (Entity, Entity) {
+    let player = app
+        .world_mut()
+        .spawn((Player, BaseLife::bundle(20.)))
+        .insert(Life(5.))
+        .id();
+    let flask = app
+        .world_mut()
+        .spawn((flask, FlaskCharges::full(flask.kind.max_charges())))
+        .id();
+    app.world_mut().trigger(EquipFlaskEvent { flask, slot: 0 });
+    app.update();
+    (player, flask)
+}
+
+#[test]
+fn test_flask_charges() {
+    let mut charges = FlaskCharges::full(20);
+    assert!(charges.consume(15));
+    assert!(!charges.consume(15));
+    assert_eq!(5, charges.current);
+    charges.add(100);
+    assert_eq!(20, charges.current);
+}
+
+#[test]
+fn test_player_flasks_slots() {
+    let mut flasks = PlayerFlasks::default();
+    let first = Entity::from_raw(1);
+    let second = Entity::from_raw(2);
+    assert_eq!(None, flasks.set(0, first));
+    assert_eq!(Some(first), flasks.set(0, second));
+    assert_eq!(None, flasks.set(4, first));
+    assert_eq!(vec![second, first], flasks.iter().collect::<Vec<_>>());
+    assert!(flasks.remove(first));
+    assert!(!flasks.remove(first));
+    assert_eq!(None, flasks.get(4));
+}
+
+#[test]
+fn test_use_instant_flask() {
+    let mut app = create_app();
+    let flask = Flask {
+        kind: FlaskKind::Small,
+        affix: Some(FlaskAffix::MovementSpeed(10.)),
+    };
+    let (player, flask) = spawn_player_with_flask(&mut app, flask);
+    assert_eq!(
+        Some(flask),
+        app.world().get::<PlayerFlasks>(player).unwrap().get(0)
+    );
+
+    app.world_mut().trigger(UseFlaskEvent(0));
+    app.update();
+
+    let life = app.world().get::<Life>(player).unwrap();
+    assert_eq!(5. + FlaskKind::Small.life(), **life);
+    let charges = app.world().get::<FlaskCharges>(flask).unwrap();
+    assert_eq!(
+        FlaskKind::Small.max_charges() - FlaskKind::Small.charges_per_use(),
+        charges.current
+    );
+
+    // The effect, with the affix, is a child of the player
+    let mut effects = app
+        .world_mut()
+        .query_filtered::<&ChildOf, With<FlaskEffect>>();
+    let parents = effects
+        .iter(app.world())
+        .map(|c| c.parent())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![player], parents);
+
+    // Not enough charges for another use
+    app.world_mut().trigger(UseFlaskEvent(0));
+    app.world_mut().trigger(UseFlaskEvent(0));
+    app.update();
+    let charges = app.world().get::<FlaskCharges>(flask).unwrap();
+    assert_eq!(0, charges.current);
+}
+
+#[test]
+fn test_over_time_flask_effect() {
+    let effect = FlaskEffect::new(FlaskKind::Medium);
+    let duration = FlaskKind::Medium.recovery_duration().unwrap();
+    assert_eq!(FlaskKind::Medium.life(), effect.life_per_second * duration);
+    assert_eq!(duration, effect.timer.duration().as_secs_f32());
+
+    let effect = FlaskEffect::new(FlaskKind::Small);
+    assert_eq!(0., effect.life_per_second);
+}
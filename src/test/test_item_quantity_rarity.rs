@@ -0,0 +1,142 @@
+use crate::components::{
+    affix::{IncreaseItemQuantity, IncreaseItemRarity},
+    character::Character,
+    damage::DamageSource,
+    equipment::{Amulet, Boots},
+    item::{ItemRarity, ItemRarityProvider, ItemSpawnBundle},
+    loot::{LootBonus, LootTable},
+};
+use crate::in_game::{affix_updates_plugin::AffixUpdatesPlugin, item_plugin::killer_loot_bonus};
+use crate::schedule::{GameState, InGameState};
+use bevy::{asset::ron, ecs::system::RunSystemOnce, prelude::*, state::app::StatesPlugin};
+
+const N_KILLS: usize = 100_000;
+
+const LOOT_TABLE: &str = r#"(
+    rarity: Normal,
+    min_level: 0,
+    drops: 1,
+    weights: (equipment: 1, orb: 0, skill: 0, nothing: 0),
+)"#;
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, AffixUpdatesPlugin))
+        .insert_state(GameState::InGame)
+        .insert_state(InGameState::Running);
+    app
+}
+
+/// Spawn a character with item quantity and rarity affixes, and return its [LootBonus]
+fn character_loot_bonus(app: &mut App) -> LootBonus {
+    let mut rng = rand::rng();
+    let character = app
+        .world_mut()
+        .spawn((
+            Character,
+            IncreaseItemQuantity::default(),
+            IncreaseItemRarity::default(),
+            children![
+                (Amulet::new(1, &mut rng).0, IncreaseItemQuantity(60.)),
+                (Boots::new(1, &mut rng).0, IncreaseItemRarity(100.)),
+                IncreaseItemQuantity(40.),
+                IncreaseItemRarity(50.),
+            ],
+        ))
+        .id();
+
+    app.update();
+
+    let quantity = app.world().get::<IncreaseItemQuantity>(character).unwrap();
+    let rarity = app.world().get::<IncreaseItemRarity>(character).unwrap();
+    LootBonus::new(quantity, rarity)
+}
+
+/// Mean number of drops per kill
+fn mean_drops(bonus: LootBonus) -> f32 {
+    let table: LootTable = ron::from_str(LOOT_TABLE).expect("Valid loot table");
+    let mut rng = rand::rng();
+    let drops: usize = (0..N_KILLS)
+        .map(|_| table.roll(bonus, &mut rng).len())
+        .sum();
+    drops as f32 / N_KILLS as f32
+}
+
+/// Ratio of magic and rare items
+fn magic_or_rare_ratio(bonus: LootBonus) -> f32 {
+    let mut rng = rand::rng();
+    let count = (0..N_KILLS)
+        .map(|_| ItemRarityProvider::gen_with_bonus(bonus.item_rarity, &mut rng))
+        .filter(|rarity| *rarity != ItemRarity::Normal)
+        .count();
+    count as f32 / N_KILLS as f32
+}
+
+#[test]
+fn test_character_loot_bonus() {
+    let mut app = create_app();
+    let bonus = character_loot_bonus(&mut app);
+    assert_eq!(100., bonus.item_quantity);
+    assert_eq!(150., bonus.item_rarity);
+}
+
+#[test]
+fn test_loot_bonus_of_the_killer() {
+    let mut app = create_app();
+    let world = app.world_mut();
+    let player = world
+        .spawn((
+            Character,
+            IncreaseItemQuantity(30.),
+            IncreaseItemRarity(50.),
+        ))
+        .id();
+    let skill = world.spawn_empty().id();
+    // A prop or the biome kills the monster
+    let other = world.spawn_empty().id();
+
+    let mut bonus_of = |source: DamageSource| {
+        world
+            .run_system_once(
+                move |characters: Query<(&IncreaseItemQuantity, &IncreaseItemRarity)>| {
+                    killer_loot_bonus(source, &characters)
+                },
+            )
+            .unwrap()
+    };
+    let bonus = bonus_of(DamageSource::from_skill(player, skill));
+    assert_eq!(30., bonus.item_quantity);
+    assert_eq!(50., bonus.item_rarity);
+
+    // The player bonus is not given to the other kills
+    let bonus = bonus_of(DamageSource::from_character(other));
+    assert_eq!(0., bonus.item_quantity);
+    assert_eq!(0., bonus.item_rarity);
+}
+
+#[test]
+fn test_item_quantity_shifts_drops() {
+    let mut app = create_app();
+    let bonus = character_loot_bonus(&mut app);
+
+    assert_eq!(1.0, mean_drops(LootBonus::default()));
+    // +100% item quantity doubles the drops
+    assert_eq!(2.0, mean_drops(bonus));
+}
+
+#[test]
+fn test_item_rarity_shifts_rarity() {
+    let mut app = create_app();
+    let bonus = character_loot_bonus(&mut app);
+
+    // Normal: 100, Magic: 80, Rare: 50
+    let without_bonus = magic_or_rare_ratio(LootBonus::default());
+    assert!(
+        (without_bonus - 130. / 230.).abs() < 0.02,
+        "{without_bonus}"
+    );
+
+    // +150% item rarity: Normal: 100, Magic: 200, Rare: 125
+    let with_bonus = magic_or_rare_ratio(bonus);
+    assert!((with_bonus - 325. / 425.).abs() < 0.02, "{with_bonus}");
+}
@@ -0,0 +1,35 @@
+use crate::components::ldtk::{LdtkProject, LDTK_PROJECT};
+use bevy::prelude::*;
+
+#[test]
+fn test_load_ldtk_project() {
+    let project = LdtkProject::load(LDTK_PROJECT).unwrap();
+    assert_eq!(2, project.levels.len());
+
+    let level = &project.levels[0];
+    let config = level.config().unwrap();
+    assert_eq!(0, config.monster_level);
+
+    // The player marker has a top left pivot, on the cell (3, 2)
+    let player = level.entities("PlayerInitialPosition").next().unwrap();
+    let cell = Vec2::new(3., (level.px_hei / 16 - 1 - 2) as f32) * 16.;
+    assert_eq!(cell, level.entity_pos(player));
+
+    assert_eq!(6, level.entities("MonsterInitialPosition").count());
+    assert!(level.tiles_layer().is_some());
+}
+
+#[test]
+fn test_ldtk_colliders_are_merged() {
+    let project = LdtkProject::load(LDTK_PROJECT).unwrap();
+    for level in &project.levels {
+        let colliders = level.colliders();
+        assert!(!colliders.is_empty());
+        for rect in colliders {
+            // Rows of cells
+            assert_eq!(16., rect.height());
+            assert!(rect.width() >= 16.);
+            assert_eq!(0., rect.width() % 16.);
+        }
+    }
+}
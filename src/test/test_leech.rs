@@ -0,0 +1,44 @@
+use crate::assert_approx_eq;
+use crate::components::{
+    affix::LifeLeech,
+    character::{Character, Life, LifeLeechPool, MaxLife},
+    damage::Damage,
+};
+use crate::in_game::affix_updates_plugin::AffixUpdatesPlugin;
+use crate::schedule::{GameState, InGameState};
+use bevy::{prelude::*, state::app::StatesPlugin};
+
+#[test]
+fn test_life_leech_recovery_rate() {
+    let leech = LifeLeech(10.);
+    let mut pool = LifeLeechPool::default();
+    pool.add(leech.leech(Damage(50.)));
+    assert_approx_eq!(5., *pool);
+
+    // Recovery is limited by the max leech rate (20% of max life per second)
+    let max_life = MaxLife(10.);
+    let mut life = Life(2.);
+    pool.recover(&mut life, max_life, 1.);
+    assert_approx_eq!(4., *life);
+    assert_approx_eq!(3., *pool);
+    pool.recover(&mut life, max_life, 10.);
+    assert_approx_eq!(7., *life);
+    assert_eq!(0., *pool);
+}
+
+#[test]
+fn test_update_character_life_leech() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, AffixUpdatesPlugin))
+        .insert_state(GameState::InGame)
+        .insert_state(InGameState::Running);
+
+    let character = app
+        .world_mut()
+        .spawn((Character, children![LifeLeech(2.), LifeLeech(3.)]))
+        .id();
+    app.update();
+
+    let leech = app.world().get::<LifeLeech>(character).unwrap();
+    assert_eq!(5., **leech);
+}
@@ -0,0 +1,179 @@
+use crate::components::{
+    loot::{LootBonus, LootKind, LootTables},
+    monster::MonsterRarity,
+};
+use crate::config::load_ron_file;
+use bevy::asset::ron;
+
+const N_KILLS: usize = 100_000;
+
+const LOOT_TABLES: &str = r#"LootTables([
+    (
+        rarity: Normal,
+        min_level: 0,
+        drops: 1,
+        weights: (equipment: 30, orb: 30, skill: 30, nothing: 10),
+    ),
+    (
+        rarity: Normal,
+        min_level: 10,
+        drops: 1,
+        weights: (equipment: 50, orb: 20, skill: 20, flask: 10, nothing: 0),
+    ),
+    (
+        monster: Some("Skull"),
+        rarity: Normal,
+        min_level: 0,
+        drops: 1,
+        weights: (equipment: 0, orb: 100, skill: 0, nothing: 0),
+    ),
+    (
+        rarity: Rare,
+        min_level: 0,
+        drops: 2,
+        weights: (equipment: 50, orb: 0, skill: 50, nothing: 0),
+        bonus: (item_quantity: 50.),
+        guaranteed_currency: true,
+    ),
+])"#;
+
+/// Count of each kind of loot over `N_KILLS` kills
+#[derive(Default)]
+struct LootStats {
+    equipment: usize,
+    orb: usize,
+    skill: usize,
+    flask: usize,
+    map: usize,
+    drops: usize,
+    kills_without_orb: usize,
+}
+
+impl LootStats {
+    fn simulate(
+        tables: &LootTables,
+        monster: &str,
+        rarity: MonsterRarity,
+        level: u16,
+        bonus: LootBonus,
+    ) -> Self {
+        let mut rng = rand::rng();
+        let table = tables.find(monster, rarity, level).expect("A loot table");
+        let mut stats = LootStats::default();
+        for _ in 0..N_KILLS {
+            let loot = table.roll(bonus, &mut rng);
+            stats.drops += loot.len();
+            if !loot.contains(&LootKind::Orb) {
+                stats.kills_without_orb += 1;
+            }
+            for kind in loot {
+                match kind {
+                    LootKind::Equipment => stats.equipment += 1,
+                    LootKind::Orb => stats.orb += 1,
+                    LootKind::Skill => stats.skill += 1,
+                    LootKind::Flask => stats.flask += 1,
+                    LootKind::Map => stats.map += 1,
+                }
+            }
+        }
+        stats
+    }
+}
+
+fn per_kill(count: usize) -> f32 {
+    count as f32 / N_KILLS as f32
+}
+
+fn assert_near(expected: f32, value: f32) {
+    assert!(
+        (expected - value).abs() < 0.02,
+        "expected: {expected}, value: {value}"
+    );
+}
+
+fn loot_tables() -> LootTables {
+    ron::from_str(LOOT_TABLES).expect("Valid loot tables")
+}
+
+#[test]
+fn test_loot_tables_asset_is_valid() {
+    let tables: LootTables = load_ron_file("loot_tables.ron").expect("Valid loot tables");
+    assert!(tables.find("Cyclope", MonsterRarity::Normal, 0).is_some());
+}
+
+#[test]
+fn test_loot_distribution() {
+    let tables = loot_tables();
+    let stats = LootStats::simulate(
+        &tables,
+        "Cyclope",
+        MonsterRarity::Normal,
+        0,
+        LootBonus::default(),
+    );
+
+    assert_near(0.3, per_kill(stats.equipment));
+    assert_near(0.3, per_kill(stats.orb));
+    assert_near(0.3, per_kill(stats.skill));
+    // The flask and map weights are 0 by default
+    assert_eq!(0, stats.flask);
+    assert_eq!(0, stats.map);
+    assert_near(0.9, per_kill(stats.drops));
+}
+
+#[test]
+fn test_loot_table_by_level_and_kind() {
+    let tables = loot_tables();
+
+    let stats = LootStats::simulate(
+        &tables,
+        "Cyclope",
+        MonsterRarity::Normal,
+        15,
+        LootBonus::default(),
+    );
+    assert_near(0.5, per_kill(stats.equipment));
+    assert_near(0.1, per_kill(stats.flask));
+    assert_near(1.0, per_kill(stats.drops));
+
+    let stats = LootStats::simulate(
+        &tables,
+        "Skull",
+        MonsterRarity::Normal,
+        15,
+        LootBonus::default(),
+    );
+    assert_eq!(N_KILLS, stats.orb);
+}
+
+#[test]
+fn test_guaranteed_currency_and_quantity() {
+    let tables = loot_tables();
+    let stats = LootStats::simulate(
+        &tables,
+        "Cyclope",
+        MonsterRarity::Rare,
+        0,
+        LootBonus::default(),
+    );
+
+    // The table never drops orbs by itself
+    assert_eq!(0, stats.kills_without_orb);
+    assert_eq!(N_KILLS, stats.orb);
+    // 2 drops * 150% + the guaranteed orb
+    assert_near(3.0, per_kill(stats.equipment + stats.skill));
+    assert_near(4.0, per_kill(stats.drops));
+
+    // The killer bonus is added to the table bonus
+    let stats = LootStats::simulate(
+        &tables,
+        "Cyclope",
+        MonsterRarity::Rare,
+        0,
+        LootBonus {
+            item_quantity: 50.,
+            item_rarity: 0.,
+        },
+    );
+    assert_near(4.0, per_kill(stats.equipment + stats.skill));
+}
@@ -0,0 +1,85 @@
+use crate::components::{
+    affix::MoreLife,
+    equipment::Equipment,
+    item::{ItemLevel, ItemRarity},
+    loot_filter::{LootFilter, LootFilterAction, LootFilterRule},
+    orb::Orb,
+};
+use bevy::{asset::ron, prelude::*};
+
+fn rule(rule: &str) -> LootFilterRule {
+    ron::from_str(rule).expect("Valid loot filter rule")
+}
+
+#[test]
+fn test_loot_filter_rule_matches() {
+    let mut world = World::new();
+    let helmet = world
+        .spawn((
+            Equipment::Helmet,
+            ItemRarity::Rare,
+            ItemLevel(12),
+            MoreLife(10.),
+        ))
+        .id();
+    let boots = world
+        .spawn((Equipment::Boots, ItemRarity::Magic, ItemLevel(3)))
+        .id();
+    let orb = world.spawn(Orb::Chaos).id();
+    let matches = |rule: &LootFilterRule, item: Entity| rule.matches(&world.entity(item));
+
+    let any = rule("(action: Hide)");
+    assert!(matches(&any, helmet));
+    assert!(matches(&any, orb));
+
+    let equipment = rule("(class: Some(Equipment(None)), action: Hide)");
+    assert!(matches(&equipment, helmet));
+    assert!(matches(&equipment, boots));
+    assert!(!matches(&equipment, orb));
+    let chaos = rule("(class: Some(Orb(Some(Chaos))), action: Hide)");
+    assert!(matches(&chaos, orb));
+    assert!(!matches(&chaos, helmet));
+
+    let rare = rule("(rarity: Some([Rare]), action: Hide)");
+    assert!(matches(&rare, helmet));
+    assert!(!matches(&rare, boots));
+    // An orb has no rarity
+    assert!(!matches(&rare, orb));
+
+    let levels = rule("(min_level: Some(5), max_level: Some(12), action: Hide)");
+    assert!(matches(&levels, helmet));
+    assert!(!matches(&levels, boots));
+
+    let life = rule("(affixes: [MoreLife], action: Hide)");
+    assert!(matches(&life, helmet));
+    assert!(!matches(&life, boots));
+
+    // All the conditions have to match
+    let rare_boots =
+        rule("(class: Some(Equipment(Some(Boots))), rarity: Some([Rare]), action: Hide)");
+    assert!(!matches(&rare_boots, helmet));
+    assert!(!matches(&rare_boots, boots));
+}
+
+#[test]
+fn test_loot_filter_first_matching_rule() {
+    let mut world = World::new();
+    let orb = world.spawn(Orb::Chaos).id();
+    let boots = world.spawn((Equipment::Boots, ItemRarity::Normal)).id();
+
+    let loot_filter: LootFilter = ron::from_str(
+        r#"LootFilter([
+            (class: Some(Orb(None)), action: Highlight((label: Some("Orb")))),
+            (action: Hide),
+        ])"#,
+    )
+    .expect("Valid loot filter");
+    assert!(matches!(
+        loot_filter.action(&world.entity(orb)),
+        Some(LootFilterAction::Highlight(_))
+    ));
+    assert!(matches!(
+        loot_filter.action(&world.entity(boots)),
+        Some(LootFilterAction::Hide)
+    ));
+}
@@ -0,0 +1,135 @@
+use crate::components::{
+    map_coords::MapCoords,
+    world_map::{ProceduralWorldMap, WorldMapConfig},
+};
+use bevy::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const TILE_SIZE: u32 = 16;
+const CHUNK_SIZE: u32 = 20;
+
+fn coords() -> MapCoords {
+    MapCoords::new(TILE_SIZE, CHUNK_SIZE)
+}
+
+/// Random tiles, and all the tiles around the origin and the chunk borders
+fn tiles() -> impl Iterator<Item = IVec2> {
+    let mut rng = StdRng::seed_from_u64(7);
+    let random = (0..2000).map(move |_| {
+        IVec2::new(
+            rng.random_range(-2_000..2_000),
+            rng.random_range(-2_000..2_000),
+        )
+    });
+    let around_origin = (-45..45).flat_map(|y| (-45..45).map(move |x| IVec2::new(x, y)));
+    random.chain(around_origin)
+}
+
+#[test]
+fn test_tile_chunk_round_trip() {
+    let coords = coords();
+    let chunk_size = CHUNK_SIZE as i32;
+    for tile in tiles() {
+        let chunk = coords.tile_to_chunk(tile);
+        let local = coords.tile_in_chunk(tile);
+        assert!((0..chunk_size).contains(&local.x), "{tile}");
+        assert!((0..chunk_size).contains(&local.y), "{tile}");
+        assert_eq!(coords.chunk_to_tile(chunk) + local, tile);
+
+        let index = coords.tile_index(tile);
+        assert!(index < coords.chunk_len());
+        assert_eq!(coords.index_to_tile(chunk, index), tile);
+    }
+}
+
+#[test]
+fn test_world_tile_round_trip() {
+    let coords = coords();
+    let half_tile = TILE_SIZE as f32 / 2.;
+    for tile in tiles() {
+        let center = coords.tile_to_world(tile);
+        assert_eq!(coords.world_to_tile(center), tile);
+        // The tile covers from its center - half a tile (included)
+        // to its center + half a tile (excluded)
+        for offset in [
+            Vec2::splat(-half_tile),
+            Vec2::splat(half_tile - 0.01),
+            Vec2::new(-half_tile, half_tile - 0.01),
+        ] {
+            assert_eq!(coords.world_to_tile(center + offset), tile, "{offset}");
+        }
+        assert_eq!(
+            coords.world_to_tile(center + Vec2::splat(half_tile)),
+            tile + IVec2::ONE
+        );
+        assert_eq!(coords.world_to_chunk(center), coords.tile_to_chunk(tile));
+    }
+}
+
+#[test]
+fn test_chunk_boundaries() {
+    let coords = coords();
+    let chunk_world_size = (TILE_SIZE * CHUNK_SIZE) as f32;
+    let half_tile = TILE_SIZE as f32 / 2.;
+    for chunk in -4..4 {
+        // The chunk starts half a tile before the center of its first tile
+        let start = chunk as f32 * chunk_world_size - half_tile;
+        assert_eq!(
+            coords.world_to_chunk(Vec2::splat(start)),
+            IVec2::splat(chunk)
+        );
+        assert_eq!(
+            coords.world_to_chunk(Vec2::splat(start - 0.01)),
+            IVec2::splat(chunk - 1)
+        );
+        assert_eq!(
+            coords.world_to_chunk(Vec2::splat(start + chunk_world_size - 0.01)),
+            IVec2::splat(chunk)
+        );
+    }
+
+    // Exact multiples of the chunk size
+    assert_eq!(
+        coords.world_to_chunk(Vec2::new(-320., 0.)),
+        IVec2::new(-1, 0)
+    );
+    assert_eq!(
+        coords.world_to_chunk(Vec2::new(0., -640.)),
+        IVec2::new(0, -2)
+    );
+    assert_eq!(coords.world_to_chunk(Vec2::new(320., 320.)), IVec2::ONE);
+    assert_eq!(
+        coords.tile_to_chunk(IVec2::new(-20, -21)),
+        IVec2::new(-1, -2)
+    );
+    assert_eq!(coords.tile_to_chunk(IVec2::new(-1, 19)), IVec2::new(-1, 0));
+}
+
+#[test]
+fn test_chunk_center() {
+    let coords = coords();
+    for tile in tiles() {
+        let chunk = coords.tile_to_chunk(tile);
+        let center = coords.chunk_center(chunk);
+        assert_eq!(coords.world_to_chunk(center), chunk);
+        // All the tiles of the chunk are around its center
+        let max_distance = (CHUNK_SIZE * TILE_SIZE) as f32 / 2.;
+        let offset = (coords.tile_to_world(tile) - center).abs();
+        assert!(offset.max_element() < max_distance, "{tile}");
+    }
+}
+
+#[test]
+fn test_remove_chunk_measures_from_the_chunk_center() {
+    let config = WorldMapConfig::default();
+    let despawn_distance = config.despawn_distance();
+    let mut world_map = ProceduralWorldMap::with_seed(config, 1);
+    let coords = world_map.coords();
+    for chunk in [IVec2::ZERO, IVec2::new(-1, 0), IVec2::new(-3, -5)] {
+        let center = coords.chunk_center(chunk);
+        let near = center + Vec2::new(despawn_distance - 1., 0.);
+        assert!(!world_map.remove_chunk_if_out_of_bound(chunk, near));
+        let far = center - Vec2::new(0., despawn_distance + 1.);
+        assert!(world_map.remove_chunk_if_out_of_bound(chunk, far));
+    }
+}
@@ -0,0 +1,72 @@
+use crate::assert_approx_eq;
+use crate::components::{
+    character::{BaseLife, Life, MaxLife},
+    map_item::{ActiveMap, MapItem, MapModifier},
+    monster::Monster,
+};
+use crate::in_game::{affix_updates_plugin::AffixUpdatesPlugin, monster_plugin::update_monster};
+use crate::schedule::{GameState, InGameState};
+use bevy::{prelude::*, state::app::StatesPlugin};
+
+#[test]
+fn test_map_rules() {
+    let map = MapItem {
+        tier: 2,
+        modifiers: vec![
+            MapModifier::MonsterLife(30),
+            MapModifier::LessPlayerRegen(40),
+            MapModifier::ItemQuantity(15),
+        ],
+    };
+    let rules = map.rules();
+    assert_eq!(30., rules.monster_life);
+    assert_eq!(0, rules.extra_rare_packs);
+    assert_eq!(15., rules.item_quantity);
+    assert_approx_eq!(0.6, rules.player_regen_multiplier());
+
+    let normal_map = MapItem {
+        tier: 1,
+        modifiers: vec![],
+    };
+    assert_eq!(1., normal_map.rules().player_regen_multiplier());
+}
+
+#[test]
+fn test_map_is_completed_after_goal_kills() {
+    let map = MapItem {
+        tier: 1,
+        modifiers: vec![MapModifier::ExtraRarePacks(2)],
+    };
+    let mut active_map = ActiveMap::new(&map, 3);
+    assert_eq!(2, active_map.rules.extra_rare_packs);
+    assert!(!active_map.add_kills(map.goal() - 1));
+    assert!(active_map.add_kills(1));
+}
+
+#[test]
+fn test_monsters_spawn_with_the_life_of_the_map() {
+    let map = MapItem {
+        tier: 1,
+        modifiers: vec![MapModifier::MonsterLife(30)],
+    };
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, AffixUpdatesPlugin))
+        .insert_state(GameState::InGame)
+        .insert_state(InGameState::Running)
+        .insert_resource(ActiveMap::new(&map, 1))
+        .add_observer(update_monster);
+
+    let monster = app.world_mut().spawn((Monster, BaseLife::bundle(10.))).id();
+    app.update();
+
+    let max_life = app.world().get::<MaxLife>(monster).unwrap();
+    assert_approx_eq!(13., **max_life);
+    let life = app.world().get::<Life>(monster).unwrap();
+    assert_approx_eq!(13., **life);
+
+    // The life is not restored afterward
+    app.world_mut().get_mut::<Life>(monster).unwrap().0 = 5.;
+    app.update();
+    let life = app.world().get::<Life>(monster).unwrap();
+    assert_approx_eq!(5., **life);
+}
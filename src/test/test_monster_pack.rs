@@ -0,0 +1,66 @@
+use crate::components::{
+    character::{CharacterDiedEvent, CharacterDyingEvent, MovementAction},
+    damage::DamageSource,
+    monster::{
+        Monster, MonsterDeathEvent, MonsterKind, MonsterLevel, PackAura, PackFollower, PackLeader,
+        Scattered,
+    },
+};
+use crate::in_game::monster_plugin::{monster_dying, scatter_pack};
+use bevy::prelude::*;
+
+#[test]
+fn test_pack_scatters_when_its_leader_dies() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<MonsterDeathEvent>()
+        .add_event::<CharacterDiedEvent>();
+
+    let world = app.world_mut();
+    let mut leader = world.spawn((
+        Monster,
+        PackLeader,
+        MonsterLevel(1),
+        MonsterKind(0),
+        Transform::default(),
+    ));
+    leader.observe(monster_dying).observe(scatter_pack);
+    let leader = leader.id();
+    let follower = world
+        .spawn((
+            Monster,
+            PackFollower(leader),
+            Transform::from_xyz(20., 0., 0.),
+            children![PackAura],
+        ))
+        .id();
+    let other_leader = world.spawn((Monster, PackLeader)).id();
+    let other_follower = world
+        .spawn((Monster, PackFollower(other_leader), children![PackAura]))
+        .id();
+
+    let player = world.spawn_empty().id();
+    world.trigger_targets(
+        CharacterDyingEvent {
+            killer: DamageSource::from_character(player),
+        },
+        leader,
+    );
+    world.flush();
+
+    // The followers of the dead leader loose the aura and flee
+    let follower = world.entity(follower);
+    assert!(follower.contains::<Scattered>());
+    assert!(!follower.contains::<PackFollower>());
+    assert!(matches!(
+        follower.get::<MovementAction>(),
+        Some(MovementAction::GoTo(_))
+    ));
+    assert!(follower.get::<Children>().is_none_or(|c| c.is_empty()));
+
+    // The other pack is not affected
+    let other_follower = world.entity(other_follower);
+    assert!(!other_follower.contains::<Scattered>());
+    assert!(other_follower.contains::<PackFollower>());
+    assert_eq!(1, other_follower.get::<Children>().unwrap().len());
+}
@@ -0,0 +1,96 @@
+use crate::components::passive_tree::PassiveTree;
+use crate::config::load_ron_file;
+use bevy::asset::ron;
+use std::collections::HashSet;
+
+/// ```text
+///     1 - 2
+///    /
+///   0 - 3 - 4
+///        \ /
+///         5
+/// ```
+const PASSIVE_TREE: &str = r#"PassiveTree(
+    nodes: [
+        (name: "Start", pos: (0., 0.), links: [1, 3]),
+        (name: "A", pos: (10., -10.), links: [2], affixes: [MoreLife(2.)]),
+        (name: "B", pos: (20., -10.), affixes: [IncreaseDamage(10.)]),
+        (name: "C", pos: (10., 0.), links: [4, 5], affixes: [PierceChance(5.)]),
+        (name: "D", pos: (20., 0.), affixes: [IncreaseAreaOfEffect(10.)]),
+        (name: "E", pos: (15., 10.), links: [4], affixes: [MoreDamage(2.)]),
+    ],
+)"#;
+
+fn passive_tree() -> PassiveTree {
+    ron::from_str(PASSIVE_TREE).expect("Valid passive tree")
+}
+
+#[test]
+fn test_passive_tree_asset_is_valid() {
+    let tree: PassiveTree = load_ron_file("passive_tree.ron").expect("Valid passive tree");
+    assert!(!tree.nodes.is_empty());
+    for (from, to) in tree.links() {
+        assert!(tree.node(from).is_some() && tree.node(to).is_some());
+    }
+
+    // The tree is connected: all nodes can be allocated from the start node
+    // of any class
+    let mut allocated = HashSet::from([0]);
+    while let Some(id) = (0..tree.nodes.len()).find(|&id| tree.can_allocate(&allocated, id)) {
+        allocated.insert(id);
+    }
+    assert_eq!(tree.nodes.len(), allocated.len());
+}
+
+#[test]
+fn test_passive_tree_neighbors() {
+    let tree = passive_tree();
+    let mut neighbors = tree.neighbors(3).collect::<Vec<_>>();
+    neighbors.sort();
+    assert_eq!(vec![0, 4, 5], neighbors);
+    assert_eq!(vec![1], tree.neighbors(2).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_passive_tree_allocate_adjacent_only() {
+    let tree = passive_tree();
+    let allocated = HashSet::from([0]);
+    assert!(tree.can_allocate(&allocated, 1));
+    assert!(tree.can_allocate(&allocated, 3));
+    assert!(!tree.can_allocate(&allocated, 0));
+    // The start node is only allocated through the character class
+    assert!(!tree.can_allocate(&HashSet::new(), 0));
+    assert!(!tree.can_allocate(&allocated, 2));
+    assert!(!tree.can_allocate(&allocated, 4));
+    assert!(!tree.can_allocate(&allocated, 42));
+
+    // Links are bidirectional: 4 is reachable from 5
+    let allocated = HashSet::from([0, 3, 5]);
+    assert!(tree.can_allocate(&allocated, 4));
+}
+
+#[test]
+fn test_passive_tree_refund_keeps_tree_connected() {
+    let tree = passive_tree();
+    let allocated = HashSet::from([0, 1, 2, 3, 4, 5]);
+
+    // The start node is never refunded
+    assert!(!tree.can_refund(&allocated, 0, 0));
+    // Leaves can be refunded
+    assert!(tree.can_refund(&allocated, 0, 2));
+    // 1 is required by 2
+    assert!(!tree.can_refund(&allocated, 0, 1));
+    // 3 is required by 4 and 5
+    assert!(!tree.can_refund(&allocated, 0, 3));
+    // 4 and 5 are linked to 3, so each of them can be refunded
+    assert!(tree.can_refund(&allocated, 0, 4));
+    assert!(tree.can_refund(&allocated, 0, 5));
+    // A node which is not allocated can't be refunded
+    assert!(!tree.can_refund(&HashSet::from([0, 3]), 0, 4));
+
+    // With another start node
+    let allocated = HashSet::from([0, 1, 3]);
+    assert!(tree.can_refund(&allocated, 3, 1));
+    assert!(!tree.can_refund(&allocated, 3, 0));
+    assert!(!tree.can_refund(&allocated, 3, 3));
+}
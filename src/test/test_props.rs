@@ -0,0 +1,41 @@
+use crate::components::{
+    prop::PropKind,
+    world_map::{ProceduralWorldMap, WorldMapConfig},
+};
+use bevy::prelude::*;
+
+fn props(world_map: &mut ProceduralWorldMap, chunk_pos: IVec2) -> Vec<(PropKind, usize, Vec2)> {
+    world_map
+        .chunk_props(chunk_pos)
+        .into_iter()
+        .map(|(prop, pos)| (prop.kind, prop.index, pos))
+        .collect()
+}
+
+#[test]
+fn test_props_are_generated_from_seed() {
+    let mut world_map = ProceduralWorldMap::with_seed(WorldMapConfig::default(), 42);
+    let mut same_seed_map = ProceduralWorldMap::with_seed(WorldMapConfig::default(), 42);
+    for chunk_pos in [IVec2::new(0, 0), IVec2::new(3, -2), IVec2::new(-5, 7)] {
+        let chunk_props = props(&mut world_map, chunk_pos);
+        assert_eq!(chunk_props, props(&mut world_map, chunk_pos));
+        assert_eq!(chunk_props, props(&mut same_seed_map, chunk_pos));
+    }
+}
+
+#[test]
+fn test_destroyed_prop_is_not_generated_again() {
+    let mut world_map = ProceduralWorldMap::with_seed(WorldMapConfig::default(), 7);
+    let chunk_pos = (0..100)
+        .map(|x| IVec2::new(x, 0))
+        .find(|&pos| !world_map.chunk_props(pos).is_empty())
+        .expect("A chunk should have props");
+
+    let (prop, _) = world_map.chunk_props(chunk_pos)[0];
+    assert!(world_map.destroy_prop(&prop));
+    assert!(!world_map.destroy_prop(&prop));
+    assert!(world_map
+        .chunk_props(chunk_pos)
+        .iter()
+        .all(|(other, _)| other.index != prop.index));
+}
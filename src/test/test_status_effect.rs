@@ -0,0 +1,61 @@
+use crate::components::status_effect::{AddStatusEffectEvent, StatusEffect};
+use crate::in_game::status_effect_plugin::StatusEffectPlugin;
+use bevy::prelude::*;
+use std::time::Duration;
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatusEffectPlugin));
+    app
+}
+
+fn status_effects(app: &mut App) -> Vec<StatusEffect> {
+    app.world_mut()
+        .query::<&StatusEffect>()
+        .iter(app.world())
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn test_status_effect_stacks() {
+    let mut effect = StatusEffect::new("Test", 0, Duration::from_secs(2)).with_max_stacks(2);
+    effect.tick(Duration::from_secs(1));
+    assert_eq!(1., effect.remaining_secs());
+
+    effect.stack(Duration::from_secs(3));
+    assert_eq!(2, effect.stacks);
+    assert_eq!(3., effect.remaining_secs());
+
+    effect.stack(Duration::from_secs(3));
+    assert_eq!(2, effect.stacks);
+
+    effect.tick(Duration::from_secs(3));
+    assert!(effect.finished());
+}
+
+#[test]
+fn test_add_same_status_effect() {
+    let mut app = create_app();
+    let character = app.world_mut().spawn_empty().id();
+    let effect = || StatusEffect::new("Test", 0, Duration::from_secs(2)).with_max_stacks(3);
+
+    app.world_mut()
+        .trigger_targets(AddStatusEffectEvent(effect()), character);
+    app.update();
+    app.world_mut()
+        .trigger_targets(AddStatusEffectEvent(effect()), character);
+    app.update();
+    let effects = status_effects(&mut app);
+    assert_eq!(1, effects.len());
+    assert_eq!(2, effects[0].stacks);
+
+    app.world_mut().trigger_targets(
+        AddStatusEffectEvent(StatusEffect::new("Other", 0, Duration::from_secs(2))),
+        character,
+    );
+    app.update();
+    assert_eq!(2, status_effects(&mut app).len());
+    let children = app.world().get::<Children>(character).unwrap();
+    assert_eq!(2, children.len());
+}
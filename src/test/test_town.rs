@@ -0,0 +1,23 @@
+use crate::components::town::Stash;
+use bevy::prelude::*;
+
+#[test]
+fn test_stash_add_and_remove() {
+    let mut world = World::new();
+    let item1 = world.spawn_empty().id();
+    let item2 = world.spawn_empty().id();
+
+    let mut stash = Stash::default();
+    assert!(stash.add_at(item1, 5));
+    assert!(!stash.add_at(item2, 5));
+    assert!(stash.add_at(item2, 0));
+    assert_eq!(Some(item2), stash.at(0));
+    assert_eq!(Some(item1), stash.at(5));
+    assert!(stash.contains(item1));
+
+    assert!(stash.remove(item1));
+    assert!(!stash.remove(item1));
+    assert!(!stash.contains(item1));
+    assert_eq!(None, stash.at(5));
+    assert_eq!(None, stash.at(Stash::len()));
+}
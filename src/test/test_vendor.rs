@@ -0,0 +1,65 @@
+use crate::components::{
+    item::ItemRarity,
+    orb::Orb,
+    vendor::{item_value, orbs_for_value, VendorOffer, CURRENCY_RECIPES},
+};
+
+#[test]
+fn test_item_value() {
+    assert_eq!(2 + 8, item_value(ItemRarity::Magic, 40, &[]));
+    // tier 1 => 4, tier 3 => 2, tier 6 => 0
+    assert_eq!(2 + 8 + 4, item_value(ItemRarity::Magic, 40, &[1, 6]));
+    assert_eq!(4 + 10 + 2, item_value(ItemRarity::Rare, 50, &[3, 6]));
+    // The value is limited below the vendor price
+    assert_eq!(0, item_value(ItemRarity::Normal, 0, &[]));
+    assert_eq!(2, item_value(ItemRarity::Normal, 20, &[]));
+    assert_eq!(2, item_value(ItemRarity::Magic, 0, &[1, 3]));
+    assert_eq!(14, item_value(ItemRarity::Rare, 40, &[1, 3]));
+}
+
+#[test]
+fn test_item_value_is_below_vendor_price() {
+    let tiers: [&[u8]; 5] = [&[], &[1], &[5, 6], &[1, 1], &[1, 1, 1, 1, 1, 1]];
+    for rarity in [ItemRarity::Normal, ItemRarity::Magic, ItemRarity::Rare] {
+        for ilevel in 0..=100 {
+            let price = VendorOffer::price(rarity, ilevel).value();
+            for tiers in tiers {
+                assert!(
+                    item_value(rarity, ilevel, tiers) < price,
+                    "{rarity:?} item of level {ilevel} with tiers {tiers:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_orbs_for_value() {
+    assert!(orbs_for_value(0).is_empty());
+    assert_eq!(
+        vec![Orb::Transmutation, Orb::Transmutation],
+        orbs_for_value(2)
+    );
+    assert_eq!(
+        vec![Orb::Chaos, Orb::Alteration, Orb::Transmutation],
+        orbs_for_value(13)
+    );
+    let value = orbs_for_value(42).iter().map(Orb::value).sum::<u16>();
+    assert_eq!(42, value);
+}
+
+#[test]
+fn test_vendor_prices() {
+    let price = VendorOffer::price(ItemRarity::Normal, 12);
+    assert_eq!(Orb::Transmutation, price.orb);
+    assert_eq!(2, price.count);
+    assert_eq!(
+        Orb::Alteration,
+        VendorOffer::price(ItemRarity::Magic, 0).orb
+    );
+
+    // A recipe never creates value
+    for recipe in CURRENCY_RECIPES {
+        assert!(recipe.input.orb.value() * recipe.input.count >= recipe.output.value());
+    }
+}
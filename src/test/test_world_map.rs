@@ -0,0 +1,72 @@
+use crate::components::world_map::{ProceduralWorldMap, WorldMapConfig};
+use bevy::prelude::*;
+
+#[test]
+fn test_chunks_cache_is_limited() {
+    let config = WorldMapConfig {
+        chunks_cache_size: 8,
+        ..Default::default()
+    };
+    let mut world_map = ProceduralWorldMap::with_seed(config, 3);
+    let origin_props = world_map
+        .chunk_props(IVec2::ZERO)
+        .into_iter()
+        .map(|(prop, pos)| (prop.kind, pos))
+        .collect::<Vec<_>>();
+
+    for x in 1..50 {
+        world_map.chunk_props(IVec2::new(x, x % 3));
+        assert!(world_map.cached_chunks() <= 8);
+    }
+
+    // The evicted chunk is generated again identically
+    let props = world_map
+        .chunk_props(IVec2::ZERO)
+        .into_iter()
+        .map(|(prop, pos)| (prop.kind, pos))
+        .collect::<Vec<_>>();
+    assert_eq!(origin_props, props);
+}
+
+#[test]
+fn test_only_revealed_tiles_are_on_the_minimap() {
+    let config = WorldMapConfig {
+        chunks_cache_size: 4,
+        ..Default::default()
+    };
+    let mut world_map = ProceduralWorldMap::with_seed(config, 5);
+    let chunk_pos = IVec2::new(-2, 3);
+    assert_eq!(world_map.revealed_tiles_kind(chunk_pos).count(), 0);
+
+    world_map.explore_chunk(chunk_pos);
+    assert!(world_map.is_explored(chunk_pos));
+    assert_eq!(world_map.revealed_tiles_kind(chunk_pos).count(), 0);
+
+    // Reveal the 5 tiles of a cross around the first tile of the chunk
+    world_map.reveal(IVec2::new(-40, 60), 1);
+    let tiles = world_map.revealed_tiles_kind(chunk_pos).collect::<Vec<_>>();
+    let indexes = tiles.iter().map(|(index, _)| *index).collect::<Vec<_>>();
+    assert_eq!(indexes, vec![0, 1, 20]);
+    assert!(world_map.is_explored(IVec2::new(-3, 3)));
+    assert!(world_map.is_explored(IVec2::new(-2, 2)));
+    assert!(!world_map.is_explored(IVec2::new(-3, 2)));
+
+    // The fog only changes when new tiles are revealed
+    let revision = world_map.fog_revision();
+    world_map.reveal(IVec2::new(-40, 60), 1);
+    assert_eq!(revision, world_map.fog_revision());
+    world_map.reveal(IVec2::new(-39, 61), 1);
+    assert_ne!(revision, world_map.fog_revision());
+    let tiles = world_map.revealed_tiles_kind(chunk_pos).collect::<Vec<_>>();
+
+    // The revealed tiles are kept when the chunk is evicted from the cache
+    for x in 0..10 {
+        world_map.chunk_props(IVec2::new(x, 0));
+    }
+    let fog = world_map.chunk_fog(chunk_pos).expect("explored");
+    assert!(fog.is_revealed(0) && !fog.is_revealed(2));
+    assert_eq!(
+        world_map.revealed_tiles_kind(chunk_pos).collect::<Vec<_>>(),
+        tiles
+    );
+}
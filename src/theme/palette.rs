@@ -20,3 +20,6 @@ pub const BUTTON_PRESSED_BACKGROUND: Color = Color::srgb(0.239, 0.286, 0.600);
 
 pub const POPUP_BACKGROUND: Color = Color::srgb(0.25, 0.25, 0.25);
 pub const POPUP_BORDER: Color = Color::BLACK;
+
+/// Text color of an unmet item requirement
+pub const UNMET_REQUIREMENT_TEXT: Color = Color::srgb(0.9, 0.2, 0.2);
@@ -1,11 +1,13 @@
 use crate::{
     components::{
         equipment::{Amulet, BodyArmour, Boots, Helmet, Weapon},
+        flask::Flask,
         inventory::PlayerEquipmentChanged,
         item::{
             Item, ItemAssets, ItemEntity, ItemImage, ItemLocation, ItemLocationAccept,
-            ItemLocationAcceptAll, ItemTileIndex,
+            ItemLocationAcceptAll, ItemLocationLocked, ItemTileIndex,
         },
+        map_item::MapItem,
         player::{Player, PlayerAction, PlayerBooks},
         skills::{SkillBook, SkillBookLocation},
     },
@@ -30,6 +32,8 @@ impl Plugin for ItemLocationPlugin {
             .add_observer(show_location_borders_filtered::<Weapon>)
             .add_observer(show_location_borders_filtered::<Amulet>)
             .add_observer(show_location_borders_filtered::<SkillBook>)
+            .add_observer(show_location_borders_filtered::<Flask>)
+            .add_observer(show_location_borders_filtered::<MapItem>)
             .add_observer(hide_location_borders)
             .add_observer(update_skills_location);
     }
@@ -126,7 +130,7 @@ fn hide_location_borders(
 
 fn on_drag_start_item(
     trigger: Trigger<Pointer<DragStart>>,
-    locations: Query<&ItemEntity, With<ItemLocation>>,
+    locations: Query<&ItemEntity, (With<ItemLocation>, Without<ItemLocationLocked>)>,
     items: Query<&ItemTileIndex>,
     cursor: Single<(&mut DraggedEntity, &mut ImageNode), With<DndCursor>>,
     assets: Res<ItemAssets>,
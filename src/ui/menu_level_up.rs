@@ -6,11 +6,11 @@ use crate::{
         upgrade::UpgradeList,
     },
     in_game::back_to_game,
-    schedule::InGameState,
+    schedule::{GameRunningSet, GameState, InGameState},
     theme::widget,
     ui::{
         popup::{Popup, PopupTitle},
-        HSizer, VSizer,
+        HSizer, InGameMenu, VSizer,
     },
 };
 use bevy::{ecs::spawn::SpawnWith, prelude::*};
@@ -19,19 +19,29 @@ pub struct LevelUpMenuPlugin;
 
 impl Plugin for LevelUpMenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(InGameState::LevelUp), spawn_level_up_menu)
+        app.init_resource::<PendingLevelUps>()
+            .add_systems(OnEnter(GameState::InGame), reset_pending_level_ups)
+            .add_systems(OnEnter(InGameState::LevelUp), spawn_level_up_menu)
             .add_systems(
                 OnExit(InGameState::LevelUp),
                 (despawn_all::<LevelUpMenu>, despawn_remaining_upgrades),
             )
             .add_systems(Update, back_to_game.run_if(in_state(InGameState::LevelUp)))
-            .add_observer(enter_level_up_state);
+            .add_systems(
+                Update,
+                enter_level_up_state.in_set(GameRunningSet::UserInput),
+            )
+            .add_observer(add_pending_level_up);
     }
 }
 
 #[derive(Component)]
 struct LevelUpMenu;
 
+/// Number of level ups for which the menu has not been shown yet
+#[derive(Resource, Default, Deref, DerefMut)]
+struct PendingLevelUps(u16);
+
 fn level_up_menu(upgrade_list: &UpgradeList) -> impl Bundle {
     let upgrade_labels = upgrade_list
         .iter()
@@ -75,10 +85,25 @@ fn button_upgrade(label: impl Into<String>, index: usize) -> impl Bundle {
     widget::button(label, observer)
 }
 
+fn reset_pending_level_ups(mut pending: ResMut<PendingLevelUps>) {
+    **pending = 0;
+}
+
+fn add_pending_level_up(_trigger: Trigger<LevelUpEvent>, mut pending: ResMut<PendingLevelUps>) {
+    **pending += 1;
+}
+
+/// Show the menu of a pending level up, like the other menus: when the game
+/// is running, and no other [InGameMenu] is shown
 fn enter_level_up_state(
-    _trigger: Trigger<LevelUpEvent>,
+    menus: Query<&Visibility, With<InGameMenu>>,
+    mut pending: ResMut<PendingLevelUps>,
     mut next_state: ResMut<NextState<InGameState>>,
 ) {
+    if **pending == 0 || menus.iter().any(|v| *v != Visibility::Hidden) {
+        return;
+    }
+    **pending -= 1;
     next_state.set(InGameState::LevelUp);
 }
 
@@ -1,5 +1,6 @@
 use crate::{
     components::despawn_all,
+    config::GameConfig,
     in_game::back_to_game,
     schedule::{GameState, InGameState},
     theme::widget,
@@ -19,7 +20,12 @@ impl Plugin for PausePlugin {
 #[derive(Component)]
 struct PauseMenu;
 
-fn pause_menu() -> impl Bundle {
+fn pause_menu(config: &GameConfig) -> impl Bundle {
+    let damage_numbers = if config.damage_numbers {
+        "Damage numbers: on"
+    } else {
+        "Damage numbers: off"
+    };
     (
         PauseMenu,
         Name::new("PauseMenu"),
@@ -27,19 +33,34 @@ fn pause_menu() -> impl Bundle {
         children![
             widget::popup_title("Pause"),
             widget::button("Back to game", on_back_to_game),
+            widget::button(damage_numbers, on_toggle_damage_numbers),
             widget::button("Quit game", on_quit_game)
         ],
     )
 }
 
-fn spawn_pause_menu(mut commands: Commands) {
-    commands.spawn(pause_menu());
+fn spawn_pause_menu(mut commands: Commands, config: Res<GameConfig>) {
+    commands.spawn(pause_menu(&config));
 }
 
 fn on_back_to_game(_trigger: Trigger<Pointer<Click>>, mut state: ResMut<NextState<InGameState>>) {
     state.set(InGameState::Running);
 }
 
+fn on_toggle_damage_numbers(
+    _trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    mut config: ResMut<GameConfig>,
+    menus: Query<Entity, With<PauseMenu>>,
+) {
+    config.damage_numbers = !config.damage_numbers;
+    // Spawn the menu again to update the button label
+    for menu in &menus {
+        commands.entity(menu).despawn();
+    }
+    commands.spawn(pause_menu(&config));
+}
+
 fn on_quit_game(_trigger: Trigger<Pointer<Click>>, mut state: ResMut<NextState<GameState>>) {
     state.set(GameState::Menu);
 }
@@ -4,14 +4,18 @@ mod menu_level_up;
 mod menu_pause;
 mod menu_player_died;
 mod panel_equipments;
+pub mod panel_flasks;
 mod panel_skills;
 pub mod popup;
 pub mod popup_info;
 pub mod progressbar;
 mod window_inventory;
+mod window_passive_tree;
 mod window_statistics;
+mod window_town;
+mod window_vendor;
 
-pub use plugin::{HSizer, UiPlugins, VSizer};
+pub use plugin::{HSizer, InGameMenu, UiPlugins, VSizer};
 
 mod plugin {
     use super::*;
@@ -37,6 +41,10 @@ mod plugin {
 )]
     pub struct VSizer;
 
+    /// A window opened while playing, which prevents the level up menu to be shown
+    #[derive(Component, Default)]
+    pub struct InGameMenu;
+
     pub struct UiPlugins;
 
     impl Plugin for UiPlugins {
@@ -49,7 +57,11 @@ mod plugin {
                 menu_player_died::PlayerDiedMenuPlugin,
                 window_inventory::InventoryPanelPlugin,
                 panel_skills::SkillsPanelPlugin,
+                panel_flasks::FlasksPanelPlugin,
                 window_statistics::StatsWindowPlugin,
+                window_town::TownWindowPlugin,
+                window_vendor::VendorPanelPlugin,
+                window_passive_tree::PassiveTreeWindowPlugin,
                 panel_equipments::EquipmentPanelPlugin,
                 popup_info::PopupInfoPlugin,
                 button::button_plugin,
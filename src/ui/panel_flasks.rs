@@ -0,0 +1,95 @@
+use crate::{
+    components::{
+        flask::{EquipFlaskEvent, Flask, FlaskSlot, PlayerFlasks, N_FLASK_SLOTS},
+        inventory::PlayerEquipmentChanged,
+        item::{ItemEntity, ItemLocation, ItemLocationAccept},
+        player::Player,
+    },
+    dnd::{DndCursor, DraggedEntity},
+};
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
+
+pub struct FlasksPanelPlugin;
+
+impl Plugin for FlasksPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_drop_item)
+            .add_observer(update_flasks_location);
+    }
+}
+
+///
+/// The flask belt, next to the [super::panel_equipments::EquipmentsPanel]
+///
+#[derive(Component)]
+struct FlasksPanel;
+
+/// Location of the [Flask] of a slot of the belt
+pub fn flask_location(slot: usize) -> impl Bundle {
+    (
+        FlaskSlot(slot),
+        ItemLocation,
+        ItemLocationAccept::<Flask>::new(),
+    )
+}
+
+pub fn flasks_panel() -> impl Bundle {
+    (
+        FlasksPanel,
+        Name::new("FlasksPanel"),
+        Node {
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            padding: UiRect::all(Val::Px(5.)),
+            ..Default::default()
+        },
+        BackgroundColor(Srgba::rgb_u8(40, 40, 40).into()),
+        Children::spawn(SpawnIter((0..N_FLASK_SLOTS).map(|slot| {
+            (
+                Node {
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                children![
+                    (
+                        Text::new(format!("{}:", slot + 1)),
+                        TextFont::from_font_size(12.)
+                    ),
+                    flask_location(slot)
+                ],
+            )
+        }))),
+    )
+}
+
+fn on_drop_item(
+    trigger: Trigger<Pointer<DragDrop>>,
+    mut commands: Commands,
+    locations: Query<&FlaskSlot>,
+    cursor: Single<&DraggedEntity, With<DndCursor>>,
+    flasks: Query<(), With<Flask>>,
+) {
+    if let Some(item_entity) = ***cursor {
+        if flasks.contains(item_entity) {
+            if let Ok(slot) = locations.get(trigger.target()) {
+                commands.trigger(EquipFlaskEvent {
+                    flask: item_entity,
+                    slot: **slot,
+                });
+            }
+        }
+    }
+}
+
+fn update_flasks_location(
+    _trigger: Trigger<PlayerEquipmentChanged>,
+    players: Query<&PlayerFlasks, With<Player>>,
+    mut locations: Query<(&mut ItemEntity, &FlaskSlot)>,
+) {
+    if let Ok(flasks) = players.single() {
+        for (mut item_entity, slot) in &mut locations {
+            item_entity.0 = flasks.get(**slot);
+        }
+    }
+}
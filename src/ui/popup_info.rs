@@ -1,10 +1,16 @@
 use crate::{
-    components::item::{
-        ItemAssets, ItemChanged, ItemDescription, ItemEntity, ItemTileIndex, ItemTitle,
+    components::{
+        character::CharacterAttributes,
+        item::{
+            ItemAssets, ItemChanged, ItemDescription, ItemEntity, ItemTileIndex, ItemTitle,
+            Requirements,
+        },
+        player::Player,
     },
+    theme::palette::UNMET_REQUIREMENT_TEXT,
     ui::popup::Popup,
 };
-use bevy::prelude::*;
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
 
 pub struct PopupInfoPlugin;
 
@@ -29,6 +35,7 @@ fn info_popup(
     img_node: ImageNode,
     title: String,
     description: String,
+    requirements: Vec<(String, bool)>,
 ) -> impl Bundle {
     (
         InfoPopup,
@@ -51,28 +58,74 @@ fn info_popup(
                 TextLayout::new_with_justify(JustifyText::Center)
             ),
             img_node,
+            requirements_text(requirements),
             (Text(description), TextFont::from_font_size(12.))
         ],
     )
 }
 
+/// "Requires ..." text, with unmet requirements in red
+fn requirements_text(requirements: Vec<(String, bool)>) -> impl Bundle {
+    let display = if requirements.is_empty() {
+        Display::None
+    } else {
+        Display::Flex
+    };
+    let spans = requirements
+        .into_iter()
+        .enumerate()
+        .map(|(i, (label, met))| {
+            let label = if i == 0 { label } else { format!(", {label}") };
+            let color = if met {
+                Color::WHITE
+            } else {
+                UNMET_REQUIREMENT_TEXT
+            };
+            (
+                TextSpan(label),
+                TextFont::from_font_size(12.),
+                TextColor(color),
+            )
+        });
+    (
+        Text::new("Requires "),
+        TextFont::from_font_size(12.),
+        Node {
+            display,
+            ..Default::default()
+        },
+        Children::spawn(SpawnIter(spans)),
+    )
+}
+
 fn spawn_popup_on_over_item(
     trigger: Trigger<Pointer<Over>>,
     mut commands: Commands,
     mut item_entities: Query<&ItemEntity>,
-    items: Query<(&ItemTitle, &ItemDescription, &ItemTileIndex)>,
+    items: Query<(
+        &ItemTitle,
+        &ItemDescription,
+        &ItemTileIndex,
+        Option<&Requirements>,
+    )>,
+    players: Query<CharacterAttributes, With<Player>>,
     assets: Res<ItemAssets>,
 ) {
     if let Ok(ItemEntity(Some(item_entity))) = item_entities.get_mut(trigger.target()) {
-        if let Ok((title, description, tile_index)) = items.get(*item_entity) {
+        if let Ok((title, description, tile_index, requirements)) = items.get(*item_entity) {
             let pos = trigger.pointer_location.position;
             let img = assets.image_node(tile_index.0);
+            let requirements = match (requirements, players.single()) {
+                (Some(requirements), Ok(attributes)) => requirements.check(&attributes),
+                _ => vec![],
+            };
             commands.spawn(info_popup(
                 *item_entity,
                 pos,
                 img,
                 title.0.clone(),
                 description.0.clone(),
+                requirements,
             ));
         }
     }
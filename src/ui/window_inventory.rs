@@ -1,4 +1,7 @@
-use super::{panel_equipments::EquipmentsPanel, panel_skills::skills_panel};
+use super::{
+    panel_equipments::EquipmentsPanel, panel_flasks::flasks_panel, panel_skills::skills_panel,
+    HSizer, InGameMenu,
+};
 use crate::{
     components::{
         despawn_all,
@@ -7,6 +10,7 @@ use crate::{
             ToggleInventory,
         },
         item::{ItemEntity, ItemLocation, ItemLocationAcceptAll},
+        map_item::{ActivateMapEvent, MapItem},
         orb::{ActivateOrbEvent, Orb},
     },
     dnd::{DndCursor, DraggedEntity},
@@ -20,6 +24,7 @@ use bevy::{ecs::query::QuerySingleError, prelude::*};
 #[derive(Component)]
 #[require(
     Name::new("InventoryWindow"),
+    InGameMenu,
     Node {
         position_type: PositionType::Absolute,
         flex_direction: FlexDirection::Column,
@@ -83,7 +88,8 @@ impl Plugin for InventoryPanelPlugin {
             .add_observer(create_panel)
             .add_observer(update_inventory)
             .add_observer(toggle_window)
-            .add_observer(on_drop_on_location);
+            .add_observer(on_drop_on_location)
+            .add_observer(activate_map_on_right_click);
     }
 }
 
@@ -115,7 +121,11 @@ fn toggle_window(
             // spawn window as it doesn't exist
             commands.spawn((
                 InventoryWindow,
-                children![EquipmentsPanel, skills_panel(), InventoryPanel],
+                children![
+                    (HSizer, children![EquipmentsPanel, flasks_panel()]),
+                    skills_panel(),
+                    InventoryPanel
+                ],
             ));
         }
         _ => unreachable!(),
@@ -183,3 +193,22 @@ fn on_drop_on_location(
         }
     }
 }
+
+/// A right click on a [MapItem] of the [Inventory] starts the map, when in the town
+fn activate_map_on_right_click(
+    trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    indexes: Query<&InventoryIndex, With<InventoryLocation>>,
+    maps: Query<(), With<MapItem>>,
+    inventory: Single<&Inventory>,
+) {
+    if trigger.button != PointerButton::Secondary {
+        return;
+    }
+    let Ok(index) = indexes.get(trigger.target()) else {
+        return;
+    };
+    if let Some(item) = inventory.at(index.0).filter(|&item| maps.contains(item)) {
+        commands.trigger(ActivateMapEvent(item));
+    }
+}
@@ -0,0 +1,320 @@
+use super::InGameMenu;
+use crate::{
+    components::{
+        despawn_all,
+        item::ItemDescriptor,
+        passive_tree::{
+            AllocatePassiveEvent, PassiveSkills, PassiveSkillsChanged, PassiveTree,
+            ResetPassivesEvent, RespecPassiveEvent, RESET_COST, RESPEC_COST,
+        },
+        player::Player,
+    },
+    schedule::{GameRunningSet, GameState},
+    theme::widget,
+};
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+
+pub struct PassiveTreeWindowPlugin;
+
+impl Plugin for PassiveTreeWindowPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameState::InGame), despawn_all::<PassiveTreeWindow>)
+            .add_systems(
+                Update,
+                toggle_window
+                    .run_if(input_just_pressed(KeyCode::KeyP))
+                    .in_set(GameRunningSet::UserInput),
+            )
+            .add_observer(update_passive_nodes);
+    }
+}
+
+const VIEWPORT_SIZE: Vec2 = Vec2::new(640., 420.);
+const NODE_RADIUS: f32 = 14.;
+const LINK_WIDTH: f32 = 4.;
+const FONT_SIZE: f32 = 12.;
+
+const ALLOCATED_COLOR: Color = Color::srgb(0.85, 0.65, 0.13);
+const ALLOCABLE_COLOR: Color = Color::srgb(0.45, 0.45, 0.55);
+const LOCKED_COLOR: Color = Color::srgb(0.15, 0.15, 0.18);
+
+///
+/// A window that shows the passive skill tree of the [Player]
+///
+#[derive(Component)]
+#[require(
+    Name::new("PassiveTreeWindow"),
+    InGameMenu,
+    Node {
+        position_type: PositionType::Absolute,
+        flex_direction: FlexDirection::Column,
+        left: Val::Px(50.),
+        top: Val::Px(50.),
+        border: UiRect::all(Val::Px(1.)),
+        ..Default::default()
+    },
+    BorderColor(Color::BLACK),
+    BackgroundColor(Color::srgba(0.1, 0.1, 0.12, 0.95))
+)]
+struct PassiveTreeWindow;
+
+///
+/// The visible part of the tree, that can be panned with the mouse
+///
+#[derive(Component)]
+#[require(
+    Name::new("PassiveTreeViewport"),
+    Node {
+        width: Val::Px(VIEWPORT_SIZE.x),
+        height: Val::Px(VIEWPORT_SIZE.y),
+        overflow: Overflow::clip(),
+        ..Default::default()
+    }
+)]
+struct PassiveTreeViewport;
+
+///
+/// The node containing all passive nodes and links, offset by the pan
+///
+#[derive(Component, Default)]
+#[require(Name::new("PassiveTreeCanvas"), Node)]
+struct PassiveTreeCanvas {
+    pan: Vec2,
+}
+
+impl PassiveTreeCanvas {
+    /// Set the position of the canvas so that the tree origin is at the center
+    /// of the viewport, offset by the pan
+    fn update_node(&self, node: &mut Node) {
+        node.position_type = PositionType::Absolute;
+        node.left = Val::Px(VIEWPORT_SIZE.x / 2. + self.pan.x);
+        node.top = Val::Px(VIEWPORT_SIZE.y / 2. + self.pan.y);
+    }
+}
+
+/// A passive node button
+#[derive(Component, Deref)]
+struct PassiveNodeButton(usize);
+
+/// A link between two passive nodes
+#[derive(Component)]
+struct PassiveLink(usize, usize);
+
+/// Text showing the remaining passive points
+#[derive(Component)]
+struct PassivePointsText;
+
+/// Text showing the description of the hovered passive node
+#[derive(Component)]
+struct PassiveNodeInfo;
+
+fn text(value: impl Into<String>) -> impl Bundle {
+    (
+        Text(value.into()),
+        TextFont::from_font_size(FONT_SIZE),
+        TextColor(Color::WHITE),
+        Pickable::IGNORE,
+    )
+}
+
+fn toggle_window(
+    mut commands: Commands,
+    windows: Query<Entity, With<PassiveTreeWindow>>,
+    tree: Res<PassiveTree>,
+) {
+    if let Ok(entity) = windows.single() {
+        commands.entity(entity).despawn();
+        return;
+    }
+
+    let canvas = PassiveTreeCanvas::default();
+    let mut canvas_node = Node::default();
+    canvas.update_node(&mut canvas_node);
+
+    let window = commands.spawn(PassiveTreeWindow).id();
+    commands.spawn((
+        Name::new("PassiveTreeHeader"),
+        Node {
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            padding: UiRect::horizontal(Val::Px(5.)),
+            ..Default::default()
+        },
+        ChildOf(window),
+        children![
+            (PassivePointsText, text("")),
+            widget::button_base(
+                format!("Reset ({} {})", RESET_COST.1, RESET_COST.0.title()),
+                |_: Trigger<Pointer<Click>>, mut commands: Commands| {
+                    commands.trigger(ResetPassivesEvent);
+                },
+                Node {
+                    padding: UiRect::all(Val::Px(5.)),
+                    margin: UiRect::all(Val::Px(5.)),
+                    ..Default::default()
+                },
+            )
+        ],
+    ));
+    let viewport = commands
+        .spawn((PassiveTreeViewport, ChildOf(window)))
+        .observe(pan_tree)
+        .id();
+    let canvas = commands
+        .spawn((canvas, canvas_node, ChildOf(viewport)))
+        .id();
+    commands.spawn((
+        Name::new("PassiveTreeFooter"),
+        Node {
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(5.)),
+            ..Default::default()
+        },
+        ChildOf(window),
+        children![
+            (PassiveNodeInfo, text("")),
+            text(format!(
+                "Left click: allocate - Right click: refund ({} {})",
+                RESPEC_COST.1,
+                RESPEC_COST.0.title()
+            )),
+        ],
+    ));
+
+    // Links are spawned first, to be displayed under the nodes
+    for (from_id, to_id) in tree.links() {
+        let (Some(from), Some(to)) = (tree.node(from_id), tree.node(to_id)) else {
+            warn!("Passive tree link {from_id} <-> {to_id} out of range");
+            continue;
+        };
+        let from = Vec2::from(from.pos);
+        let to = Vec2::from(to.pos);
+        let center = (from + to) / 2.;
+        let length = from.distance(to);
+        let angle = (to - from).to_angle();
+        commands.spawn((
+            PassiveLink(from_id, to_id),
+            Name::new("PassiveLink"),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(center.x - length / 2.),
+                top: Val::Px(center.y - LINK_WIDTH / 2.),
+                width: Val::Px(length),
+                height: Val::Px(LINK_WIDTH),
+                ..Default::default()
+            },
+            Transform::from_rotation(Quat::from_rotation_z(angle)),
+            BackgroundColor(LOCKED_COLOR),
+            Pickable::IGNORE,
+            ChildOf(canvas),
+        ));
+    }
+
+    for (id, node) in tree.nodes.iter().enumerate() {
+        commands
+            .spawn((
+                PassiveNodeButton(id),
+                Name::new(format!("PassiveNodeButton({id})")),
+                Button,
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(node.pos.0 - NODE_RADIUS),
+                    top: Val::Px(node.pos.1 - NODE_RADIUS),
+                    width: Val::Px(2. * NODE_RADIUS),
+                    height: Val::Px(2. * NODE_RADIUS),
+                    border: UiRect::all(Val::Px(2.)),
+                    ..Default::default()
+                },
+                BorderRadius::MAX,
+                BorderColor(Color::BLACK),
+                BackgroundColor(LOCKED_COLOR),
+                ChildOf(canvas),
+            ))
+            .observe(click_passive_node)
+            .observe(show_passive_node_info);
+    }
+
+    commands.trigger(PassiveSkillsChanged);
+}
+
+fn pan_tree(
+    trigger: Trigger<Pointer<Drag>>,
+    mut canvases: Query<(&mut PassiveTreeCanvas, &mut Node)>,
+) {
+    for (mut canvas, mut node) in &mut canvases {
+        canvas.pan += trigger.delta;
+        canvas.update_node(&mut node);
+    }
+}
+
+fn click_passive_node(
+    trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    buttons: Query<&PassiveNodeButton>,
+) {
+    let Ok(&PassiveNodeButton(id)) = buttons.get(trigger.target()) else {
+        return;
+    };
+    match trigger.button {
+        PointerButton::Primary => commands.trigger(AllocatePassiveEvent(id)),
+        PointerButton::Secondary => commands.trigger(RespecPassiveEvent(id)),
+        PointerButton::Middle => {}
+    }
+}
+
+fn show_passive_node_info(
+    trigger: Trigger<Pointer<Over>>,
+    buttons: Query<&PassiveNodeButton>,
+    mut infos: Query<&mut Text, With<PassiveNodeInfo>>,
+    tree: Res<PassiveTree>,
+) {
+    let Some(node) = buttons
+        .get(trigger.target())
+        .ok()
+        .and_then(|button| tree.node(**button))
+    else {
+        return;
+    };
+    let affixes = node
+        .affixes
+        .iter()
+        .map(|affix| affix.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    for mut info in &mut infos {
+        info.0 = format!("{}: {affixes}", node.name);
+    }
+}
+
+fn update_passive_nodes(
+    _trigger: Trigger<PassiveSkillsChanged>,
+    players: Query<&PassiveSkills, With<Player>>,
+    mut buttons: Query<(&PassiveNodeButton, &mut BackgroundColor)>,
+    mut links: Query<(&PassiveLink, &mut BackgroundColor), Without<PassiveNodeButton>>,
+    mut points_texts: Query<&mut Text, With<PassivePointsText>>,
+    tree: Res<PassiveTree>,
+) {
+    let Ok(passives) = players.single() else {
+        return;
+    };
+    let allocated = passives.allocated_with_start();
+    for (button, mut color) in &mut buttons {
+        color.0 = if allocated.contains(&**button) {
+            ALLOCATED_COLOR
+        } else if tree.can_allocate(&allocated, **button) {
+            ALLOCABLE_COLOR
+        } else {
+            LOCKED_COLOR
+        };
+    }
+    for (&PassiveLink(from, to), mut color) in &mut links {
+        color.0 = if allocated.contains(&from) && allocated.contains(&to) {
+            ALLOCATED_COLOR
+        } else {
+            LOCKED_COLOR
+        };
+    }
+    for mut text in &mut points_texts {
+        text.0 = format!("Passive points: {}", passives.points);
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{
     components::{
         affix::{
-            Armour, IncreaseAttackSpeed, IncreaseDamage, IncreaseMovementSpeed, LifeRegen,
-            MoreDamage, PierceChance,
+            Armour, Dexterity, EnergyShield, Evasion, IncreaseAttackSpeed, IncreaseDamage,
+            IncreaseItemQuantity, IncreaseItemRarity, IncreaseLightRadius, IncreaseMovementSpeed,
+            Intelligence, LifeLeech, LifeRegen, MoreDamage, PierceChance, Strength,
         },
         character::MaxLife,
         despawn_all,
@@ -21,14 +22,23 @@ impl Plugin for StatsWindowPlugin {
                 Update,
                 (
                     toggle_window.run_if(input_just_pressed(KeyCode::KeyC)),
+                    update_stat::<Strength>,
+                    update_stat::<Dexterity>,
+                    update_stat::<Intelligence>,
                     update_stat::<Armour>,
+                    update_stat::<Evasion>,
+                    update_stat::<EnergyShield>,
                     update_stat::<MaxLife>,
                     update_stat::<LifeRegen>,
+                    update_stat::<LifeLeech>,
                     update_stat::<IncreaseMovementSpeed>,
                     update_stat::<IncreaseAttackSpeed>,
                     update_stat::<PierceChance>,
                     update_stat::<MoreDamage>,
                     update_stat::<IncreaseDamage>,
+                    update_stat::<IncreaseItemQuantity>,
+                    update_stat::<IncreaseItemRarity>,
+                    update_stat::<IncreaseLightRadius>,
                 )
                     .in_set(GameRunningSet::UserInput),
             );
@@ -98,12 +108,24 @@ fn toggle_window(mut commands: Commands, windows: Query<Entity, With<StatisticsW
                 BackgroundColor(Color::srgba(0.5, 0.3, 0.3, 0.8)),
             ))
             .with_children(|p| {
+                p.spawn(stat_label("Strength:".into()));
+                p.spawn(stat_value::<Strength>());
+                p.spawn(stat_label("Dexterity:".into()));
+                p.spawn(stat_value::<Dexterity>());
+                p.spawn(stat_label("Intelligence:".into()));
+                p.spawn(stat_value::<Intelligence>());
                 p.spawn(stat_label("Armour:".into()));
                 p.spawn(stat_value::<Armour>());
+                p.spawn(stat_label("Evasion:".into()));
+                p.spawn(stat_value::<Evasion>());
+                p.spawn(stat_label("Energy shield:".into()));
+                p.spawn(stat_value::<EnergyShield>());
                 p.spawn(stat_label("Maximum life:".into()));
                 p.spawn(stat_value::<MaxLife>());
                 p.spawn(stat_label("Life regeneration:".into()));
                 p.spawn(stat_value::<LifeRegen>());
+                p.spawn(stat_label("Life leech:".into()));
+                p.spawn(stat_value::<LifeLeech>());
                 p.spawn(stat_label("Movement speed:".into()));
                 p.spawn(stat_value::<IncreaseMovementSpeed>());
                 p.spawn(stat_label("Attack speed:".into()));
@@ -114,6 +136,12 @@ fn toggle_window(mut commands: Commands, windows: Query<Entity, With<StatisticsW
                 p.spawn(stat_value::<MoreDamage>());
                 p.spawn(stat_label("Increase damage:".into()));
                 p.spawn(stat_value::<IncreaseDamage>());
+                p.spawn(stat_label("Item quantity:".into()));
+                p.spawn(stat_value::<IncreaseItemQuantity>());
+                p.spawn(stat_label("Item rarity:".into()));
+                p.spawn(stat_value::<IncreaseItemRarity>());
+                p.spawn(stat_label("Light radius:".into()));
+                p.spawn(stat_value::<IncreaseLightRadius>());
             });
     }
 }
@@ -0,0 +1,268 @@
+use super::{window_vendor::VendorPanel, InGameMenu};
+use crate::{
+    components::{
+        despawn_all,
+        item::{ItemEntity, ItemLocation, ItemLocationAccept, ItemLocationAcceptAll},
+        map_item::{ActivateMapEvent, MapItem},
+        orb::{ActivateOrbEvent, Orb},
+        town::{
+            AddToStashEvent, CraftingBench, EnterTownStationEvent, LeaveTownStationEvent,
+            PutOnCraftingBenchEvent, Stash, TownStation, TownStorageChanged,
+        },
+    },
+    dnd::{DndCursor, DraggedEntity},
+    schedule::{AreaState, GameState},
+};
+use bevy::prelude::*;
+
+///
+/// A window that shows the [TownStation] the player is on
+///
+#[derive(Component)]
+#[require(
+    Name::new("TownStationWindow"),
+    InGameMenu,
+    Node {
+        position_type: PositionType::Absolute,
+        flex_direction: FlexDirection::Column,
+        left: Val::Px(0.),
+        bottom: Val::Px(50.),
+        border: UiRect::all(Val::Px(1.)),
+        padding: UiRect::all(Val::Px(5.)),
+        ..Default::default()
+    },
+    BorderColor(Color::BLACK),
+    BackgroundColor(Color::srgb(0.5, 0.5, 0.5))
+)]
+struct TownStationWindow;
+
+///
+/// A panel that shows the content of the [Stash]
+///
+#[derive(Component)]
+#[require(
+    Name::new("StashPanel"),
+    Node {
+        display: Display::Grid,
+        grid_template_columns: RepeatedGridTrack::flex(Stash::N_COLS, 1.),
+        grid_template_rows: RepeatedGridTrack::flex(Stash::N_ROWS, 1.),
+        ..Default::default()
+    },
+    BackgroundColor(Srgba::rgb(0.16, 0.16, 0.16).into())
+)]
+struct StashPanel;
+
+/// A location in the [StashPanel]
+#[derive(Component)]
+#[require(ItemLocation)]
+struct StashLocation(usize);
+
+/// The location of the item on the [CraftingBench]
+#[derive(Component)]
+#[require(ItemLocation)]
+struct CraftingBenchLocation;
+
+/// The location where the maps are put to be activated
+#[derive(Component)]
+#[require(ItemLocation)]
+struct MapDeviceLocation;
+
+pub struct TownWindowPlugin;
+
+impl Plugin for TownWindowPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameState::InGame), despawn_all::<TownStationWindow>)
+            .add_systems(OnExit(AreaState::Town), despawn_all::<TownStationWindow>)
+            .add_observer(open_window)
+            .add_observer(close_window)
+            .add_observer(create_stash_panel)
+            .add_observer(update_town_storage)
+            .add_observer(on_drop_on_stash_location)
+            .add_observer(on_drop_on_crafting_bench)
+            .add_observer(on_drop_on_map_device);
+    }
+}
+
+fn title(text: &str) -> impl Bundle {
+    (
+        Text(text.into()),
+        TextFont::from_font_size(14.),
+        TextColor(Color::BLACK),
+    )
+}
+
+fn hint(text: &str) -> impl Bundle {
+    (
+        Text(text.into()),
+        TextFont::from_font_size(10.),
+        TextColor(Color::BLACK),
+    )
+}
+
+fn open_window(
+    trigger: Trigger<EnterTownStationEvent>,
+    mut commands: Commands,
+    windows: Query<Entity, With<TownStationWindow>>,
+) {
+    for entity in &windows {
+        commands.entity(entity).despawn();
+    }
+    let station = **trigger;
+    if station == TownStation::Portal {
+        return;
+    }
+    let window = commands
+        .spawn((TownStationWindow, children![title(station.title())]))
+        .id();
+    match station {
+        TownStation::Stash => {
+            commands.spawn((StashPanel, ChildOf(window)));
+        }
+        TownStation::CraftingBench => {
+            commands.spawn((
+                hint("Drop an item, then the orbs to apply"),
+                ChildOf(window),
+            ));
+            commands.spawn((
+                CraftingBenchLocation,
+                ItemLocationAcceptAll,
+                ChildOf(window),
+            ));
+        }
+        TownStation::MapDevice => {
+            commands.spawn((hint("Drop a map to start it"), ChildOf(window)));
+            commands.spawn((
+                MapDeviceLocation,
+                ItemLocationAccept::<MapItem>::new(),
+                ChildOf(window),
+            ));
+        }
+        TownStation::Vendor => {
+            commands.spawn((VendorPanel, ChildOf(window)));
+        }
+        TownStation::Portal => unreachable!(),
+    }
+    commands.trigger(TownStorageChanged);
+}
+
+fn close_window(
+    _trigger: Trigger<LeaveTownStationEvent>,
+    mut commands: Commands,
+    windows: Query<Entity, With<TownStationWindow>>,
+) {
+    for entity in &windows {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn create_stash_panel(trigger: Trigger<OnAdd, StashPanel>, mut commands: Commands) {
+    let panel = trigger.target();
+    for idx in 0..Stash::len() {
+        let (grid_column, grid_row) = Stash::grid_placement(idx);
+        commands.spawn((
+            StashLocation(idx),
+            ItemLocationAcceptAll,
+            Name::new(format!("StashLocation({idx})")),
+            Node {
+                grid_column,
+                grid_row,
+                ..ItemLocation::default_node()
+            },
+            ChildOf(panel),
+        ));
+    }
+    commands.trigger(TownStorageChanged);
+}
+
+fn update_town_storage(
+    _trigger: Trigger<TownStorageChanged>,
+    mut stash_locations: Query<(&mut ItemEntity, &StashLocation)>,
+    mut bench_locations: Query<
+        &mut ItemEntity,
+        (With<CraftingBenchLocation>, Without<StashLocation>),
+    >,
+    stash: Single<&Stash>,
+    bench: Single<&CraftingBench>,
+) {
+    for (mut item_entity, location) in &mut stash_locations {
+        item_entity.0 = stash.at(location.0);
+    }
+    for mut item_entity in &mut bench_locations {
+        item_entity.0 = bench.0;
+    }
+}
+
+fn on_drop_on_stash_location(
+    trigger: Trigger<Pointer<DragDrop>>,
+    mut commands: Commands,
+    locations: Query<&StashLocation>,
+    cursor: Single<&DraggedEntity, With<DndCursor>>,
+    orbs: Query<(), With<Orb>>,
+    stash: Single<&Stash>,
+) {
+    let Ok(location) = locations.get(trigger.target()) else {
+        return;
+    };
+    let Some(drop_item) = ***cursor else {
+        return;
+    };
+    match stash.at(location.0) {
+        None => {
+            commands.trigger(AddToStashEvent {
+                item: drop_item,
+                pos: location.0,
+            });
+        }
+        Some(target_item) => {
+            if orbs.contains(drop_item) {
+                commands.trigger(ActivateOrbEvent {
+                    orb: drop_item,
+                    item: target_item,
+                });
+            }
+        }
+    }
+}
+
+fn on_drop_on_crafting_bench(
+    trigger: Trigger<Pointer<DragDrop>>,
+    mut commands: Commands,
+    locations: Query<(), With<CraftingBenchLocation>>,
+    cursor: Single<&DraggedEntity, With<DndCursor>>,
+    orbs: Query<(), With<Orb>>,
+    bench: Single<&CraftingBench>,
+) {
+    if !locations.contains(trigger.target()) {
+        return;
+    }
+    let Some(drop_item) = ***cursor else {
+        return;
+    };
+    match (bench.0, orbs.contains(drop_item)) {
+        (Some(item), true) => {
+            commands.trigger(ActivateOrbEvent {
+                orb: drop_item,
+                item,
+            });
+        }
+        (None, false) => {
+            commands.trigger(PutOnCraftingBenchEvent(drop_item));
+        }
+        _ => {}
+    }
+}
+
+fn on_drop_on_map_device(
+    trigger: Trigger<Pointer<DragDrop>>,
+    mut commands: Commands,
+    locations: Query<(), With<MapDeviceLocation>>,
+    cursor: Single<&DraggedEntity, With<DndCursor>>,
+    maps: Query<(), With<MapItem>>,
+) {
+    if !locations.contains(trigger.target()) {
+        return;
+    }
+    if let Some(map) = (***cursor).filter(|&item| maps.contains(item)) {
+        commands.trigger(ActivateMapEvent(map));
+    }
+}
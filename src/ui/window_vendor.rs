@@ -0,0 +1,138 @@
+use super::{button::TextButton, HSizer, VSizer};
+use crate::{
+    components::{
+        item::{ItemEntity, ItemLocation, ItemLocationAcceptAll, ItemLocationLocked},
+        vendor::{
+            BuyItemEvent, ExchangeCurrencyEvent, SellItemEvent, VendorStock, VendorStockChanged,
+            CURRENCY_RECIPES,
+        },
+    },
+    dnd::{DndCursor, DraggedEntity},
+};
+use bevy::prelude::*;
+
+///
+/// A two-pane trade panel: the offers of the [VendorStock] on the left,
+/// the location to sell items and the currency recipes on the right
+///
+#[derive(Component)]
+#[require(
+    Name::new("VendorPanel"),
+    HSizer,
+    BackgroundColor(Srgba::rgb(0.16, 0.16, 0.16).into())
+)]
+pub struct VendorPanel;
+
+/// A location of the offers, clicked to buy its item
+#[derive(Component)]
+#[require(ItemLocation, ItemLocationLocked)]
+struct OfferLocation(usize);
+
+/// The price of the [OfferLocation] with the same index
+#[derive(Component)]
+struct OfferPrice(usize);
+
+/// The location where the items are dropped to be sold
+#[derive(Component)]
+#[require(ItemLocation)]
+struct SellLocation;
+
+pub struct VendorPanelPlugin;
+
+impl Plugin for VendorPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(create_panel)
+            .add_observer(update_offers)
+            .add_observer(buy_on_click)
+            .add_observer(on_drop_on_sell_location);
+    }
+}
+
+fn text(value: impl Into<String>) -> impl Bundle {
+    (
+        Text(value.into()),
+        TextFont::from_font_size(10.),
+        TextColor(Color::WHITE),
+    )
+}
+
+fn create_panel(trigger: Trigger<OnAdd, VendorPanel>, mut commands: Commands) {
+    let panel = trigger.target();
+    let offers = commands
+        .spawn((VSizer, children![text("Buy (click)")], ChildOf(panel)))
+        .id();
+    for index in 0..VendorStock::LEN {
+        commands.spawn((
+            HSizer,
+            children![
+                (
+                    OfferLocation(index),
+                    Name::new(format!("OfferLocation({index})"))
+                ),
+                (OfferPrice(index), text(""))
+            ],
+            ChildOf(offers),
+        ));
+    }
+
+    let sell = commands
+        .spawn((
+            VSizer,
+            children![
+                text("Sell (drop)"),
+                (SellLocation, ItemLocationAcceptAll),
+                text("Exchange"),
+            ],
+            ChildOf(panel),
+        ))
+        .id();
+    for (index, recipe) in CURRENCY_RECIPES.iter().enumerate() {
+        commands
+            .spawn((TextButton::small(recipe.to_string()), ChildOf(sell)))
+            .observe(move |_: Trigger<Pointer<Click>>, mut commands: Commands| {
+                commands.trigger(ExchangeCurrencyEvent(index));
+            });
+    }
+    commands.trigger(VendorStockChanged);
+}
+
+fn update_offers(
+    _trigger: Trigger<VendorStockChanged>,
+    mut locations: Query<(&mut ItemEntity, &OfferLocation)>,
+    mut prices: Query<(&mut Text, &OfferPrice)>,
+    stock: Single<&VendorStock>,
+) {
+    for (mut item_entity, location) in &mut locations {
+        item_entity.0 = stock.get(location.0).map(|offer| offer.item);
+    }
+    for (mut text, price) in &mut prices {
+        text.0 = stock
+            .get(price.0)
+            .map(|offer| offer.price.to_string())
+            .unwrap_or_default();
+    }
+}
+
+fn buy_on_click(
+    trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    locations: Query<&OfferLocation>,
+) {
+    if let Ok(location) = locations.get(trigger.target()) {
+        commands.trigger(BuyItemEvent(location.0));
+    }
+}
+
+fn on_drop_on_sell_location(
+    trigger: Trigger<Pointer<DragDrop>>,
+    mut commands: Commands,
+    locations: Query<(), With<SellLocation>>,
+    cursor: Single<&DraggedEntity, With<DndCursor>>,
+) {
+    if !locations.contains(trigger.target()) {
+        return;
+    }
+    if let Some(item) = ***cursor {
+        commands.trigger(SellItemEvent(item));
+    }
+}
@@ -18,6 +18,11 @@ impl DespawnAfter {
     pub fn pause(&mut self, pause: bool) {
         self.pause = pause;
     }
+
+    /// Fraction of the duration elapsed, between 0.0 and 1.0
+    pub fn fraction(&self) -> f32 {
+        self.timer.fraction()
+    }
 }
 
 pub fn despawn_after_plugin(app: &mut App) {